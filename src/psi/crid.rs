@@ -0,0 +1,132 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::psi::{
+    Descriptors,
+    Desc73,
+    Desc76,
+    Desc76Crid,
+    Eit,
+    EitItem,
+    Nit,
+    Sdt,
+};
+
+
+const CRID_SCHEME: &str = "crid://";
+
+
+/// Fully-qualified CRID of the event
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crid {
+    /// Type of the CRID. See `CRID_TYPE_*` constants
+    pub crid_type: u8,
+    /// CRID in the `crid://<authority>/<data>` format
+    pub value: String,
+}
+
+
+#[inline]
+fn find_authority(descriptors: &Descriptors) -> Option<&str> {
+    descriptors.find::<Desc73>().map(|desc| desc.authority.as_str())
+}
+
+
+/// Resolves CRIDs of the events with the default authority defined
+/// in the SDT and NIT, as described in TS 102 323 - 6.3.
+///
+/// Default authority is looked up in the following order:
+///
+/// 1. EIT event loop
+/// 2. SDT service loop
+/// 3. NIT transport stream loop
+/// 4. NIT network loop
+#[derive(Debug, Default)]
+pub struct CridResolver<'a> {
+    /// List of the SDT actual and other
+    pub sdt: Vec<&'a Sdt>,
+    /// List of the NIT actual and other
+    pub nit: Vec<&'a Nit>,
+}
+
+
+impl<'a> CridResolver<'a> {
+    /// Returns default authority for the event
+    pub fn default_authority(&self, eit: &'a Eit, item: &'a EitItem) -> Option<&'a str> {
+        if let Some(v) = find_authority(&item.descriptors) {
+            return Some(v);
+        }
+
+        let service = self.sdt.iter()
+            .filter(|sdt| sdt.tsid == eit.tsid && sdt.onid == eit.onid)
+            .find_map(|sdt| sdt.get_service(eit.pnr));
+        if let Some(v) = service.and_then(|item| find_authority(&item.descriptors)) {
+            return Some(v);
+        }
+
+        for nit in &self.nit {
+            let ts = nit.items.iter()
+                .find(|item| item.tsid == eit.tsid && item.onid == eit.onid);
+            if let Some(ts) = ts {
+                if let Some(v) = find_authority(&ts.descriptors) {
+                    return Some(v);
+                }
+                if let Some(v) = find_authority(&nit.descriptors) {
+                    return Some(v);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns fully-qualified CRIDs of the event.
+    /// CRIDs referenced to the Content Identifier Table and relative CRIDs
+    /// without default authority are skipped
+    pub fn resolve(&self, eit: &'a Eit, item: &'a EitItem) -> Vec<Crid> {
+        let mut result = Vec::new();
+        let authority = self.default_authority(eit, item)
+            .map(|v| v.strip_prefix(CRID_SCHEME).unwrap_or(v));
+
+        for desc in item.descriptors.find_all::<Desc76>() {
+            for crid in &desc.items {
+                let value = match &crid.crid {
+                    Desc76Crid::Descriptor(v) => v,
+                    Desc76Crid::Reference(_) => continue,
+                };
+
+                let value = if value.starts_with('/') {
+                    match authority {
+                        Some(a) => format!("{}{}{}", CRID_SCHEME, a, value),
+                        None => continue,
+                    }
+                } else if value.get(.. CRID_SCHEME.len())
+                    .map(|v| v.eq_ignore_ascii_case(CRID_SCHEME))
+                    .unwrap_or(false)
+                {
+                    value.clone()
+                } else {
+                    format!("{}{}", CRID_SCHEME, value)
+                };
+
+                result.push(Crid {
+                    crid_type: crid.crid_type,
+                    value,
+                });
+            }
+        }
+
+        result
+    }
+
+    /// Returns fully-qualified CRID of the event by the CRID type
+    pub fn get_crid(&self, eit: &'a Eit, item: &'a EitItem, crid_type: u8) -> Option<String> {
+        self.resolve(eit, item).into_iter()
+            .find(|crid| crid.crid_type == crid_type)
+            .map(|crid| crid.value)
+    }
+}
@@ -10,21 +10,81 @@ use std::{
     any::Any,
 };
 
+use crate::psi::PsiError;
+
+mod registry; pub use registry::*;
+
 mod raw; pub use raw::*;
+mod x02; pub use x02::*;
+mod x03; pub use x03::*;
+mod x05; pub use x05::*;
+mod x06; pub use x06::*;
 mod x09; pub use x09::*;
 mod x0a; pub use x0a::*;
+mod x0b; pub use x0b::*;
 mod x0e; pub use x0e::*;
+mod x10; pub use x10::*;
+mod x11; pub use x11::*;
+mod x13; pub use x13::*;
+mod x14; pub use x14::*;
+mod x1c; pub use x1c::*;
+mod x28; pub use x28::*;
+mod x2a; pub use x2a::*;
+mod x2b; pub use x2b::*;
+mod x38; pub use x38::*;
 mod x40; pub use x40::*;
 mod x41; pub use x41::*;
 mod x43; pub use x43::*;
 mod x44; pub use x44::*;
 mod x48; pub use x48::*;
+mod x49; pub use x49::*;
+mod x4a; pub use x4a::*;
+mod x4b; pub use x4b::*;
+mod x4c; pub use x4c::*;
 mod x4d; pub use x4d::*;
 mod x4e; pub use x4e::*;
+mod x4f; pub use x4f::*;
+mod x50; pub use x50::*;
 mod x52; pub use x52::*;
+mod x53; pub use x53::*;
+mod x54; pub use x54::*;
+mod x55; pub use x55::*;
+mod x56; pub use x56::*;
 mod x58; pub use x58::*;
+mod x59; pub use x59::*;
 mod x5a; pub use x5a::*;
+mod x5b; pub use x5b::*;
+mod x5c; pub use x5c::*;
+mod x5d; pub use x5d::*;
+mod x5e; pub use x5e::*;
+mod x5f; pub use x5f::*;
+mod x60; pub use x60::*;
+mod x61; pub use x61::*;
+mod x62; pub use x62::*;
+mod x63; pub use x63::*;
+mod x64; pub use x64::*;
+mod x66; pub use x66::*;
+mod x6a; pub use x6a::*;
+mod x6c; pub use x6c::*;
+mod x6d; pub use x6d::*;
+mod x6f; pub use x6f::*;
+mod x72; pub use x72::*;
+mod x73; pub use x73::*;
+mod x76; pub use x76::*;
+mod x79; pub use x79::*;
+mod x7a; pub use x7a::*;
+mod x7b; pub use x7b::*;
+mod x7c; pub use x7c::*;
+mod x7f; pub use x7f::*;
+mod x81; pub use x81::*;
 mod x83; pub use x83::*;
+mod x83_nordig; pub use x83_nordig::*;
+mod x86; pub use x86::*;
+mod x87; pub use x87::*;
+mod x87_nordig; pub use x87_nordig::*;
+mod x88; pub use x88::*;
+mod xa0; pub use xa0::*;
+mod xa1; pub use xa1::*;
 
 
 pub trait AsAny {
@@ -33,10 +93,24 @@ pub trait AsAny {
 }
 
 
-pub trait Desc: AsAny + fmt::Debug {
+pub trait DescClone {
+    fn clone_box(&self) -> Box<dyn Desc>;
+}
+
+
+pub trait Desc: AsAny + DescClone + fmt::Debug {
     fn tag(&self) -> u8;
     fn size(&self) -> usize;
     fn assemble(&self, buffer: &mut Vec<u8>);
+
+    /// Checks that descriptor payload fits into the 8-bit descriptor_length
+    fn validate(&self) -> Result<(), PsiError> {
+        let size = self.size();
+        if ! (2 ..= DESC_MAX_SIZE).contains(&size) {
+            return Err(PsiError::DescriptorOverflow { tag: self.tag(), size });
+        }
+        Ok(())
+    }
 }
 
 
@@ -46,30 +120,22 @@ impl<T: 'static + Desc> AsAny for T {
 }
 
 
+impl<T: 'static + Desc + Clone> DescClone for T {
+    fn clone_box(&self) -> Box<dyn Desc> { Box::new(self.clone()) }
+}
+
+
+/// Maximum descriptor size with tag and length
+pub const DESC_MAX_SIZE: usize = 2 + 255;
+
+
 /// Descriptors extends the definitions of programs and program elements.
 pub struct Descriptor(Box<dyn Desc>);
 
 
 impl Clone for Descriptor {
-    fn clone(&self) -> Self {
-        match self.0.tag() {
-            0x09 => self.downcast_ref::<Desc09>().clone().into(),
-            0x0A => self.downcast_ref::<Desc0A>().clone().into(),
-            0x0E => self.downcast_ref::<Desc0E>().clone().into(),
-            0x40 => self.downcast_ref::<Desc40>().clone().into(),
-            0x41 => self.downcast_ref::<Desc41>().clone().into(),
-            0x43 => self.downcast_ref::<Desc43>().clone().into(),
-            0x44 => self.downcast_ref::<Desc44>().clone().into(),
-            0x48 => self.downcast_ref::<Desc48>().clone().into(),
-            0x4D => self.downcast_ref::<Desc4D>().clone().into(),
-            0x4E => self.downcast_ref::<Desc4E>().clone().into(),
-            0x52 => self.downcast_ref::<Desc52>().clone().into(),
-            0x58 => self.downcast_ref::<Desc58>().clone().into(),
-            0x5A => self.downcast_ref::<Desc5A>().clone().into(),
-            0x83 => self.downcast_ref::<Desc83>().clone().into(),
-            _ => self.downcast_ref::<DescRaw>().clone().into(),
-        }
-    }
+    #[inline]
+    fn clone(&self) -> Self { Descriptor(self.0.clone_box()) }
 }
 
 
@@ -83,24 +149,275 @@ impl fmt::Debug for Descriptor {
 }
 
 
+macro_rules! descriptor_kind {
+    ( $( $(#[$doc:meta])* $variant:ident($desc:ident), )* ) => {
+        /// Typed reference to the descriptor for pattern matching
+        ///
+        /// # Examples
+        ///
+        /// ``` ignore
+        /// match desc.kind() {
+        ///     DescriptorKind::Service(d) => println!("{}", d.name),
+        ///     _ => {}
+        /// }
+        /// ```
+        #[derive(Debug)]
+        pub enum DescriptorKind<'a> {
+            $( $(#[$doc])* $variant(&'a $desc), )*
+            /// Descriptor not supported in core
+            Raw(&'a DescRaw),
+            /// Descriptor defined by application
+            Other(&'a dyn Desc),
+        }
+
+
+        impl Descriptor {
+            /// Returns typed reference to the descriptor
+            pub fn kind(&self) -> DescriptorKind<'_> {
+                let any = self.0.as_any_ref();
+                $(
+                    if let Some(desc) = any.downcast_ref::<$desc>() {
+                        return DescriptorKind::$variant(desc);
+                    }
+                )*
+                if let Some(desc) = any.downcast_ref::<DescRaw>() {
+                    return DescriptorKind::Raw(desc);
+                }
+                DescriptorKind::Other(self.0.as_ref())
+            }
+        }
+    };
+}
+
+
+descriptor_kind! {
+    /// 0x02 - video stream descriptor
+    VideoStream(Desc02),
+    /// 0x03 - audio stream descriptor
+    AudioStream(Desc03),
+    /// 0x05 - registration descriptor
+    Registration(Desc05),
+    /// 0x06 - data stream alignment descriptor
+    DataStreamAlignment(Desc06),
+    /// 0x09 - conditional access descriptor
+    CA(Desc09),
+    /// 0x0A - ISO 639 language descriptor
+    Language(Desc0A),
+    /// 0x0B - system clock descriptor
+    SystemClock(Desc0B),
+    /// 0x0E - maximum bitrate descriptor
+    MaximumBitrate(Desc0E),
+    /// 0x10 - smoothing buffer descriptor
+    SmoothingBuffer(Desc10),
+    /// 0x11 - STD descriptor
+    STD(Desc11),
+    /// 0x13 - carousel identifier descriptor
+    CarouselIdentifier(Desc13),
+    /// 0x14 - association tag descriptor
+    AssociationTag(Desc14),
+    /// 0x1C - MPEG-4 audio descriptor
+    Mpeg4Audio(Desc1C),
+    /// 0x28 - AVC video descriptor
+    AvcVideo(Desc28),
+    /// 0x2A - AVC timing and HRD descriptor
+    AvcTiming(Desc2A),
+    /// 0x2B - MPEG-2 AAC audio descriptor
+    Mpeg2Aac(Desc2B),
+    /// 0x38 - HEVC video descriptor
+    HevcVideo(Desc38),
+    /// 0x40 - network name descriptor
+    NetworkName(Desc40),
+    /// 0x41 - service list descriptor
+    ServiceList(Desc41),
+    /// 0x43 - satellite delivery system descriptor
+    SatelliteDelivery(Desc43),
+    /// 0x44 - cable delivery system descriptor
+    CableDelivery(Desc44),
+    /// 0x48 - service descriptor
+    Service(Desc48),
+    /// 0x49 - country availability descriptor
+    CountryAvailability(Desc49),
+    /// 0x4A - linkage descriptor
+    Linkage(Desc4A),
+    /// 0x4B - NVOD reference descriptor
+    NvodReference(Desc4B),
+    /// 0x4C - time shifted service descriptor
+    TimeShiftedService(Desc4C),
+    /// 0x4D - short event descriptor
+    ShortEvent(Desc4D),
+    /// 0x4E - extended event descriptor
+    ExtendedEvent(Desc4E),
+    /// 0x4F - time shifted event descriptor
+    TimeShiftedEvent(Desc4F),
+    /// 0x50 - component descriptor
+    Component(Desc50),
+    /// 0x52 - stream identifier descriptor
+    StreamIdentifier(Desc52),
+    /// 0x53 - CA identifier descriptor
+    CaIdentifier(Desc53),
+    /// 0x54 - content descriptor
+    Content(Desc54),
+    /// 0x55 - parental rating descriptor
+    ParentalRating(Desc55),
+    /// 0x56 - teletext descriptor
+    Teletext(Desc56),
+    /// 0x58 - local time offset descriptor
+    LocalTimeOffset(Desc58),
+    /// 0x59 - subtitling descriptor
+    Subtitling(Desc59),
+    /// 0x5A - terrestrial delivery system descriptor
+    TerrestrialDelivery(Desc5A),
+    /// 0x5B - multilingual network name descriptor
+    MultilingualNetworkName(Desc5B),
+    /// 0x5C - multilingual bouquet name descriptor
+    MultilingualBouquetName(Desc5C),
+    /// 0x5D - multilingual service name descriptor
+    MultilingualServiceName(Desc5D),
+    /// 0x5E - multilingual component descriptor
+    MultilingualComponent(Desc5E),
+    /// 0x5F - private data specifier descriptor
+    PrivateDataSpecifier(Desc5F),
+    /// 0x60 - service move descriptor
+    ServiceMove(Desc60),
+    /// 0x61 - short smoothing buffer descriptor
+    ShortSmoothingBuffer(Desc61),
+    /// 0x62 - frequency list descriptor
+    FrequencyList(Desc62),
+    /// 0x63 - partial transport stream descriptor
+    PartialTransportStream(Desc63),
+    /// 0x64 - data broadcast descriptor
+    DataBroadcast(Desc64),
+    /// 0x66 - data broadcast id descriptor
+    DataBroadcastId(Desc66),
+    /// 0x6A - AC-3 descriptor
+    AC3(Desc6A),
+    /// 0x6C - cell list descriptor
+    CellList(Desc6C),
+    /// 0x6D - cell frequency link descriptor
+    CellFrequencyLink(Desc6D),
+    /// 0x6F - application signalling descriptor
+    ApplicationSignalling(Desc6F),
+    /// 0x72 - service availability descriptor
+    ServiceAvailability(Desc72),
+    /// 0x73 - default authority descriptor
+    DefaultAuthority(Desc73),
+    /// 0x76 - content identifier descriptor
+    ContentIdentifier(Desc76),
+    /// 0x79 - S2 satellite delivery system descriptor
+    S2SatelliteDelivery(Desc79),
+    /// 0x7A - enhanced AC-3 descriptor
+    EnhancedAC3(Desc7A),
+    /// 0x7B - DTS audio descriptor
+    DTS(Desc7B),
+    /// 0x7C - AAC descriptor
+    AAC(Desc7C),
+    /// 0x7F - extension descriptor
+    Extension(Desc7F),
+    /// 0x81 - ATSC AC-3 audio descriptor
+    AtscAC3(Desc81),
+    /// 0x83 - logical channel descriptor
+    LogicalChannel(Desc83),
+    /// 0x83 - NorDig logical channel descriptor version 1
+    NorDigLogicalChannel(Desc83NorDig),
+    /// 0x86 - ATSC caption service descriptor
+    CaptionService(Desc86),
+    /// 0x87 - ATSC content advisory descriptor
+    ContentAdvisory(Desc87),
+    /// 0x87 - NorDig logical channel descriptor version 2
+    NorDigLogicalChannelV2(Desc87NorDig),
+    /// 0x88 - HD simulcast logical channel descriptor
+    HdSimulcastLogicalChannel(Desc88),
+    /// 0xA0 - ATSC extended channel name descriptor
+    ExtendedChannelName(DescA0),
+    /// 0xA1 - ATSC service location descriptor
+    ServiceLocation(DescA1),
+}
+
+
 impl Descriptor {
-    /// Validates descriptor length with ::check(slice) and parse
-    fn parse(slice: &[u8]) -> Self {
+    /// Validates descriptor length with ::check(slice) and parse.
+    /// Descriptors registered by application are checked first
+    fn parse(slice: &[u8], private_data_specifier: u32, context: DescriptorContext) -> Self {
+        if let Some(desc) = parse_custom(slice, private_data_specifier, context) {
+            return desc;
+        }
+
         match slice[0] {
+            0x02 if Desc02::check(slice) => Desc02::parse(slice).into(),
+            0x03 if Desc03::check(slice) => Desc03::parse(slice).into(),
+            0x05 if Desc05::check(slice) => Desc05::parse(slice).into(),
+            0x06 if Desc06::check(slice) => Desc06::parse(slice).into(),
             0x09 if Desc09::check(slice) => Desc09::parse(slice).into(),
             0x0A if Desc0A::check(slice) => Desc0A::parse(slice).into(),
+            0x0B if Desc0B::check(slice) => Desc0B::parse(slice).into(),
             0x0E if Desc0E::check(slice) => Desc0E::parse(slice).into(),
+            0x10 if Desc10::check(slice) => Desc10::parse(slice).into(),
+            0x11 if Desc11::check(slice) => Desc11::parse(slice).into(),
+            0x13 if Desc13::check(slice) => Desc13::parse(slice).into(),
+            0x14 if Desc14::check(slice) => Desc14::parse(slice).into(),
+            0x1C if Desc1C::check(slice) => Desc1C::parse(slice).into(),
+            0x28 if Desc28::check(slice) => Desc28::parse(slice).into(),
+            0x2A if Desc2A::check(slice) => Desc2A::parse(slice).into(),
+            0x2B if Desc2B::check(slice) => Desc2B::parse(slice).into(),
+            0x38 if Desc38::check(slice) => Desc38::parse(slice).into(),
             0x40 if Desc40::check(slice) => Desc40::parse(slice).into(),
             0x41 if Desc41::check(slice) => Desc41::parse(slice).into(),
             0x43 if Desc43::check(slice) => Desc43::parse(slice).into(),
             0x44 if Desc44::check(slice) => Desc44::parse(slice).into(),
             0x48 if Desc48::check(slice) => Desc48::parse(slice).into(),
+            0x49 if Desc49::check(slice) => Desc49::parse(slice).into(),
+            0x4A if Desc4A::check(slice) => Desc4A::parse(slice).into(),
+            0x4B if Desc4B::check(slice) => Desc4B::parse(slice).into(),
+            0x4C if Desc4C::check(slice) => Desc4C::parse(slice).into(),
             0x4D if Desc4D::check(slice) => Desc4D::parse(slice).into(),
             0x4E if Desc4E::check(slice) => Desc4E::parse(slice).into(),
+            0x4F if Desc4F::check(slice) => Desc4F::parse(slice).into(),
+            0x50 if Desc50::check(slice) => Desc50::parse(slice).into(),
             0x52 if Desc52::check(slice) => Desc52::parse(slice).into(),
+            0x53 if Desc53::check(slice) => Desc53::parse(slice).into(),
+            0x54 if Desc54::check(slice) => Desc54::parse(slice).into(),
+            0x55 if Desc55::check(slice) => Desc55::parse(slice).into(),
+            0x56 if Desc56::check(slice) => Desc56::parse(slice).into(),
             0x58 if Desc58::check(slice) => Desc58::parse(slice).into(),
+            0x59 if Desc59::check(slice) => Desc59::parse(slice).into(),
             0x5A if Desc5A::check(slice) => Desc5A::parse(slice).into(),
-            0x83 if Desc83::check(slice) => Desc83::parse(slice).into(),
+            0x5B if Desc5B::check(slice) => Desc5B::parse(slice).into(),
+            0x5C if Desc5C::check(slice) => Desc5C::parse(slice).into(),
+            0x5D if Desc5D::check(slice) => Desc5D::parse(slice).into(),
+            0x5E if Desc5E::check(slice) => Desc5E::parse(slice).into(),
+            0x5F if Desc5F::check(slice) => Desc5F::parse(slice).into(),
+            0x60 if Desc60::check(slice) => Desc60::parse(slice).into(),
+            0x61 if Desc61::check(slice) => Desc61::parse(slice).into(),
+            0x62 if Desc62::check(slice) => Desc62::parse(slice).into(),
+            0x63 if Desc63::check(slice) => Desc63::parse(slice).into(),
+            0x64 if Desc64::check(slice) => Desc64::parse(slice).into(),
+            0x66 if Desc66::check(slice) => Desc66::parse(slice).into(),
+            0x6A if Desc6A::check(slice) => Desc6A::parse(slice).into(),
+            0x6C if Desc6C::check(slice) => Desc6C::parse(slice).into(),
+            0x6D if Desc6D::check(slice) => Desc6D::parse(slice).into(),
+            0x6F if Desc6F::check(slice) => Desc6F::parse(slice).into(),
+            0x72 if Desc72::check(slice) => Desc72::parse(slice).into(),
+            0x73 if Desc73::check(slice) => Desc73::parse(slice).into(),
+            0x76 if Desc76::check(slice) => Desc76::parse(slice).into(),
+            0x79 if Desc79::check(slice) => Desc79::parse(slice).into(),
+            0x7A if Desc7A::check(slice) => Desc7A::parse(slice).into(),
+            0x7B if Desc7B::check(slice) => Desc7B::parse(slice).into(),
+            0x7C if Desc7C::check(slice) => Desc7C::parse(slice).into(),
+            0x7F if Desc7F::check(slice) => Desc7F::parse(slice).into(),
+            0x81 if Desc81::check(slice) => Desc81::parse(slice).into(),
+            0x83 if private_data_specifier == PRIVATE_DATA_SPECIFIER_NORDIG &&
+                Desc83NorDig::check(slice) => Desc83NorDig::parse(slice).into(),
+            0x83 if private_data_specifier != PRIVATE_DATA_SPECIFIER_NORDIG &&
+                Desc83::check(slice) => Desc83::parse(slice).into(),
+            0x86 if Desc86::check(slice) => Desc86::parse(slice).into(),
+            0x87 if private_data_specifier == PRIVATE_DATA_SPECIFIER_NORDIG &&
+                Desc87NorDig::check(slice) => Desc87NorDig::parse(slice).into(),
+            0x87 if private_data_specifier != PRIVATE_DATA_SPECIFIER_NORDIG &&
+                Desc87::check(slice) => Desc87::parse(slice).into(),
+            0x88 if private_data_specifier != PRIVATE_DATA_SPECIFIER_NORDIG &&
+                Desc88::check(slice) => Desc88::parse(slice).into(),
+            0xA0 if DescA0::check(slice) => DescA0::parse(slice).into(),
+            0xA1 if DescA1::check(slice) => DescA1::parse(slice).into(),
             _ => DescRaw::parse(slice).into(),
         }
     }
@@ -111,17 +428,28 @@ impl Descriptor {
     #[inline]
     fn size(&self) -> usize { self.0.size() }
 
+    #[inline]
+    pub fn validate(&self) -> Result<(), PsiError> { self.0.validate() }
+
     #[inline]
     pub fn tag(&self) -> u8 { self.0.tag() }
 
+    /// Returns `true` if descriptor has type `T`
+    #[inline]
+    pub fn is<T: 'static + Desc>(&self) -> bool {
+        self.0.as_any_ref().is::<T>()
+    }
+
+    /// Returns reference to the descriptor if it has type `T`
     #[inline]
-    pub fn downcast_ref<T: 'static + Desc>(&self) -> &T {
-        self.0.as_any_ref().downcast_ref::<T>().unwrap()
+    pub fn downcast_ref<T: 'static + Desc>(&self) -> Option<&T> {
+        self.0.as_any_ref().downcast_ref::<T>()
     }
 
+    /// Returns mutable reference to the descriptor if it has type `T`
     #[inline]
-    pub fn downcast_mut<T: 'static + Desc>(&mut self) -> &mut T {
-        self.0.as_any_mut().downcast_mut::<T>().unwrap()
+    pub fn downcast_mut<T: 'static + Desc>(&mut self) -> Option<&mut T> {
+        self.0.as_any_mut().downcast_mut::<T>()
     }
 }
 
@@ -138,18 +466,33 @@ impl fmt::Debug for Descriptors {
 
 
 impl Descriptors {
+    #[inline]
     pub fn parse(&mut self, slice: &[u8]) {
+        self.parse_context(slice, DescriptorContext::Any)
+    }
+
+    /// Parses descriptors loop from the table defined by `context`.
+    pub fn parse_context(&mut self, slice: &[u8], context: DescriptorContext) {
+        let mut private_data_specifier = 0;
         let mut skip: usize = 0;
         while slice.len() >= skip + 2 {
             let next = skip + 2 + slice[skip + 1] as usize;
             if next > slice.len() {
                 break;
             }
-            self.0.push(Descriptor::parse(&slice[skip .. next]));
+
+            let desc = &slice[skip .. next];
+            if desc[0] == 0x5F && Desc5F::check(desc) {
+                private_data_specifier = Desc5F::parse(desc).private_data_specifier;
+            }
+
+            self.0.push(Descriptor::parse(desc, private_data_specifier, context));
             skip = next;
         }
     }
 
+    /// Appends descriptors into buffer and returns assembled size.
+    /// Descriptors should be checked with `validate` before
     pub fn assemble(&self, buffer: &mut Vec<u8>) -> usize {
         let size = buffer.len();
         for item in &self.0 {
@@ -161,6 +504,29 @@ impl Descriptors {
     #[inline]
     pub fn size(&self) -> usize { self.0.iter().fold(0, |acc, x| acc + x.size()) }
 
+    /// Splits descriptors into groups with assembled size
+    /// less or equal than `limit`. Returns at least one group
+    pub fn split(&self, limit: usize) -> Vec<Descriptors> {
+        let mut out = vec![Descriptors::default()];
+        let mut size = 0;
+
+        for item in &self.0 {
+            if size != 0 && size + item.size() > limit {
+                out.push(Descriptors::default());
+                size = 0;
+            }
+            size += item.size();
+            out.last_mut().unwrap().0.push(item.clone());
+        }
+
+        out
+    }
+
+    /// Returns error for the first descriptor that could not be assembled
+    pub fn validate(&self) -> Result<(), PsiError> {
+        self.0.iter().try_for_each(|x| x.validate())
+    }
+
     #[inline]
     pub fn is_empty(&self) -> bool { self.0.is_empty() }
 
@@ -168,7 +534,7 @@ impl Descriptors {
     pub fn len(&self) -> usize { self.0.len() }
 
     #[inline]
-    pub fn get(&mut self, index: usize) -> Option<&Descriptor> { self.0.get(index) }
+    pub fn get(&self, index: usize) -> Option<&Descriptor> { self.0.get(index) }
 
     #[inline]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut Descriptor> { self.0.get_mut(index) }
@@ -183,4 +549,162 @@ impl Descriptors {
 
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &Descriptor> { self.0.iter() }
+
+    /// Returns first descriptor with type `T`
+    #[inline]
+    pub fn find<T: 'static + Desc>(&self) -> Option<&T> {
+        self.0.iter().find_map(|x| x.downcast_ref::<T>())
+    }
+
+    /// Returns mutable reference to the first descriptor with type `T`
+    #[inline]
+    pub fn find_mut<T: 'static + Desc>(&mut self) -> Option<&mut T> {
+        self.0.iter_mut().find_map(|x| x.downcast_mut::<T>())
+    }
+
+    /// Returns iterator over all descriptors with type `T`
+    #[inline]
+    pub fn find_all<T: 'static + Desc>(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().filter_map(|x| x.downcast_ref::<T>())
+    }
+
+    /// Removes all descriptors with type `T`
+    #[inline]
+    pub fn remove<T: 'static + Desc>(&mut self) {
+        self.0.retain(|x| ! x.is::<T>())
+    }
+
+    /// Retains only descriptors specified by the predicate
+    #[inline]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Descriptor) -> bool,
+    {
+        self.0.retain(f)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        DescriptorKind,
+        PsiError,
+        Desc0E,
+        Desc48,
+        Desc52,
+        DescRaw,
+    };
+    use crate::textcode::{
+        StringDVB,
+        ISO6937,
+    };
+
+    static DATA: &[u8] = &[
+        0x0e, 0x03, 0xc1, 0x2e, 0xbc,
+        0x52, 0x01, 0x02,
+        0x4d, 0x01, 0x00,
+        0x52, 0x01, 0x03];
+
+    #[test]
+    fn test_kind() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA);
+
+        let mut iter = descriptors.iter();
+        match iter.next().unwrap().kind() {
+            DescriptorKind::MaximumBitrate(desc) => assert_eq!(desc.bitrate, 77500),
+            _ => unreachable!(),
+        };
+        match iter.next().unwrap().kind() {
+            DescriptorKind::StreamIdentifier(desc) => assert_eq!(desc.tag, 2),
+            _ => unreachable!(),
+        };
+        match iter.next().unwrap().kind() {
+            DescriptorKind::Raw(desc) => assert_eq!(desc.tag, 0x4D),
+            _ => unreachable!(),
+        };
+
+        assert!(descriptors.get(0).unwrap().downcast_ref::<Desc52>().is_none());
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA);
+
+        let cloned = descriptors.clone();
+        assert_eq!(cloned.len(), 4);
+
+        let mut assembled = Vec::new();
+        cloned.assemble(&mut assembled);
+        assert_eq!(assembled.as_slice(), DATA);
+    }
+
+    #[test]
+    fn test_find() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA);
+
+        assert_eq!(descriptors.find::<Desc0E>().unwrap().bitrate, 77500);
+        assert_eq!(descriptors.find::<Desc52>().unwrap().tag, 2);
+        assert_eq!(descriptors.find_all::<Desc52>().map(|x| x.tag).collect::<Vec<u8>>(), vec![2, 3]);
+
+        descriptors.find_mut::<Desc52>().unwrap().tag = 4;
+        assert_eq!(descriptors.find::<Desc52>().unwrap().tag, 4);
+
+        descriptors.remove::<Desc52>();
+        assert_eq!(descriptors.len(), 2);
+        assert!(descriptors.find::<Desc52>().is_none());
+
+        descriptors.retain(|x| ! x.is::<DescRaw>());
+        assert_eq!(descriptors.len(), 1);
+        assert!(descriptors.find::<Desc0E>().is_some());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA);
+        assert!(descriptors.validate().is_ok());
+
+        descriptors.push(Desc48 {
+            service_type: 1,
+            provider: StringDVB::from_str("Provider", ISO6937),
+            name: StringDVB::from_str(&"x".repeat(250), ISO6937),
+        });
+        assert_eq!(descriptors.validate(), Err(PsiError::DescriptorOverflow {
+            tag: 0x48,
+            size: 2 + 1 + 1 + 8 + 1 + 250,
+        }));
+
+        // size includes descriptors that do not pass validation
+        assert_eq!(descriptors.size(), DATA.len() + 2 + 1 + 1 + 8 + 1 + 250);
+    }
+
+    #[test]
+    fn test_split() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA);
+
+        let list = descriptors.split(DATA.len());
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].size(), DATA.len());
+
+        let list = descriptors.split(DATA.len() - 1);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.iter().map(Descriptors::len).sum::<usize>(), descriptors.len());
+
+        let mut assembled = Vec::new();
+        for item in &list {
+            assert!(item.size() < DATA.len());
+            item.assemble(&mut assembled);
+        }
+        assert_eq!(assembled.as_slice(), DATA);
+
+        let list = Descriptors::default().split(DATA.len());
+        assert_eq!(list.len(), 1);
+        assert!(list[0].is_empty());
+    }
 }
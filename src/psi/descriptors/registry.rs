@@ -0,0 +1,262 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use std::sync::{
+    RwLock,
+    atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+use super::Descriptor;
+
+
+/// Table where descriptor loop is placed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DescriptorContext {
+    /// Any table
+    Any,
+    /// Program Map Table
+    Pmt,
+    /// Network Information Table
+    Nit,
+    /// Service Description Table
+    Sdt,
+    /// Event Information Table
+    Eit,
+    /// Time Offset Table
+    Tot,
+    /// Selection Information Table
+    Sit,
+}
+
+
+impl Default for DescriptorContext {
+    #[inline]
+    fn default() -> Self { DescriptorContext::Any }
+}
+
+
+/// Function to parse custom descriptor.
+/// Receives complete descriptor with tag and length.
+/// Should return `None` if descriptor is not valid.
+pub type DescriptorParser = fn(&[u8]) -> Option<Descriptor>;
+
+
+struct Entry {
+    tag: u8,
+    private_data_specifier: Option<u32>,
+    context: DescriptorContext,
+    parser: DescriptorParser,
+}
+
+
+impl Entry {
+    /// Returns match priority or `None` if entry is not applicable
+    fn score(&self, tag: u8, private_data_specifier: u32, context: DescriptorContext) -> Option<u8> {
+        if self.tag != tag {
+            return None;
+        }
+
+        let mut score = 0;
+
+        match self.private_data_specifier {
+            Some(v) if v == private_data_specifier => score += 2,
+            Some(_) => return None,
+            None => {}
+        }
+
+        match self.context {
+            DescriptorContext::Any => {}
+            v if v == context => score += 1,
+            _ => return None,
+        }
+
+        Some(score)
+    }
+}
+
+
+static REGISTRY: RwLock<Vec<Entry>> = RwLock::new(Vec::new());
+
+
+/// Set if registry has parsers. Allows to skip locking on each parse
+static REGISTRY_USED: AtomicBool = AtomicBool::new(false);
+
+
+/// Registers parser for the private or user-defined descriptor.
+/// Registered parsers take precedence over the descriptors supported in core.
+///
+/// - `tag` - descriptor tag
+/// - `private_data_specifier` - parser applied only if preceding
+///   private_data_specifier_descriptor (0x5F) in the same descriptor loop
+///   has this value. `None` to apply for any private data specifier
+/// - `context` - parser applied only for the descriptors in this table
+///
+/// If several parsers are applicable, parser with private_data_specifier
+/// has higher priority, next parser with the table context.
+/// Parser registered later overrides previous one with same priority.
+///
+/// # Examples
+///
+/// ``` ignore
+/// use mpegts::psi::*;
+///
+/// fn parse_desc_f0(slice: &[u8]) -> Option<Descriptor> {
+///     if DescF0::check(slice) { Some(DescF0::parse(slice).into()) } else { None }
+/// }
+///
+/// register_descriptor(0xF0, Some(0x0000_0028), DescriptorContext::Sdt, parse_desc_f0);
+/// ```
+pub fn register_descriptor(
+    tag: u8,
+    private_data_specifier: Option<u32>,
+    context: DescriptorContext,
+    parser: DescriptorParser)
+{
+    let mut registry = REGISTRY.write().unwrap();
+    registry.push(Entry {
+        tag,
+        private_data_specifier,
+        context,
+        parser,
+    });
+    REGISTRY_USED.store(true, Ordering::Release);
+}
+
+
+/// Removes all parsers registered for the tag
+pub fn unregister_descriptor(tag: u8) {
+    let mut registry = REGISTRY.write().unwrap();
+    registry.retain(|x| x.tag != tag);
+    REGISTRY_USED.store(! registry.is_empty(), Ordering::Release);
+}
+
+
+/// Parses descriptor with registered parser
+pub(crate) fn parse_custom(slice: &[u8], private_data_specifier: u32, context: DescriptorContext) -> Option<Descriptor> {
+    if ! REGISTRY_USED.load(Ordering::Acquire) {
+        return None;
+    }
+
+    let registry = REGISTRY.read().unwrap();
+
+    let mut parser: Option<(u8, DescriptorParser)> = None;
+    for entry in registry.iter() {
+        if let Some(score) = entry.score(slice[0], private_data_specifier, context) {
+            match parser {
+                Some((v, _)) if v > score => {}
+                _ => parser = Some((score, entry.parser)),
+            }
+        }
+    }
+
+    let (_, parser) = parser?;
+    drop(registry);
+    parser(slice)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Desc,
+        Descriptor,
+        Descriptors,
+        DescriptorKind,
+        DescriptorContext,
+        DescRaw,
+        register_descriptor,
+        unregister_descriptor,
+    };
+
+    /// Removes registered parsers on drop
+    struct Unregister(u8);
+
+    impl Drop for Unregister {
+        fn drop(&mut self) { unregister_descriptor(self.0) }
+    }
+
+    #[derive(Debug, Clone)]
+    struct DescFE {
+        value: u8,
+    }
+
+    impl Desc for DescFE {
+        fn tag(&self) -> u8 { 0xFE }
+        fn size(&self) -> usize { 3 }
+        fn assemble(&self, buffer: &mut Vec<u8>) {
+            buffer.extend_from_slice(&[0xFE, 0x01, self.value]);
+        }
+    }
+
+    fn parse_desc_fe(slice: &[u8]) -> Option<Descriptor> {
+        if slice.len() == 3 {
+            Some(DescFE { value: slice[2] }.into())
+        } else {
+            None
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct DescFEAlt {
+        data: Vec<u8>,
+    }
+
+    impl Desc for DescFEAlt {
+        fn tag(&self) -> u8 { 0xFE }
+        fn size(&self) -> usize { 2 + self.data.len() }
+        fn assemble(&self, buffer: &mut Vec<u8>) {
+            buffer.push(0xFE);
+            buffer.push(self.data.len() as u8);
+            buffer.extend_from_slice(&self.data);
+        }
+    }
+
+    fn parse_desc_fe_alt(slice: &[u8]) -> Option<Descriptor> {
+        Some(DescFEAlt { data: slice[2 ..].to_vec() }.into())
+    }
+
+    static DATA_FE: &[u8] = &[
+        0xfe, 0x01, 0x01,
+        0x5f, 0x04, 0x00, 0x00, 0x00, 0x28,
+        0xfe, 0x01, 0x02,
+        0xfe, 0x02, 0x03, 0x04];
+
+    #[test]
+    fn test_registry() {
+        let _guard = Unregister(0xFE);
+        register_descriptor(0xFE, Some(0x0000_0028), DescriptorContext::Sdt, parse_desc_fe);
+        register_descriptor(0xFE, None, DescriptorContext::Any, parse_desc_fe_alt);
+
+        let mut descriptors = Descriptors::default();
+        descriptors.parse_context(DATA_FE, DescriptorContext::Sdt);
+        assert_eq!(descriptors.len(), 4);
+
+        let mut iter = descriptors.iter();
+        assert!(iter.next().unwrap().is::<DescFEAlt>());
+        iter.next();
+        match iter.next().unwrap().kind() {
+            DescriptorKind::Other(desc) => assert_eq!(desc.tag(), 0xFE),
+            _ => unreachable!(),
+        };
+        // not valid for DescFE
+        assert!(iter.next().unwrap().is::<DescRaw>());
+
+        assert_eq!(descriptors.find::<DescFE>().unwrap().value, 2);
+
+        let mut assembled = Vec::new();
+        descriptors.clone().assemble(&mut assembled);
+        assert_eq!(assembled.as_slice(), DATA_FE);
+
+        let mut descriptors = Descriptors::default();
+        descriptors.parse_context(DATA_FE, DescriptorContext::Pmt);
+        assert!(descriptors.find::<DescFE>().is_none());
+        assert_eq!(descriptors.find_all::<DescFEAlt>().count(), 3);
+    }
+}
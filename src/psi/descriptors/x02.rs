@@ -0,0 +1,140 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The video stream descriptor provides basic information which identifies
+/// the coding parameters of a video elementary stream.
+///
+/// ISO 13818-1 - 2.6.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc02 {
+    /// Multiple frame rates may be present in the stream
+    pub multiple_frame_rate_flag: u8,
+    /// Frame rate code as defined in ISO 13818-2
+    pub frame_rate_code: u8,
+    /// Stream contains constrained parameters (ISO 11172-2)
+    pub constrained_parameter_flag: u8,
+    /// Stream contains only I-pictures
+    pub still_picture_flag: u8,
+    /// Profile and level of the ISO 13818-2 stream.
+    /// `None` if stream contains only ISO 11172-2 data
+    pub profile_and_level_indication: Option<u8>,
+    /// Chroma format as defined in ISO 13818-2
+    pub chroma_format: u8,
+    /// Frame rate extension fields are present in the stream
+    pub frame_rate_extension_flag: u8,
+}
+
+
+impl Desc02 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mpeg_1_only_flag = (slice[2] & 0x04) != 0;
+        slice.len() == if mpeg_1_only_flag { MIN_SIZE } else { MIN_SIZE + 2 }
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            multiple_frame_rate_flag: slice[2] >> 7,
+            frame_rate_code: (slice[2] >> 3) & 0x0F,
+            constrained_parameter_flag: (slice[2] >> 1) & 0x01,
+            still_picture_flag: slice[2] & 0x01,
+            .. Default::default()
+        };
+
+        if (slice[2] & 0x04) == 0 {
+            result.profile_and_level_indication = Some(slice[3]);
+            result.chroma_format = slice[4] >> 6;
+            result.frame_rate_extension_flag = (slice[4] >> 5) & 0x01;
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc02 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x02
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.profile_and_level_indication.map_or(0, |_| 2)
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x02);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.multiple_frame_rate_flag, 1,
+            self.frame_rate_code, 4,
+            u8::from(self.profile_and_level_indication.is_none()), 1,
+            self.constrained_parameter_flag, 1,
+            self.still_picture_flag, 1));
+
+        if let Some(profile_and_level_indication) = self.profile_and_level_indication {
+            buffer.push(profile_and_level_indication);
+            buffer.push(set_bits!(8,
+                self.chroma_format, 2,
+                self.frame_rate_extension_flag, 1,
+                0x1F, 5));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc02,
+    };
+
+    static DATA_02: &[u8] = &[0x02, 0x03, 0x1a, 0x48, 0x5f];
+
+    #[test]
+    fn test_02_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_02);
+
+        let desc = descriptors.find::<Desc02>().unwrap();
+        assert_eq!(desc.multiple_frame_rate_flag, 0);
+        assert_eq!(desc.frame_rate_code, 3);
+        assert_eq!(desc.constrained_parameter_flag, 1);
+        assert_eq!(desc.still_picture_flag, 0);
+        assert_eq!(desc.profile_and_level_indication, Some(0x48));
+        assert_eq!(desc.chroma_format, 1);
+        assert_eq!(desc.frame_rate_extension_flag, 0);
+    }
+
+    #[test]
+    fn test_02_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc02 {
+            frame_rate_code: 3,
+            constrained_parameter_flag: 1,
+            profile_and_level_indication: Some(0x48),
+            chroma_format: 1,
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_02);
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The audio stream descriptor provides basic information which identifies
+/// the coding version of an audio elementary stream.
+///
+/// ISO 13818-1 - 2.6.4
+#[derive(Debug, Default, Clone)]
+pub struct Desc03 {
+    /// Stream contains free format bitrate
+    pub free_format_flag: u8,
+    /// ID field as defined in ISO 13818-3
+    pub id: u8,
+    /// Highest layer used in the stream
+    pub layer: u8,
+    /// Bitrate may be changed between audio frames
+    pub variable_rate_audio_indicator: u8,
+}
+
+
+impl Desc03 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            free_format_flag: slice[2] >> 7,
+            id: (slice[2] >> 6) & 0x01,
+            layer: (slice[2] >> 4) & 0x03,
+            variable_rate_audio_indicator: (slice[2] >> 3) & 0x01,
+        }
+    }
+}
+
+
+impl Desc for Desc03 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x03
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x03);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.free_format_flag, 1,
+            self.id, 1,
+            self.layer, 2,
+            self.variable_rate_audio_indicator, 1,
+            0x07, 3));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc03,
+    };
+
+    static DATA_03: &[u8] = &[0x03, 0x01, 0x67];
+
+    #[test]
+    fn test_03_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_03);
+
+        let desc = descriptors.find::<Desc03>().unwrap();
+        assert_eq!(desc.free_format_flag, 0);
+        assert_eq!(desc.id, 1);
+        assert_eq!(desc.layer, 2);
+        assert_eq!(desc.variable_rate_audio_indicator, 0);
+    }
+
+    #[test]
+    fn test_03_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc03 {
+            free_format_flag: 0,
+            id: 1,
+            layer: 2,
+            variable_rate_audio_indicator: 0,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_03);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 6;
+
+
+/// HEVC video (ISO 23008-2)
+pub const FORMAT_HEVC: u32 = 0x4845_5643;
+/// Opus audio
+pub const FORMAT_OPUS: u32 = 0x4F70_7573;
+/// SMPTE 336M KLV metadata
+pub const FORMAT_KLVA: u32 = 0x4B4C_5641;
+/// SMPTE 302M AES3 audio
+pub const FORMAT_BSSD: u32 = 0x4253_5344;
+/// Dolby AC-3 audio
+pub const FORMAT_AC3: u32 = 0x4143_2D33;
+/// Dolby E-AC-3 audio
+pub const FORMAT_EAC3: u32 = 0x4541_4333;
+
+
+/// The registration descriptor provides a method to uniquely and
+/// unambiguously identify formats of private data.
+///
+/// ISO 13818-1 - 2.6.8
+#[derive(Debug, Default, Clone)]
+pub struct Desc05 {
+    /// Format identifier assigned by the registration authority (SMPTE)
+    pub format_identifier: u32,
+    /// Additional identification info defined by the format owner
+    pub info: Vec<u8>,
+}
+
+
+impl Desc05 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            format_identifier: slice[2 ..].get_u32(),
+            info: Vec::from(&slice[6 ..]),
+        }
+    }
+}
+
+
+impl Desc for Desc05 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x05
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.info.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x05;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2 ..].set_u32(self.format_identifier);
+        buffer.extend_from_slice(&self.info);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc05,
+        FORMAT_OPUS,
+    };
+
+    static DATA_05: &[u8] = &[0x05, 0x04, 0x4f, 0x70, 0x75, 0x73];
+
+    #[test]
+    fn test_05_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_05);
+
+        let desc = descriptors.find::<Desc05>().unwrap();
+        assert_eq!(desc.format_identifier, FORMAT_OPUS);
+        assert!(desc.info.is_empty());
+    }
+
+    #[test]
+    fn test_05_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc05 {
+            format_identifier: FORMAT_OPUS,
+            info: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_05);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The data stream alignment descriptor describes which type of alignment
+/// is present in the associated elementary stream.
+///
+/// ISO 13818-1 - 2.6.10
+#[derive(Debug, Default, Clone)]
+pub struct Desc06 {
+    /// Alignment type. For video: 1 - slice or access unit, 2 - access unit, ...
+    pub alignment_type: u8,
+}
+
+
+impl Desc06 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            alignment_type: slice[2],
+        }
+    }
+}
+
+
+impl Desc for Desc06 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x06
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x06);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(self.alignment_type);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc06,
+    };
+
+    static DATA_06: &[u8] = &[0x06, 0x01, 0x02];
+
+    #[test]
+    fn test_06_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_06);
+
+        let desc = descriptors.find::<Desc06>().unwrap();
+        assert_eq!(desc.alignment_type, 2);
+    }
+
+    #[test]
+    fn test_06_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc06 {
+            alignment_type: 2,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_06);
+    }
+}
@@ -81,7 +81,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_09);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc09>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc09>().unwrap();
         assert_eq!(desc.caid, 2403);
         assert_eq!(desc.pid, 1281);
         assert_eq!(desc.data, []);
@@ -96,7 +96,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_0A);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc0A>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc0A>().unwrap();
         let item = &desc.items[0];
         assert_eq!(item.code, textcode::StringDVB::from_str("eng", 0));
         assert_eq!(item.audio_type, 1);
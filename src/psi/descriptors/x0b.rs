@@ -0,0 +1,104 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 4;
+
+
+/// The system clock descriptor conveys information about the system
+/// clock that was used to generate the timestamps.
+///
+/// ISO 13818-1 - 2.6.20
+#[derive(Debug, Default, Clone)]
+pub struct Desc0B {
+    /// System clock has been derived from an external frequency reference
+    pub external_clock_reference_indicator: u8,
+    /// Clock accuracy integer. Accuracy is integer * 10^(-exponent) ppm.
+    /// Value 0 means 30 ppm
+    pub clock_accuracy_integer: u8,
+    /// Clock accuracy exponent
+    pub clock_accuracy_exponent: u8,
+}
+
+
+impl Desc0B {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            external_clock_reference_indicator: slice[2] >> 7,
+            clock_accuracy_integer: slice[2] & 0x3F,
+            clock_accuracy_exponent: slice[3] >> 5,
+        }
+    }
+}
+
+
+impl Desc for Desc0B {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x0B
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x0B);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.external_clock_reference_indicator, 1,
+            1, 1,
+            self.clock_accuracy_integer, 6));
+        buffer.push(set_bits!(8,
+            self.clock_accuracy_exponent, 3,
+            0x1F, 5));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc0B,
+    };
+
+    static DATA_0B: &[u8] = &[0x0b, 0x02, 0xc1, 0x3f];
+
+    #[test]
+    fn test_0b_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_0B);
+
+        let desc = descriptors.find::<Desc0B>().unwrap();
+        assert_eq!(desc.external_clock_reference_indicator, 1);
+        assert_eq!(desc.clock_accuracy_integer, 1);
+        assert_eq!(desc.clock_accuracy_exponent, 1);
+    }
+
+    #[test]
+    fn test_0b_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc0B {
+            external_clock_reference_indicator: 1,
+            clock_accuracy_integer: 1,
+            clock_accuracy_exponent: 1,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_0B);
+    }
+}
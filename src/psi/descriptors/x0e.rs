@@ -74,7 +74,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_0E);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc0E>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc0E>().unwrap();
         assert_eq!(desc.bitrate, 77500);
     }
 
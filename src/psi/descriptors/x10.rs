@@ -0,0 +1,96 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 8;
+
+
+/// The smoothing buffer descriptor conveys information about the size
+/// of a smoothing buffer and the leak rate out of that buffer.
+///
+/// ISO 13818-1 - 2.6.30
+#[derive(Debug, Default, Clone)]
+pub struct Desc10 {
+    /// Leak rate out of the buffer in units of 400 bits/s
+    pub sb_leak_rate: u32,
+    /// Size of the buffer in bytes
+    pub sb_size: u32,
+}
+
+
+impl Desc10 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            sb_leak_rate: slice[2 ..].get_u24() & 0x003F_FFFF,
+            sb_size: slice[5 ..].get_u24() & 0x003F_FFFF,
+        }
+    }
+}
+
+
+impl Desc for Desc10 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x10
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x10;
+        buffer[skip + 1] = (MIN_SIZE - 2) as u8;
+        buffer[skip + 2 ..].set_u24(0x00C0_0000 | (self.sb_leak_rate & 0x003F_FFFF));
+        buffer[skip + 5 ..].set_u24(0x00C0_0000 | (self.sb_size & 0x003F_FFFF));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc10,
+    };
+
+    static DATA_10: &[u8] = &[0x10, 0x06, 0xc0, 0x61, 0xa8, 0xc0, 0x07, 0xd0];
+
+    #[test]
+    fn test_10_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_10);
+
+        let desc = descriptors.find::<Desc10>().unwrap();
+        assert_eq!(desc.sb_leak_rate, 25000);
+        assert_eq!(desc.sb_size, 2000);
+    }
+
+    #[test]
+    fn test_10_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc10 {
+            sb_leak_rate: 25000,
+            sb_size: 2000,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_10);
+    }
+}
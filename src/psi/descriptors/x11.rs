@@ -0,0 +1,87 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The STD descriptor specifies the leak valid mode of the T-STD buffer
+/// for video elementary streams.
+///
+/// ISO 13818-1 - 2.6.32
+#[derive(Debug, Default, Clone)]
+pub struct Desc11 {
+    /// Transfer from the buffer uses the leak method
+    pub leak_valid_flag: u8,
+}
+
+
+impl Desc11 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            leak_valid_flag: slice[2] & 0x01,
+        }
+    }
+}
+
+
+impl Desc for Desc11 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x11
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x11);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(0xFE | self.leak_valid_flag);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc11,
+    };
+
+    static DATA_11: &[u8] = &[0x11, 0x01, 0xff];
+
+    #[test]
+    fn test_11_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_11);
+
+        let desc = descriptors.find::<Desc11>().unwrap();
+        assert_eq!(desc.leak_valid_flag, 1);
+    }
+
+    #[test]
+    fn test_11_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc11 {
+            leak_valid_flag: 1,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_11);
+    }
+}
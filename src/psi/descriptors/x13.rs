@@ -0,0 +1,226 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 7;
+
+/// Standard boot
+pub const CAROUSEL_FORMAT_STANDARD: u8 = 0x00;
+/// Enhanced boot with location of the ServiceGateway object
+pub const CAROUSEL_FORMAT_ENHANCED: u8 = 0x01;
+
+
+/// Location of the ServiceGateway object for the enhanced boot
+#[derive(Debug, Default, Clone)]
+pub struct Desc13e {
+    pub module_version: u8,
+    pub module_id: u16,
+    pub block_size: u16,
+    pub module_size: u32,
+    pub compression_method: u8,
+    pub original_size: u32,
+    /// Timeout in seconds to acquire the module
+    pub timeout: u8,
+    pub object_key: Vec<u8>,
+}
+
+
+/// The carousel identifier descriptor identifies the object carousel
+/// and provides the information to boot the carousel.
+///
+/// ISO 13818-6 - 11.4.1, TS 102 809 - B.2.8.1
+#[derive(Debug, Default, Clone)]
+pub struct Desc13 {
+    /// Carousel identifier. Same as in the ServiceGateway IOR
+    pub carousel_id: u32,
+    /// Format of the boot information:
+    /// * `0x00` - standard boot
+    /// * `0x01` - enhanced boot
+    pub format_id: u8,
+    /// Location of the ServiceGateway for the `format_id` 0x01
+    pub enhanced: Option<Desc13e>,
+    /// Private data bytes
+    pub private_data: Vec<u8>,
+}
+
+
+impl Desc13 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        if slice[6] == CAROUSEL_FORMAT_ENHANCED {
+            slice.len() >= MIN_SIZE + 16 &&
+            slice.len() >= MIN_SIZE + 16 + usize::from(slice[MIN_SIZE + 15])
+        } else {
+            true
+        }
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            carousel_id: slice[2 ..].get_u32(),
+            format_id: slice[6],
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        if result.format_id == CAROUSEL_FORMAT_ENHANCED {
+            let key_s = skip + 16;
+            let key_e = key_s + usize::from(slice[skip + 15]);
+            result.enhanced = Some(Desc13e {
+                module_version: slice[skip],
+                module_id: slice[skip + 1 ..].get_u16(),
+                block_size: slice[skip + 3 ..].get_u16(),
+                module_size: slice[skip + 5 ..].get_u32(),
+                compression_method: slice[skip + 9],
+                original_size: slice[skip + 10 ..].get_u32(),
+                timeout: slice[skip + 14],
+                object_key: Vec::from(&slice[key_s .. key_e]),
+            });
+            skip = key_e;
+        }
+
+        result.private_data.extend_from_slice(&slice[skip ..]);
+
+        result
+    }
+}
+
+
+impl Desc for Desc13 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x13
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE +
+        self.enhanced.as_ref().map(|v| 16 + v.object_key.len()).unwrap_or(0) +
+        self.private_data.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x13;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2 ..].set_u32(self.carousel_id);
+        buffer[skip + 6] = self.format_id;
+
+        if let Some(enhanced) = &self.enhanced {
+            let skip = buffer.len();
+            buffer.resize(skip + 16, 0x00);
+            buffer[skip] = enhanced.module_version;
+            buffer[skip + 1 ..].set_u16(enhanced.module_id);
+            buffer[skip + 3 ..].set_u16(enhanced.block_size);
+            buffer[skip + 5 ..].set_u32(enhanced.module_size);
+            buffer[skip + 9] = enhanced.compression_method;
+            buffer[skip + 10 ..].set_u32(enhanced.original_size);
+            buffer[skip + 14] = enhanced.timeout;
+            buffer[skip + 15] = enhanced.object_key.len() as u8;
+            buffer.extend_from_slice(&enhanced.object_key);
+        }
+
+        buffer.extend_from_slice(&self.private_data);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc13,
+        Desc13e,
+        CAROUSEL_FORMAT_STANDARD,
+        CAROUSEL_FORMAT_ENHANCED,
+    };
+
+    static DATA_13: &[u8] = &[0x13, 0x05, 0x00, 0x00, 0x00, 0x01, 0x00];
+
+    static DATA_13_ENHANCED: &[u8] = &[
+        0x13, 0x19, 0x00, 0x00, 0x00, 0x01, 0x01,
+        0x02, 0x00, 0x03, 0x0f, 0xe2, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x05, 0x04, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    #[test]
+    fn test_13_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_13);
+
+        let desc = descriptors.find::<Desc13>().unwrap();
+        assert_eq!(desc.carousel_id, 1);
+        assert_eq!(desc.format_id, CAROUSEL_FORMAT_STANDARD);
+        assert!(desc.enhanced.is_none());
+        assert!(desc.private_data.is_empty());
+    }
+
+    #[test]
+    fn test_13_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc13 {
+            carousel_id: 1,
+            format_id: CAROUSEL_FORMAT_STANDARD,
+            enhanced: None,
+            private_data: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_13);
+    }
+
+    #[test]
+    fn test_13_enhanced_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_13_ENHANCED);
+
+        let desc = descriptors.find::<Desc13>().unwrap();
+        assert_eq!(desc.format_id, CAROUSEL_FORMAT_ENHANCED);
+        let enhanced = desc.enhanced.as_ref().unwrap();
+        assert_eq!(enhanced.module_version, 2);
+        assert_eq!(enhanced.module_id, 3);
+        assert_eq!(enhanced.block_size, 4066);
+        assert_eq!(enhanced.module_size, 4096);
+        assert_eq!(enhanced.original_size, 4096);
+        assert_eq!(enhanced.timeout, 5);
+        assert_eq!(enhanced.object_key, [0x00, 0x00, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_13_enhanced_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc13 {
+            carousel_id: 1,
+            format_id: CAROUSEL_FORMAT_ENHANCED,
+            enhanced: Some(Desc13e {
+                module_version: 2,
+                module_id: 3,
+                block_size: 4066,
+                module_size: 4096,
+                compression_method: 0,
+                original_size: 4096,
+                timeout: 5,
+                object_key: vec![0x00, 0x00, 0x00, 0x01],
+            }),
+            private_data: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_13_ENHANCED);
+    }
+}
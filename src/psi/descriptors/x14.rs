@@ -0,0 +1,133 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 7;
+
+/// Stream carries DSI/DII with transaction_id and timeout in the selector
+pub const ASSOCIATION_USE_DSI: u16 = 0x0000;
+/// Program stream association
+pub const ASSOCIATION_USE_PROGRAM: u16 = 0x0001;
+
+
+/// The association tag descriptor binds the association tag used in
+/// the DSM-CC messages with the elementary stream.
+///
+/// ISO 13818-6 - 11.4.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc14 {
+    /// Association tag of the stream
+    pub association_tag: u16,
+    /// Usage of the association:
+    /// * `0x0000` - selector contains transaction_id and timeout
+    /// * `0x0001` - program association, empty selector
+    pub tag_use: u16,
+    /// Selector bytes
+    pub selector: Vec<u8>,
+    /// Private data bytes
+    pub private_data: Vec<u8>,
+}
+
+
+impl Desc14 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        slice.len() >= MIN_SIZE + usize::from(slice[6])
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let selector_e = MIN_SIZE + usize::from(slice[6]);
+
+        Self {
+            association_tag: slice[2 ..].get_u16(),
+            tag_use: slice[4 ..].get_u16(),
+            selector: Vec::from(&slice[MIN_SIZE .. selector_e]),
+            private_data: Vec::from(&slice[selector_e ..]),
+        }
+    }
+
+    /// Returns transaction_id and timeout in microseconds
+    /// from the selector with use 0x0000
+    pub fn get_transaction(&self) -> Option<(u32, u32)> {
+        if self.tag_use == ASSOCIATION_USE_DSI && self.selector.len() == 8 {
+            Some((self.selector.get_u32(), self.selector[4 ..].get_u32()))
+        } else {
+            None
+        }
+    }
+}
+
+
+impl Desc for Desc14 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x14
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.selector.len() + self.private_data.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x14;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.association_tag);
+        buffer[skip + 4 ..].set_u16(self.tag_use);
+        buffer[skip + 6] = self.selector.len() as u8;
+        buffer.extend_from_slice(&self.selector);
+        buffer.extend_from_slice(&self.private_data);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc14,
+        ASSOCIATION_USE_DSI,
+    };
+
+    static DATA_14: &[u8] = &[
+        0x14, 0x0d, 0x00, 0x0b, 0x00, 0x00, 0x08, 0x80, 0x00, 0x00, 0x02, 0xff, 0xff, 0xff,
+        0xff,
+    ];
+
+    #[test]
+    fn test_14_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_14);
+
+        let desc = descriptors.find::<Desc14>().unwrap();
+        assert_eq!(desc.association_tag, 0x000B);
+        assert_eq!(desc.tag_use, ASSOCIATION_USE_DSI);
+        assert_eq!(desc.get_transaction(), Some((0x8000_0002, 0xFFFF_FFFF)));
+        assert!(desc.private_data.is_empty());
+    }
+
+    #[test]
+    fn test_14_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc14 {
+            association_tag: 0x000B,
+            tag_use: ASSOCIATION_USE_DSI,
+            selector: vec![0x80, 0x00, 0x00, 0x02, 0xff, 0xff, 0xff, 0xff],
+            private_data: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_14);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The MPEG-4 audio descriptor provides basic information for identifying
+/// the coding parameters of an ISO 14496-3 audio elementary stream.
+///
+/// ISO 13818-1 - 2.6.38
+#[derive(Debug, Default, Clone)]
+pub struct Desc1C {
+    /// Profile and level of the audio stream (ISO 14496-3)
+    pub profile_and_level: u8,
+}
+
+
+impl Desc1C {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            profile_and_level: slice[2],
+        }
+    }
+}
+
+
+impl Desc for Desc1C {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x1C
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x1C);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(self.profile_and_level);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc1C,
+    };
+
+    static DATA_1C: &[u8] = &[0x1c, 0x01, 0x58];
+
+    #[test]
+    fn test_1c_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_1C);
+
+        let desc = descriptors.find::<Desc1C>().unwrap();
+        assert_eq!(desc.profile_and_level, 0x58);
+    }
+
+    #[test]
+    fn test_1c_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc1C {
+            profile_and_level: 0x58,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_1C);
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 6;
+
+
+/// The AVC video descriptor provides basic information for identifying
+/// coding parameters of the ISO 14496-10 (H.264) video stream.
+///
+/// ISO 13818-1 - 2.6.64
+#[derive(Debug, Default, Clone)]
+pub struct Desc28 {
+    /// Profile as defined in ISO 14496-10
+    pub profile_idc: u8,
+    /// constraint_set0_flag .. constraint_set5_flag and AVC_compatible_flags
+    pub constraint_flags: u8,
+    /// Level as defined in ISO 14496-10
+    pub level_idc: u8,
+    /// Stream may include AVC still pictures
+    pub still_present: u8,
+    /// Stream may contain AVC 24-hour pictures
+    pub picture_24_hour_flag: u8,
+    /// Frame packing arrangement SEI messages are not present
+    pub frame_packing_sei_not_present_flag: u8,
+}
+
+
+impl Desc28 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            profile_idc: slice[2],
+            constraint_flags: slice[3],
+            level_idc: slice[4],
+            still_present: slice[5] >> 7,
+            picture_24_hour_flag: (slice[5] >> 6) & 0x01,
+            frame_packing_sei_not_present_flag: (slice[5] >> 5) & 0x01,
+        }
+    }
+}
+
+
+impl Desc for Desc28 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x28
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x28);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(self.profile_idc);
+        buffer.push(self.constraint_flags);
+        buffer.push(self.level_idc);
+        buffer.push(set_bits!(8,
+            self.still_present, 1,
+            self.picture_24_hour_flag, 1,
+            self.frame_packing_sei_not_present_flag, 1,
+            0x1F, 5));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc28,
+    };
+
+    static DATA_28: &[u8] = &[0x28, 0x04, 0x64, 0x00, 0x28, 0x3f];
+
+    #[test]
+    fn test_28_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_28);
+
+        let desc = descriptors.find::<Desc28>().unwrap();
+        assert_eq!(desc.profile_idc, 100);
+        assert_eq!(desc.constraint_flags, 0);
+        assert_eq!(desc.level_idc, 40);
+        assert_eq!(desc.still_present, 0);
+        assert_eq!(desc.picture_24_hour_flag, 0);
+        assert_eq!(desc.frame_packing_sei_not_present_flag, 1);
+    }
+
+    #[test]
+    fn test_28_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc28 {
+            profile_idc: 100,
+            constraint_flags: 0,
+            level_idc: 40,
+            still_present: 0,
+            picture_24_hour_flag: 0,
+            frame_packing_sei_not_present_flag: 1,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_28);
+    }
+}
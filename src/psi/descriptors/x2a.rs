@@ -0,0 +1,190 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 4;
+
+
+/// Picture and timing info
+#[derive(Debug, Default, Clone)]
+pub struct Desc2Ai {
+    /// AVC time base is 90 kHz. If not set `n` and `k` defines time base
+    pub clock_90khz_flag: u8,
+    /// Time base frequency is 27 MHz * N / K
+    pub n: u32,
+    /// Time base frequency is 27 MHz * N / K
+    pub k: u32,
+    /// Number of time units of a clock operating at the time base frequency
+    pub num_units_in_tick: u32,
+}
+
+
+/// The AVC timing and HRD descriptor provides timing and HRD parameters
+/// of the associated AVC video stream.
+///
+/// ISO 13818-1 - 2.6.66
+#[derive(Debug, Default, Clone)]
+pub struct Desc2A {
+    /// HRD Buffering Period SEI messages are present
+    pub hrd_management_valid_flag: u8,
+    /// Picture and timing info
+    pub timing: Option<Desc2Ai>,
+    /// Stream has a fixed frame rate
+    pub fixed_frame_rate_flag: u8,
+    /// Presentation time is based on the picture order count
+    pub temporal_poc_flag: u8,
+    /// Stream has picture to display conversion info
+    pub picture_to_display_conversion_flag: u8,
+}
+
+
+impl Desc2A {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut size = MIN_SIZE;
+        if (slice[2] & 0x01) != 0 {
+            size += 1 + 4;
+            if (slice[3] & 0x80) == 0 {
+                size += 8;
+            }
+        }
+        slice.len() == size
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            hrd_management_valid_flag: slice[2] >> 7,
+            .. Default::default()
+        };
+
+        let mut skip = 3;
+        if (slice[2] & 0x01) != 0 {
+            let mut timing = Desc2Ai {
+                clock_90khz_flag: slice[skip] >> 7,
+                .. Default::default()
+            };
+            skip += 1;
+            if timing.clock_90khz_flag == 0 {
+                timing.n = slice[skip ..].get_u32();
+                timing.k = slice[skip + 4 ..].get_u32();
+                skip += 8;
+            }
+            timing.num_units_in_tick = slice[skip ..].get_u32();
+            skip += 4;
+            result.timing = Some(timing);
+        }
+
+        result.fixed_frame_rate_flag = slice[skip] >> 7;
+        result.temporal_poc_flag = (slice[skip] >> 6) & 0x01;
+        result.picture_to_display_conversion_flag = (slice[skip] >> 5) & 0x01;
+
+        result
+    }
+}
+
+
+impl Desc for Desc2A {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x2A
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + match &self.timing {
+            Some(v) if v.clock_90khz_flag == 0 => 1 + 8 + 4,
+            Some(_) => 1 + 4,
+            None => 0,
+        }
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x2A);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.hrd_management_valid_flag, 1,
+            0x3F, 6,
+            u8::from(self.timing.is_some()), 1));
+
+        if let Some(timing) = &self.timing {
+            buffer.push(set_bits!(8,
+                timing.clock_90khz_flag, 1,
+                0x7F, 7));
+
+            let skip = buffer.len();
+            if timing.clock_90khz_flag == 0 {
+                buffer.resize(skip + 12, 0x00);
+                buffer[skip ..].set_u32(timing.n);
+                buffer[skip + 4 ..].set_u32(timing.k);
+                buffer[skip + 8 ..].set_u32(timing.num_units_in_tick);
+            } else {
+                buffer.resize(skip + 4, 0x00);
+                buffer[skip ..].set_u32(timing.num_units_in_tick);
+            }
+        }
+
+        buffer.push(set_bits!(8,
+            self.fixed_frame_rate_flag, 1,
+            self.temporal_poc_flag, 1,
+            self.picture_to_display_conversion_flag, 1,
+            0x1F, 5));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc2A,
+        Desc2Ai,
+    };
+
+    static DATA_2A: &[u8] = &[0x2a, 0x07, 0x7f, 0xff, 0x00, 0x00, 0x07, 0x08, 0x9f];
+
+    #[test]
+    fn test_2a_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_2A);
+
+        let desc = descriptors.find::<Desc2A>().unwrap();
+        assert_eq!(desc.hrd_management_valid_flag, 0);
+        let timing = desc.timing.as_ref().unwrap();
+        assert_eq!(timing.clock_90khz_flag, 1);
+        assert_eq!(timing.num_units_in_tick, 1800);
+        assert_eq!(desc.fixed_frame_rate_flag, 1);
+        assert_eq!(desc.temporal_poc_flag, 0);
+        assert_eq!(desc.picture_to_display_conversion_flag, 0);
+    }
+
+    #[test]
+    fn test_2a_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc2A {
+            hrd_management_valid_flag: 0,
+            timing: Some(Desc2Ai {
+                clock_90khz_flag: 1,
+                num_units_in_tick: 1800,
+                .. Default::default()
+            }),
+            fixed_frame_rate_flag: 1,
+            temporal_poc_flag: 0,
+            picture_to_display_conversion_flag: 0,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_2A);
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 5;
+
+
+/// The MPEG-2 AAC audio descriptor provides basic information for
+/// identifying the coding parameters of an ISO 13818-7 audio stream.
+///
+/// ISO 13818-1 - 2.6.68
+#[derive(Debug, Default, Clone)]
+pub struct Desc2B {
+    /// AAC profile as defined in ISO 13818-7
+    pub profile: u8,
+    /// Number and configuration of the audio channels
+    pub channel_configuration: u8,
+    /// Additional information: 0 - AAC, 1 - AAC with BSAC
+    pub additional_information: u8,
+}
+
+
+impl Desc2B {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            profile: slice[2],
+            channel_configuration: slice[3],
+            additional_information: slice[4],
+        }
+    }
+}
+
+
+impl Desc for Desc2B {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x2B
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x2B);
+        buffer.push((MIN_SIZE - 2) as u8);
+        buffer.push(self.profile);
+        buffer.push(self.channel_configuration);
+        buffer.push(self.additional_information);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc2B,
+    };
+
+    static DATA_2B: &[u8] = &[0x2b, 0x03, 0x01, 0x02, 0x00];
+
+    #[test]
+    fn test_2b_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_2B);
+
+        let desc = descriptors.find::<Desc2B>().unwrap();
+        assert_eq!(desc.profile, 1);
+        assert_eq!(desc.channel_configuration, 2);
+        assert_eq!(desc.additional_information, 0);
+    }
+
+    #[test]
+    fn test_2b_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc2B {
+            profile: 1,
+            channel_configuration: 2,
+            additional_information: 0,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_2B);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 15;
+
+
+/// Range of the temporal sub-layers
+#[derive(Debug, Default, Clone)]
+pub struct Desc38i {
+    /// Minimum value of the TemporalId
+    pub temporal_id_min: u8,
+    /// Maximum value of the TemporalId
+    pub temporal_id_max: u8,
+}
+
+
+/// The HEVC video descriptor provides basic information for identifying
+/// coding parameters of the ISO 23008-2 (H.265) video stream.
+///
+/// ISO 13818-1 - 2.6.95
+#[derive(Debug, Default, Clone)]
+pub struct Desc38 {
+    /// Profile space as defined in ISO 23008-2
+    pub profile_space: u8,
+    /// Tier as defined in ISO 23008-2
+    pub tier_flag: u8,
+    /// Profile as defined in ISO 23008-2
+    pub profile_idc: u8,
+    /// Profile compatibility flags
+    pub profile_compatibility_indication: u32,
+    /// Stream contains progressive pictures
+    pub progressive_source_flag: u8,
+    /// Stream contains interlaced pictures
+    pub interlaced_source_flag: u8,
+    /// No frame packing arrangement SEI messages
+    pub non_packed_constraint_flag: u8,
+    /// Stream contains only frames
+    pub frame_only_constraint_flag: u8,
+    /// general_reserved_zero_44bits or profile specific constraint flags
+    pub copied_44bits: u64,
+    /// Level as defined in ISO 23008-2
+    pub level_idc: u8,
+    /// Stream may include HEVC still pictures
+    pub still_present_flag: u8,
+    /// Stream may contain HEVC 24-hour pictures
+    pub picture_24hr_present_flag: u8,
+    /// Sub-picture HRD parameters are not present
+    pub sub_pic_hrd_params_not_present_flag: u8,
+    /// HDR and WCG: 0 - SDR, 1 - WCG, 2 - HDR and WCG, 3 - not indicated
+    pub hdr_wcg_idc: u8,
+    /// Temporal sub-layers subset
+    pub temporal_layer: Option<Desc38i>,
+}
+
+
+impl Desc38 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let temporal_layer_subset_flag = (slice[14] & 0x80) != 0;
+        slice.len() == if temporal_layer_subset_flag { MIN_SIZE + 2 } else { MIN_SIZE }
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let flags = (u64::from(slice[7 ..].get_u16()) << 32) | u64::from(slice[9 ..].get_u32());
+
+        let mut result = Self {
+            profile_space: slice[2] >> 6,
+            tier_flag: (slice[2] >> 5) & 0x01,
+            profile_idc: slice[2] & 0x1F,
+            profile_compatibility_indication: slice[3 ..].get_u32(),
+            progressive_source_flag: slice[7] >> 7,
+            interlaced_source_flag: (slice[7] >> 6) & 0x01,
+            non_packed_constraint_flag: (slice[7] >> 5) & 0x01,
+            frame_only_constraint_flag: (slice[7] >> 4) & 0x01,
+            copied_44bits: flags & 0x0FFF_FFFF_FFFF,
+            level_idc: slice[13],
+            still_present_flag: (slice[14] >> 6) & 0x01,
+            picture_24hr_present_flag: (slice[14] >> 5) & 0x01,
+            sub_pic_hrd_params_not_present_flag: (slice[14] >> 4) & 0x01,
+            hdr_wcg_idc: slice[14] & 0x03,
+            temporal_layer: None,
+        };
+
+        if (slice[14] & 0x80) != 0 {
+            result.temporal_layer = Some(Desc38i {
+                temporal_id_min: slice[15] >> 5,
+                temporal_id_max: slice[16] >> 5,
+            });
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc38 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x38
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.temporal_layer.as_ref().map_or(0, |_| 2)
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x38;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2] = set_bits!(8,
+            self.profile_space, 2,
+            self.tier_flag, 1,
+            self.profile_idc, 5);
+        buffer[skip + 3 ..].set_u32(self.profile_compatibility_indication);
+
+        let flags =
+            (u64::from(self.progressive_source_flag) << 47) |
+            (u64::from(self.interlaced_source_flag) << 46) |
+            (u64::from(self.non_packed_constraint_flag) << 45) |
+            (u64::from(self.frame_only_constraint_flag) << 44) |
+            (self.copied_44bits & 0x0FFF_FFFF_FFFF);
+        buffer[skip + 7 ..].set_u16((flags >> 32) as u16);
+        buffer[skip + 9 ..].set_u32(flags as u32);
+
+        buffer[skip + 13] = self.level_idc;
+        buffer[skip + 14] = set_bits!(8,
+            u8::from(self.temporal_layer.is_some()), 1,
+            self.still_present_flag, 1,
+            self.picture_24hr_present_flag, 1,
+            self.sub_pic_hrd_params_not_present_flag, 1,
+            0x03, 2,
+            self.hdr_wcg_idc, 2);
+
+        if let Some(temporal_layer) = &self.temporal_layer {
+            buffer.push(set_bits!(8,
+                temporal_layer.temporal_id_min, 3,
+                0x1F, 5));
+            buffer.push(set_bits!(8,
+                temporal_layer.temporal_id_max, 3,
+                0x1F, 5));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc38,
+    };
+
+    static DATA_38: &[u8] = &[
+        0x38, 0x0d, 0x01, 0x60, 0x00, 0x00, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x78,
+        0x1f,
+    ];
+
+    #[test]
+    fn test_38_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_38);
+
+        let desc = descriptors.find::<Desc38>().unwrap();
+        assert_eq!(desc.profile_space, 0);
+        assert_eq!(desc.tier_flag, 0);
+        assert_eq!(desc.profile_idc, 1);
+        assert_eq!(desc.profile_compatibility_indication, 0x6000_0000);
+        assert_eq!(desc.progressive_source_flag, 1);
+        assert_eq!(desc.interlaced_source_flag, 0);
+        assert_eq!(desc.non_packed_constraint_flag, 1);
+        assert_eq!(desc.frame_only_constraint_flag, 1);
+        assert_eq!(desc.copied_44bits, 0);
+        assert_eq!(desc.level_idc, 120);
+        assert_eq!(desc.sub_pic_hrd_params_not_present_flag, 1);
+        assert_eq!(desc.hdr_wcg_idc, 3);
+        assert!(desc.temporal_layer.is_none());
+    }
+
+    #[test]
+    fn test_38_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc38 {
+            profile_idc: 1,
+            profile_compatibility_indication: 0x6000_0000,
+            progressive_source_flag: 1,
+            non_packed_constraint_flag: 1,
+            frame_only_constraint_flag: 1,
+            level_idc: 120,
+            sub_pic_hrd_params_not_present_flag: 1,
+            hdr_wcg_idc: 3,
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_38);
+    }
+}
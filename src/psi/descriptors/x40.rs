@@ -70,7 +70,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_40);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc40>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc40>().unwrap();
         assert_eq!(desc.name, textcode::StringDVB::from_str("Cesbo", 5));
     }
 
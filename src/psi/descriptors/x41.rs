@@ -97,7 +97,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_41);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc41>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc41>().unwrap();
         let mut items = desc.items.iter();
         let item = items.next().unwrap();
         assert_eq!(item.service_id, 8581);
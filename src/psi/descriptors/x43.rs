@@ -113,7 +113,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_43);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc43>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc43>().unwrap();
         assert_eq!(desc.frequency, 12380000);
         assert_eq!(desc.orbital_position, 780);
         assert_eq!(desc.west_east_flag, constants::POSITION_EAST);
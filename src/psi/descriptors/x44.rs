@@ -97,7 +97,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_44);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc44>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc44>().unwrap();
         assert_eq!(desc.frequency, 346000000);
         assert_eq!(desc.fec_outer, constants::FEC_OUTER_NOT_DEFINED);
         assert_eq!(desc.modulation, constants::MODULATION_DVB_C_256_QAM);
@@ -11,6 +11,23 @@ use super::Desc;
 
 const MIN_SIZE: usize = 5;
 
+/// NVOD reference service
+pub const SERVICE_TYPE_NVOD_REFERENCE: u8 = 0x04;
+/// NVOD time-shifted service
+pub const SERVICE_TYPE_NVOD_TIME_SHIFTED: u8 = 0x05;
+/// H.264/AVC SD NVOD time-shifted service
+pub const SERVICE_TYPE_AVC_SD_NVOD_TIME_SHIFTED: u8 = 0x17;
+/// H.264/AVC SD NVOD reference service
+pub const SERVICE_TYPE_AVC_SD_NVOD_REFERENCE: u8 = 0x18;
+/// H.264/AVC HD NVOD time-shifted service
+pub const SERVICE_TYPE_AVC_HD_NVOD_TIME_SHIFTED: u8 = 0x1A;
+/// H.264/AVC HD NVOD reference service
+pub const SERVICE_TYPE_AVC_HD_NVOD_REFERENCE: u8 = 0x1B;
+/// H.264/AVC frame compatible plano-stereoscopic HD NVOD time-shifted service
+pub const SERVICE_TYPE_AVC_3D_NVOD_TIME_SHIFTED: u8 = 0x1D;
+/// H.264/AVC frame compatible plano-stereoscopic HD NVOD reference service
+pub const SERVICE_TYPE_AVC_3D_NVOD_REFERENCE: u8 = 0x1E;
+
 
 /// The service descriptor provides the names of the service provider
 /// and the service in text form together with the service_type.
@@ -51,6 +68,24 @@ impl Desc48 {
             name: StringDVB::from(&slice[name_s .. name_e]),
         }
     }
+
+    /// Returns `true` if service is an NVOD reference service
+    pub fn is_nvod_reference(&self) -> bool {
+        matches!(self.service_type,
+            SERVICE_TYPE_NVOD_REFERENCE |
+            SERVICE_TYPE_AVC_SD_NVOD_REFERENCE |
+            SERVICE_TYPE_AVC_HD_NVOD_REFERENCE |
+            SERVICE_TYPE_AVC_3D_NVOD_REFERENCE)
+    }
+
+    /// Returns `true` if service is an NVOD time-shifted service
+    pub fn is_nvod_time_shifted(&self) -> bool {
+        matches!(self.service_type,
+            SERVICE_TYPE_NVOD_TIME_SHIFTED |
+            SERVICE_TYPE_AVC_SD_NVOD_TIME_SHIFTED |
+            SERVICE_TYPE_AVC_HD_NVOD_TIME_SHIFTED |
+            SERVICE_TYPE_AVC_3D_NVOD_TIME_SHIFTED)
+    }
 }
 
 
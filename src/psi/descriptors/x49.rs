@@ -0,0 +1,124 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::{
+    country,
+    StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The country availability descriptor identifies countries that are
+/// either intended or not intended to receive the service.
+///
+/// EN 300 468 - 6.2.10
+#[derive(Debug, Default, Clone)]
+pub struct Desc49 {
+    /// * `true` - service intended for reception in the listed countries
+    /// * `false` - service not intended for reception in the listed countries
+    pub country_availability_flag: bool,
+    /// List of ISO 3166 alpha-3 country codes
+    pub countries: Vec<StringDVB>,
+}
+
+
+impl Desc49 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - MIN_SIZE) % 3) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            country_availability_flag: (slice[2] & 0x80) != 0,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            result.countries.push(StringDVB::from(&slice[skip .. skip + 3]));
+            skip += 3;
+        }
+
+        result
+    }
+
+    /// Returns `true` if country defined by ISO 3166 code is in the list
+    pub fn contains(&self, code: &str) -> bool {
+        self.countries.iter().any(|item| country::is_same(&item.to_string(), code))
+    }
+}
+
+
+impl Desc for Desc49 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x49
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.countries.len() * 3
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x49);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(if self.country_availability_flag { 0xFF } else { 0x7F });
+
+        for item in &self.countries {
+            item.assemble(buffer);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc49,
+        },
+    };
+
+    static DATA_49: &[u8] = &[0x49, 0x07, 0xff, 0x46, 0x49, 0x4e, 0x53, 0x57, 0x45];
+
+    #[test]
+    fn test_49_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_49);
+
+        let desc = descriptors.find::<Desc49>().unwrap();
+        assert!(desc.country_availability_flag);
+        assert_eq!(desc.countries.len(), 2);
+        assert!(desc.contains("FIN"));
+        assert!(desc.contains("se"));
+        assert!(! desc.contains("NOR"));
+    }
+
+    #[test]
+    fn test_49_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc49 {
+            country_availability_flag: true,
+            countries: vec![
+                textcode::StringDVB::from_str("FIN", textcode::ISO6937),
+                textcode::StringDVB::from_str("SWE", textcode::ISO6937),
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_49);
+    }
+}
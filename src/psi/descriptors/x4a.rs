@@ -0,0 +1,593 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 9;
+
+
+/// Service which carries the information
+pub const LINKAGE_INFORMATION_SERVICE: u8 = 0x01;
+/// Mobile hand-over to the other service
+pub const LINKAGE_MOBILE_HAND_OVER: u8 = 0x08;
+/// System software update service
+pub const LINKAGE_SYSTEM_SOFTWARE_UPDATE: u8 = 0x09;
+/// Event linkage
+pub const LINKAGE_EVENT: u8 = 0x0D;
+/// Extended event linkage
+pub const LINKAGE_EXTENDED_EVENT: u8 = 0x0E;
+
+
+/// Mobile hand-over info
+#[derive(Debug, Default, Clone)]
+pub struct Desc4AHandOver {
+    /// Type of hand-over: 1 - identical service, 2 - local variation, 3 - associated service
+    pub hand_over_type: u8,
+    /// Origin of the descriptor: 0 - NIT, 1 - SDT
+    pub origin_type: u8,
+    /// Network identifier. Defined for hand_over_type 1, 2 or 3
+    pub network_id: u16,
+    /// Service identifier of the service containing the linkage. Defined for origin_type 0
+    pub initial_service_id: u16,
+}
+
+
+impl Desc4AHandOver {
+    #[inline]
+    fn has_network_id(&self) -> bool {
+        (1 ..= 3).contains(&self.hand_over_type)
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        1 +
+            if self.has_network_id() { 2 } else { 0 } +
+            if self.origin_type == 0 { 2 } else { 0 }
+    }
+}
+
+
+/// Manufacturer of the system software update
+#[derive(Debug, Default, Clone)]
+pub struct Desc4AOui {
+    /// IEEE OUI of the manufacturer
+    pub oui: u32,
+    /// Selector bytes
+    pub selector: Vec<u8>,
+}
+
+
+/// Event linkage info
+#[derive(Debug, Default, Clone)]
+pub struct Desc4AEvent {
+    /// Identifies the event in the target service
+    pub target_event_id: u16,
+    /// Target service is included in the SDT
+    pub target_listed: u8,
+    /// Target event is simulcast
+    pub event_simulcast: u8,
+}
+
+
+/// Extended event linkage info
+#[derive(Debug, Default, Clone)]
+pub struct Desc4AExtendedEvent {
+    /// Identifies the event in the target service
+    pub target_event_id: u16,
+    /// Target service is included in the SDT
+    pub target_listed: u8,
+    /// Target event is simulcast
+    pub event_simulcast: u8,
+    /// Type of the target service: 0 - SD, 1 - HD, 2 - frame compatible 3D, ...
+    pub link_type: u8,
+    /// Target identification:
+    /// * `0` - transport_stream_id of the linkage descriptor
+    /// * `1` - `target_id` is target_transport_stream_id
+    /// * `2` - any transport stream
+    /// * `3` - `target_id` is user_defined_id
+    pub target_id_type: u8,
+    /// Target transport stream or user defined identifier
+    pub target_id: u16,
+    /// Target original network identifier
+    pub target_onid: Option<u16>,
+    /// Target service identifier
+    pub target_service_id: Option<u16>,
+}
+
+
+impl Desc4AExtendedEvent {
+    #[inline]
+    fn size(&self) -> usize {
+        if self.target_id_type == 3 {
+            3 + 2
+        } else {
+            3 +
+                if self.target_id_type == 1 { 2 } else { 0 } +
+                self.target_onid.map_or(0, |_| 2) +
+                self.target_service_id.map_or(0, |_| 2)
+        }
+    }
+}
+
+
+/// Linkage type specific info
+#[derive(Debug, Clone)]
+pub enum Desc4AInfo {
+    /// Linkage type without additional info or with invalid info.
+    /// Info is stored in the private_data
+    Raw,
+    /// `LINKAGE_MOBILE_HAND_OVER`
+    MobileHandOver(Desc4AHandOver),
+    /// `LINKAGE_SYSTEM_SOFTWARE_UPDATE`
+    SystemSoftwareUpdate(Vec<Desc4AOui>),
+    /// `LINKAGE_EVENT`
+    Event(Desc4AEvent),
+    /// `LINKAGE_EXTENDED_EVENT`
+    ExtendedEvent(Vec<Desc4AExtendedEvent>),
+}
+
+
+impl Default for Desc4AInfo {
+    #[inline]
+    fn default() -> Self { Desc4AInfo::Raw }
+}
+
+
+impl Desc4AInfo {
+    /// Parses linkage type specific info. Returns info and size of the info
+    fn parse(linkage_type: u8, slice: &[u8]) -> Option<(Self, usize)> {
+        match linkage_type {
+            LINKAGE_MOBILE_HAND_OVER => {
+                let mut info = Desc4AHandOver {
+                    hand_over_type: *slice.first()? >> 4,
+                    origin_type: slice[0] & 0x01,
+                    .. Default::default()
+                };
+                let size = info.size();
+                if slice.len() < size {
+                    return None;
+                }
+
+                let mut skip = 1;
+                if info.has_network_id() {
+                    info.network_id = slice[skip ..].get_u16();
+                    skip += 2;
+                }
+                if info.origin_type == 0 {
+                    info.initial_service_id = slice[skip ..].get_u16();
+                }
+
+                Some((Desc4AInfo::MobileHandOver(info), size))
+            }
+            LINKAGE_SYSTEM_SOFTWARE_UPDATE => {
+                let end = 1 + usize::from(*slice.first()?);
+                if slice.len() < end {
+                    return None;
+                }
+
+                let mut items = Vec::new();
+                let mut skip = 1;
+                while end > skip {
+                    if end < skip + 4 {
+                        return None;
+                    }
+                    let next = skip + 4 + usize::from(slice[skip + 3]);
+                    if end < next {
+                        return None;
+                    }
+                    items.push(Desc4AOui {
+                        oui: slice[skip ..].get_u24(),
+                        selector: Vec::from(&slice[skip + 4 .. next]),
+                    });
+                    skip = next;
+                }
+
+                Some((Desc4AInfo::SystemSoftwareUpdate(items), end))
+            }
+            LINKAGE_EVENT => {
+                if slice.len() < 3 {
+                    return None;
+                }
+
+                let info = Desc4AEvent {
+                    target_event_id: slice.get_u16(),
+                    target_listed: slice[2] >> 7,
+                    event_simulcast: (slice[2] >> 6) & 0x01,
+                };
+
+                Some((Desc4AInfo::Event(info), 3))
+            }
+            LINKAGE_EXTENDED_EVENT => {
+                let end = 1 + usize::from(*slice.first()?);
+                if slice.len() < end {
+                    return None;
+                }
+
+                let mut items = Vec::new();
+                let mut skip = 1;
+                while end > skip {
+                    if end < skip + 3 {
+                        return None;
+                    }
+
+                    let flags = slice[skip + 2];
+                    let mut item = Desc4AExtendedEvent {
+                        target_event_id: slice[skip ..].get_u16(),
+                        target_listed: flags >> 7,
+                        event_simulcast: (flags >> 6) & 0x01,
+                        link_type: (flags >> 4) & 0x03,
+                        target_id_type: (flags >> 2) & 0x03,
+                        .. Default::default()
+                    };
+                    if item.target_id_type != 3 {
+                        if (flags & 0x02) != 0 {
+                            item.target_onid = Some(0);
+                        }
+                        if (flags & 0x01) != 0 {
+                            item.target_service_id = Some(0);
+                        }
+                    }
+
+                    let next = skip + item.size();
+                    if end < next {
+                        return None;
+                    }
+
+                    skip += 3;
+                    if item.target_id_type == 1 || item.target_id_type == 3 {
+                        item.target_id = slice[skip ..].get_u16();
+                        skip += 2;
+                    }
+                    if item.target_onid.is_some() {
+                        item.target_onid = Some(slice[skip ..].get_u16());
+                        skip += 2;
+                    }
+                    if item.target_service_id.is_some() {
+                        item.target_service_id = Some(slice[skip ..].get_u16());
+                        skip += 2;
+                    }
+
+                    items.push(item);
+                }
+
+                Some((Desc4AInfo::ExtendedEvent(items), end))
+            }
+            _ => None,
+        }
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Desc4AInfo::Raw => 0,
+            Desc4AInfo::MobileHandOver(v) => v.size(),
+            Desc4AInfo::SystemSoftwareUpdate(v) => {
+                v.iter().fold(1, |acc, item| acc + 4 + item.selector.len())
+            }
+            Desc4AInfo::Event(_) => 3,
+            Desc4AInfo::ExtendedEvent(v) => {
+                v.iter().fold(1, |acc, item| acc + item.size())
+            }
+        }
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        match self {
+            Desc4AInfo::Raw => {}
+            Desc4AInfo::MobileHandOver(v) => {
+                buffer.push(set_bits!(8,
+                    v.hand_over_type, 4,
+                    0x07, 3,
+                    v.origin_type, 1));
+
+                let skip = buffer.len();
+                buffer.resize(skip + v.size() - 1, 0x00);
+                let mut skip = skip;
+                if v.has_network_id() {
+                    buffer[skip ..].set_u16(v.network_id);
+                    skip += 2;
+                }
+                if v.origin_type == 0 {
+                    buffer[skip ..].set_u16(v.initial_service_id);
+                }
+            }
+            Desc4AInfo::SystemSoftwareUpdate(v) => {
+                buffer.push((self.size() - 1) as u8);
+                for item in v {
+                    let skip = buffer.len();
+                    buffer.resize(skip + 4, 0x00);
+                    buffer[skip ..].set_u24(item.oui);
+                    buffer[skip + 3] = item.selector.len() as u8;
+                    buffer.extend_from_slice(&item.selector);
+                }
+            }
+            Desc4AInfo::Event(v) => {
+                let skip = buffer.len();
+                buffer.resize(skip + 3, 0x00);
+                buffer[skip ..].set_u16(v.target_event_id);
+                buffer[skip + 2] = set_bits!(8,
+                    v.target_listed, 1,
+                    v.event_simulcast, 1,
+                    0x3F, 6);
+            }
+            Desc4AInfo::ExtendedEvent(v) => {
+                buffer.push((self.size() - 1) as u8);
+                for item in v {
+                    let skip = buffer.len();
+                    buffer.resize(skip + item.size(), 0x00);
+                    buffer[skip ..].set_u16(item.target_event_id);
+
+                    let user_defined = item.target_id_type == 3;
+                    buffer[skip + 2] = set_bits!(8,
+                        item.target_listed, 1,
+                        item.event_simulcast, 1,
+                        item.link_type, 2,
+                        item.target_id_type, 2,
+                        u8::from(! user_defined && item.target_onid.is_some()), 1,
+                        u8::from(! user_defined && item.target_service_id.is_some()), 1);
+
+                    let mut skip = skip + 3;
+                    if item.target_id_type == 1 || user_defined {
+                        buffer[skip ..].set_u16(item.target_id);
+                        skip += 2;
+                    }
+                    if user_defined {
+                        continue;
+                    }
+                    if let Some(onid) = item.target_onid {
+                        buffer[skip ..].set_u16(onid);
+                        skip += 2;
+                    }
+                    if let Some(service_id) = item.target_service_id {
+                        buffer[skip ..].set_u16(service_id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+/// The linkage descriptor identifies a service that can be presented if
+/// the consumer requests additional information related to a specific
+/// entity described by the SI system.
+///
+/// EN 300 468 - 6.2.19
+#[derive(Debug, Default, Clone)]
+pub struct Desc4A {
+    /// Transport stream identifier of the information service
+    pub tsid: u16,
+    /// Original network identifier of the information service
+    pub onid: u16,
+    /// Service identifier of the information service
+    pub service_id: u16,
+    /// Type of linkage
+    pub linkage_type: u8,
+    /// Linkage type specific info
+    pub info: Desc4AInfo,
+    /// Private data
+    pub private_data: Vec<u8>,
+}
+
+
+impl Desc4A {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let linkage_type = slice[8];
+        let (info, size) = Desc4AInfo::parse(linkage_type, &slice[MIN_SIZE ..])
+            .unwrap_or((Desc4AInfo::Raw, 0));
+
+        Self {
+            tsid: slice[2 ..].get_u16(),
+            onid: slice[4 ..].get_u16(),
+            service_id: slice[6 ..].get_u16(),
+            linkage_type,
+            info,
+            private_data: Vec::from(&slice[MIN_SIZE + size ..]),
+        }
+    }
+}
+
+
+impl Desc for Desc4A {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x4A
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.info.size() + self.private_data.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x4A;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.tsid);
+        buffer[skip + 4 ..].set_u16(self.onid);
+        buffer[skip + 6 ..].set_u16(self.service_id);
+        buffer[skip + 8] = self.linkage_type;
+
+        self.info.assemble(buffer);
+        buffer.extend_from_slice(&self.private_data);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Desc,
+        Descriptors,
+        Desc4A,
+        Desc4AInfo,
+        Desc4AOui,
+        Desc4AHandOver,
+        Desc4AExtendedEvent,
+        LINKAGE_SYSTEM_SOFTWARE_UPDATE,
+        LINKAGE_MOBILE_HAND_OVER,
+        LINKAGE_EXTENDED_EVENT,
+    };
+
+    static DATA_4A_SSU: &[u8] = &[
+        0x4a, 0x10, 0x00, 0x01, 0x00, 0x55, 0x00, 0x0a, 0x09, 0x06, 0x00, 0x15, 0x0d, 0x02,
+        0x01, 0x02, 0xaa, 0xbb,
+    ];
+
+    static DATA_4A_HAND_OVER: &[u8] = &[
+        0x4a, 0x0c, 0x00, 0x01, 0x00, 0x55, 0x00, 0x0a, 0x08, 0x1e, 0x00, 0x55, 0x00, 0x0b,
+    ];
+
+    static DATA_4A_EXTENDED_EVENT: &[u8] = &[
+        0x4a, 0x0f, 0x00, 0x01, 0x00, 0x55, 0x00, 0x0a, 0x0e, 0x07, 0x00, 0x10, 0xd5, 0x00,
+        0x02, 0x00, 0x0c,
+    ];
+
+    static DATA_4A_RAW: &[u8] = &[
+        0x4a, 0x07, 0x00, 0x01, 0x00, 0x55, 0x00, 0x0a, 0x01,
+    ];
+
+    #[test]
+    fn test_4a_parse_ssu() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4A_SSU);
+
+        let desc = descriptors.find::<Desc4A>().unwrap();
+        assert_eq!(desc.size(), DATA_4A_SSU.len());
+        assert_eq!(desc.tsid, 1);
+        assert_eq!(desc.onid, 0x55);
+        assert_eq!(desc.service_id, 10);
+        assert_eq!(desc.linkage_type, LINKAGE_SYSTEM_SOFTWARE_UPDATE);
+        match &desc.info {
+            Desc4AInfo::SystemSoftwareUpdate(v) => {
+                assert_eq!(v.len(), 1);
+                assert_eq!(v[0].oui, 0x00150D);
+                assert_eq!(v[0].selector.as_slice(), &[0x01, 0x02]);
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(desc.private_data.as_slice(), &[0xaa, 0xbb]);
+    }
+
+    #[test]
+    fn test_4a_parse_hand_over() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4A_HAND_OVER);
+
+        let desc = descriptors.find::<Desc4A>().unwrap();
+        assert_eq!(desc.size(), DATA_4A_HAND_OVER.len());
+        match &desc.info {
+            Desc4AInfo::MobileHandOver(v) => {
+                assert_eq!(v.hand_over_type, 1);
+                assert_eq!(v.origin_type, 0);
+                assert_eq!(v.network_id, 0x55);
+                assert_eq!(v.initial_service_id, 11);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_4a_parse_extended_event() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4A_EXTENDED_EVENT);
+
+        let desc = descriptors.find::<Desc4A>().unwrap();
+        assert_eq!(desc.size(), DATA_4A_EXTENDED_EVENT.len());
+        match &desc.info {
+            Desc4AInfo::ExtendedEvent(v) => {
+                assert_eq!(v.len(), 1);
+                let item = &v[0];
+                assert_eq!(item.target_event_id, 0x10);
+                assert_eq!(item.target_listed, 1);
+                assert_eq!(item.event_simulcast, 1);
+                assert_eq!(item.link_type, 1);
+                assert_eq!(item.target_id_type, 1);
+                assert_eq!(item.target_id, 2);
+                assert_eq!(item.target_onid, None);
+                assert_eq!(item.target_service_id, Some(12));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_4a_parse_raw() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4A_RAW);
+
+        let desc = descriptors.find::<Desc4A>().unwrap();
+        assert!(matches!(desc.info, Desc4AInfo::Raw));
+        assert!(desc.private_data.is_empty());
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+        assert_eq!(assembled.as_slice(), DATA_4A_RAW);
+    }
+
+    #[test]
+    fn test_4a_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc4A {
+            tsid: 1,
+            onid: 0x55,
+            service_id: 10,
+            linkage_type: LINKAGE_SYSTEM_SOFTWARE_UPDATE,
+            info: Desc4AInfo::SystemSoftwareUpdate(vec![
+                Desc4AOui {
+                    oui: 0x00150D,
+                    selector: vec![0x01, 0x02],
+                },
+            ]),
+            private_data: vec![0xaa, 0xbb],
+        });
+        descriptors.push(Desc4A {
+            tsid: 1,
+            onid: 0x55,
+            service_id: 10,
+            linkage_type: LINKAGE_MOBILE_HAND_OVER,
+            info: Desc4AInfo::MobileHandOver(Desc4AHandOver {
+                hand_over_type: 1,
+                origin_type: 0,
+                network_id: 0x55,
+                initial_service_id: 11,
+            }),
+            private_data: Vec::new(),
+        });
+        descriptors.push(Desc4A {
+            tsid: 1,
+            onid: 0x55,
+            service_id: 10,
+            linkage_type: LINKAGE_EXTENDED_EVENT,
+            info: Desc4AInfo::ExtendedEvent(vec![
+                Desc4AExtendedEvent {
+                    target_event_id: 0x10,
+                    target_listed: 1,
+                    event_simulcast: 1,
+                    link_type: 1,
+                    target_id_type: 1,
+                    target_id: 2,
+                    target_onid: None,
+                    target_service_id: Some(12),
+                },
+            ]),
+            private_data: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        let expected = [DATA_4A_SSU, DATA_4A_HAND_OVER, DATA_4A_EXTENDED_EVENT].concat();
+        assert_eq!(assembled, expected);
+    }
+}
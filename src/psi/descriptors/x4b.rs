@@ -0,0 +1,136 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc4Bi {
+    /// Transport stream with the time-shifted service
+    pub tsid: u16,
+    /// Original network of the time-shifted service
+    pub onid: u16,
+    /// Time-shifted service (pnr)
+    pub service_id: u16,
+}
+
+
+/// The NVOD reference descriptor gives a list of the services which
+/// together form a Near Video On Demand service.
+///
+/// EN 300 468 - 6.2.26
+#[derive(Debug, Default, Clone)]
+pub struct Desc4B {
+    pub items: Vec<Desc4Bi>,
+}
+
+
+impl Desc4B {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - 2) % 6) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            result.items.push(Desc4Bi {
+                tsid: slice[skip ..].get_u16(),
+                onid: slice[skip + 2 ..].get_u16(),
+                service_id: slice[skip + 4 ..].get_u16(),
+            });
+            skip += 6;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc4B {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x4B
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 6
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let mut skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0x4B;
+        buffer[skip + 1] = (size - 2) as u8;
+        skip += 2;
+
+        for item in &self.items {
+            buffer[skip ..].set_u16(item.tsid);
+            buffer[skip + 2 ..].set_u16(item.onid);
+            buffer[skip + 4 ..].set_u16(item.service_id);
+            skip += 6;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc4B,
+        Desc4Bi,
+    };
+
+    static DATA_4B: &[u8] = &[
+        0x4b, 0x0c, 0x00, 0x01, 0x00, 0x46, 0x01, 0x01, 0x00, 0x01, 0x00, 0x46, 0x01, 0x02,
+    ];
+
+    #[test]
+    fn test_4b_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4B);
+
+        let desc = descriptors.find::<Desc4B>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+        let item = &desc.items[1];
+        assert_eq!(item.tsid, 1);
+        assert_eq!(item.onid, 70);
+        assert_eq!(item.service_id, 258);
+    }
+
+    #[test]
+    fn test_4b_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc4B {
+            items: vec![
+                Desc4Bi {
+                    tsid: 1,
+                    onid: 70,
+                    service_id: 257,
+                },
+                Desc4Bi {
+                    tsid: 1,
+                    onid: 70,
+                    service_id: 258,
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_4B);
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 4;
+
+
+/// The time shifted service descriptor is used in place of the service
+/// descriptor to indicate services which are time shifted copies
+/// of other services.
+///
+/// EN 300 468 - 6.2.45
+#[derive(Debug, Default, Clone)]
+pub struct Desc4C {
+    /// Service id of the NVOD reference service
+    pub reference_service_id: u16,
+}
+
+
+impl Desc4C {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            reference_service_id: slice[2 ..].get_u16(),
+        }
+    }
+}
+
+
+impl Desc for Desc4C {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x4C
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x4C;
+        buffer[skip + 1] = (MIN_SIZE - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.reference_service_id);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc4C,
+    };
+
+    static DATA_4C: &[u8] = &[0x4c, 0x02, 0x01, 0x00];
+
+    #[test]
+    fn test_4c_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4C);
+
+        let desc = descriptors.find::<Desc4C>().unwrap();
+        assert_eq!(desc.reference_service_id, 256);
+    }
+
+    #[test]
+    fn test_4c_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc4C {
+            reference_service_id: 256,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_4C);
+    }
+}
@@ -95,7 +95,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_4D);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc4D>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc4D>().unwrap();
         assert_eq!(desc.size(), DATA_4D.len());
         assert_eq!(desc.lang, textcode::StringDVB::from_str("rus", textcode::ISO6937));
         assert_eq!(desc.name, textcode::StringDVB::from_str("Стройка на Аляске.", textcode::ISO8859_5));
@@ -12,6 +12,14 @@ use super::Desc;
 const MIN_SIZE: usize = 8;
 
 
+/// Maximum size of the items and text with length bytes
+const MAX_PAYLOAD_SIZE: usize = 0xFF - (MIN_SIZE - 2);
+
+
+/// Maximum number of descriptors in the series
+const MAX_NUMBER: usize = 16;
+
+
 /// extended_event_descriptor - provides a detailed text description of
 /// an event, which may be used in addition to the short event descriptor.
 /// More than one extended event descriptor can be associated to allow
@@ -75,6 +83,66 @@ impl Desc4E {
             text: StringDVB::from(&slice[text_s .. text_e]),
         }
     }
+
+    /// Builds series of the extended event descriptors with `number` and
+    /// `last_number` defined. Text breaks on the word boundary.
+    /// Items placed before the text. Item that exceeds single descriptor
+    /// is truncated. Text that exceeds 16 descriptors is dropped.
+    pub fn build(lang: &StringDVB, items: &[(StringDVB, StringDVB)], text: &StringDVB) -> Vec<Self> {
+        let mut out: Vec<Desc4E> = Vec::new();
+
+        let new_desc = || Desc4E {
+            number: 0,
+            last_number: 0,
+            lang: lang.clone(),
+            items: Vec::new(),
+            text: StringDVB::default(),
+        };
+
+        let mut desc = new_desc();
+        let mut space = MAX_PAYLOAD_SIZE;
+
+        for (item_desc, item_text) in items {
+            let (item_desc, _) = item_desc.split_at_word(MAX_PAYLOAD_SIZE - 2);
+            let (item_text, _) = item_text.split_at_word(MAX_PAYLOAD_SIZE - 2 - item_desc.size());
+
+            let size = 2 + item_desc.size() + item_text.size();
+            if size > space {
+                out.push(desc);
+                desc = new_desc();
+                space = MAX_PAYLOAD_SIZE;
+            }
+
+            desc.items.push((item_desc, item_text));
+            space -= size;
+        }
+
+        let mut text = text.clone();
+        while ! text.is_empty() {
+            let (head, tail) = text.split_at_word(space);
+            if ! head.is_empty() {
+                desc.text = head;
+                text = tail;
+            }
+
+            out.push(desc);
+            desc = new_desc();
+            space = MAX_PAYLOAD_SIZE;
+        }
+
+        if ! desc.items.is_empty() || out.is_empty() {
+            out.push(desc);
+        }
+
+        out.truncate(MAX_NUMBER);
+        let last_number = (out.len() - 1) as u8;
+        for (number, desc) in out.iter_mut().enumerate() {
+            desc.number = number as u8;
+            desc.last_number = last_number;
+        }
+
+        out
+    }
 }
 
 
@@ -88,7 +156,7 @@ impl Desc for Desc4E {
     fn size(&self) -> usize {
         let mut items_size = 0;
         for (item_desc, item_text) in &self.items {
-            items_size += item_desc.size() + item_text.size();
+            items_size += 2 + item_desc.size() + item_text.size();
         }
         MIN_SIZE + items_size + self.text.size()
     }
@@ -141,7 +209,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_4E);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc4E>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc4E>().unwrap();
         assert_eq!(desc.size(), DATA_4E.len());
         assert_eq!(desc.number, 0);
         assert_eq!(desc.last_number, 0);
@@ -165,4 +233,94 @@ mod tests {
 
         assert_eq!(assembled.as_slice(), DATA_4E);
     }
+
+    #[test]
+    fn test_4e_items() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc4E {
+            number: 0,
+            last_number: 0,
+            lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+            items: vec![
+                (
+                    textcode::StringDVB::from_str("Directors", textcode::ISO6937),
+                    textcode::StringDVB::from_str("Anthony Russo, Joe Russo", textcode::ISO6937),
+                ),
+                (
+                    textcode::StringDVB::from_str("Writers", textcode::ISO6937),
+                    textcode::StringDVB::from_str("Christopher Markus", textcode::ISO6937),
+                ),
+                (
+                    textcode::StringDVB::from_str("Year", textcode::ISO6937),
+                    textcode::StringDVB::from_str("2019", textcode::ISO6937),
+                ),
+            ],
+            text: textcode::StringDVB::from_str("Avengers", textcode::ISO6937),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        // each item has two length bytes
+        let desc = descriptors.find::<Desc4E>().unwrap();
+        assert_eq!(desc.size(), 8 + (2 + 9 + 24) + (2 + 7 + 18) + (2 + 4 + 4) + 8);
+        assert_eq!(desc.size(), assembled.len());
+        assert_eq!(usize::from(assembled[1]), assembled.len() - 2);
+
+        let mut parsed = Descriptors::default();
+        parsed.parse(&assembled);
+        let desc = parsed.find::<Desc4E>().unwrap();
+        assert_eq!(desc.items.len(), 3);
+        assert_eq!(desc.items[1].0.to_string(), "Writers");
+        assert_eq!(desc.items[1].1.to_string(), "Christopher Markus");
+        assert_eq!(desc.items[2].1.to_string(), "2019");
+        assert_eq!(desc.text.to_string(), "Avengers");
+    }
+
+    #[test]
+    fn test_4e_build() {
+        let lang = textcode::StringDVB::from_str("eng", textcode::ISO6937);
+        let items = vec![
+            (
+                textcode::StringDVB::from_str("Directors", textcode::ISO6937),
+                textcode::StringDVB::from_str("Anthony Russo, Joe Russo", textcode::ISO6937),
+            ),
+            (
+                textcode::StringDVB::from_str("Writers", textcode::ISO6937),
+                textcode::StringDVB::from_str("Christopher Markus, Stephen McFeely", textcode::ISO6937),
+            ),
+        ];
+        let text = "Winter is coming. ".repeat(30);
+
+        let list = Desc4E::build(&lang, &items, &textcode::StringDVB::from_str(&text, textcode::ISO8859_1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[0].items.len(), 2);
+
+        let mut out = String::new();
+        for (number, desc) in list.iter().enumerate() {
+            assert!(desc.validate().is_ok());
+            assert_eq!(desc.number as usize, number);
+            assert_eq!(desc.last_number, 2);
+            let text = desc.text.to_string();
+            assert!(text.ends_with(' '));
+            out.push_str(&text);
+        }
+        assert_eq!(out, text);
+
+        let text = "Зима".repeat(200);
+        let list = Desc4E::build(&lang, &[], &textcode::StringDVB::from_str(&text, textcode::UTF8));
+        assert_eq!(list.len(), 7);
+
+        let mut assembled = Vec::new();
+        let mut descriptors = Descriptors::default();
+        for desc in list {
+            descriptors.push(desc);
+        }
+        descriptors.assemble(&mut assembled);
+
+        let mut parsed = Descriptors::default();
+        parsed.parse(&assembled);
+        let out: String = parsed.find_all::<Desc4E>().map(|x| x.text.to_string()).collect();
+        assert_eq!(out, text);
+    }
 }
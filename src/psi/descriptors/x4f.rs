@@ -0,0 +1,97 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 6;
+
+
+/// The time shifted event descriptor is used in place of the short event
+/// descriptor to indicate an event which is a time shifted copy
+/// of another event.
+///
+/// EN 300 468 - 6.2.44
+#[derive(Debug, Default, Clone)]
+pub struct Desc4F {
+    /// Service id of the NVOD reference service
+    pub reference_service_id: u16,
+    /// Event id of the reference event
+    pub reference_event_id: u16,
+}
+
+
+impl Desc4F {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            reference_service_id: slice[2 ..].get_u16(),
+            reference_event_id: slice[4 ..].get_u16(),
+        }
+    }
+}
+
+
+impl Desc for Desc4F {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x4F
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x4F;
+        buffer[skip + 1] = (MIN_SIZE - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.reference_service_id);
+        buffer[skip + 4 ..].set_u16(self.reference_event_id);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc4F,
+    };
+
+    static DATA_4F: &[u8] = &[0x4f, 0x04, 0x01, 0x00, 0x12, 0x34];
+
+    #[test]
+    fn test_4f_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_4F);
+
+        let desc = descriptors.find::<Desc4F>().unwrap();
+        assert_eq!(desc.reference_service_id, 256);
+        assert_eq!(desc.reference_event_id, 0x1234);
+    }
+
+    #[test]
+    fn test_4f_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc4F {
+            reference_service_id: 256,
+            reference_event_id: 0x1234,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_4F);
+    }
+}
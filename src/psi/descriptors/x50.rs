@@ -0,0 +1,127 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 8;
+
+
+/// The component descriptor identifies the type of component stream
+/// and may be used to provide a text description of the elementary stream.
+///
+/// EN 300 468 - 6.2.8
+#[derive(Debug, Default, Clone)]
+pub struct Desc50 {
+    /// Extension of the stream_content
+    pub stream_content_ext: u8,
+    /// Type of stream: video, audio, or EBU-data
+    pub stream_content: u8,
+    /// Type of the video, audio or EBU-data component
+    pub component_type: u8,
+    /// Same value as the component_tag field in the stream identifier descriptor
+    pub component_tag: u8,
+    /// Language of the component
+    pub lang: StringDVB,
+    /// Text description of the component stream
+    pub text: StringDVB,
+}
+
+
+impl Desc50 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            stream_content_ext: slice[2] >> 4,
+            stream_content: slice[2] & 0x0F,
+            component_type: slice[3],
+            component_tag: slice[4],
+            lang: StringDVB::from(&slice[5 .. 8]),
+            text: StringDVB::from(&slice[8 ..]),
+        }
+    }
+}
+
+
+impl Desc for Desc50 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x50
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.text.size()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x50);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.stream_content_ext, 4,
+            self.stream_content, 4));
+        buffer.push(self.component_type);
+        buffer.push(self.component_tag);
+
+        self.lang.assemble(buffer);
+        self.text.assemble(buffer);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Desc,
+            Descriptors,
+            Desc50,
+        },
+    };
+
+    static DATA_50: &[u8] = &[
+        0x50, 0x0e, 0xf1, 0x03, 0x01, 0x65, 0x6e, 0x67, 0x31, 0x36, 0x3a, 0x39, 0x20, 0x53, 0x44, 0x54,
+    ];
+
+    #[test]
+    fn test_50_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_50);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc50>().unwrap();
+        assert_eq!(desc.size(), DATA_50.len());
+        assert_eq!(desc.stream_content_ext, 0x0F);
+        assert_eq!(desc.stream_content, 0x01);
+        assert_eq!(desc.component_type, 0x03);
+        assert_eq!(desc.component_tag, 0x01);
+        assert_eq!(desc.lang, textcode::StringDVB::from_str("eng", textcode::ISO6937));
+        assert_eq!(desc.text, textcode::StringDVB::from_str("16:9 SDT", textcode::ISO6937));
+    }
+
+    #[test]
+    fn test_50_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc50 {
+            stream_content_ext: 0x0F,
+            stream_content: 0x01,
+            component_type: 0x03,
+            component_tag: 0x01,
+            lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+            text: textcode::StringDVB::from_str("16:9 SDT", textcode::ISO6937),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_50);
+    }
+}
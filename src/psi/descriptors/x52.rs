@@ -70,7 +70,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_52);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc52>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc52>().unwrap();
         assert_eq!(desc.tag, 2);
     }
 
@@ -0,0 +1,103 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+/// The CA identifier descriptor indicates whether a particular bouquet,
+/// service or event is associated with a conditional access system
+/// and identifies the CA system.
+///
+/// EN 300 468 - 6.2.5
+#[derive(Debug, Default, Clone)]
+pub struct Desc53 {
+    /// List of CA system identifiers
+    pub items: Vec<u16>,
+}
+
+
+impl Desc53 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - MIN_SIZE) % 2) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            result.items.push(slice[skip ..].get_u16());
+            skip += 2;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc53 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x53
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 2
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let mut skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0x53;
+        buffer[skip + 1] = (size - 2) as u8;
+        skip += 2;
+
+        for item in &self.items {
+            buffer[skip ..].set_u16(*item);
+            skip += 2;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc53,
+    };
+
+    static DATA_53: &[u8] = &[0x53, 0x04, 0x09, 0x63, 0x0b, 0x00];
+
+    #[test]
+    fn test_53_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_53);
+
+        let desc = descriptors.find::<Desc53>().unwrap();
+        assert_eq!(desc.items, vec![0x0963, 0x0B00]);
+    }
+
+    #[test]
+    fn test_53_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc53 {
+            items: vec![0x0963, 0x0B00],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_53);
+    }
+}
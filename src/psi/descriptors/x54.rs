@@ -0,0 +1,340 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+/// Content genre defined by the content_nibble_level_1
+///
+/// EN 300 468 - Table 29
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Genre {
+    Undefined,
+    MovieDrama,
+    NewsCurrentAffairs,
+    ShowGameShow,
+    Sports,
+    ChildrenYouth,
+    MusicBalletDance,
+    ArtsCulture,
+    SocialPoliticalEconomics,
+    EducationScienceFactual,
+    LeisureHobbies,
+    SpecialCharacteristics,
+    Adult,
+    Reserved(u8),
+    UserDefined,
+}
+
+
+impl From<u8> for Genre {
+    fn from(level_1: u8) -> Self {
+        match level_1 {
+            0x0 => Genre::Undefined,
+            0x1 => Genre::MovieDrama,
+            0x2 => Genre::NewsCurrentAffairs,
+            0x3 => Genre::ShowGameShow,
+            0x4 => Genre::Sports,
+            0x5 => Genre::ChildrenYouth,
+            0x6 => Genre::MusicBalletDance,
+            0x7 => Genre::ArtsCulture,
+            0x8 => Genre::SocialPoliticalEconomics,
+            0x9 => Genre::EducationScienceFactual,
+            0xA => Genre::LeisureHobbies,
+            0xB => Genre::SpecialCharacteristics,
+            0xC => Genre::Adult,
+            0xF => Genre::UserDefined,
+            v => Genre::Reserved(v),
+        }
+    }
+}
+
+
+impl Genre {
+    /// Returns human-readable name of the genre
+    pub fn name(self) -> &'static str {
+        match self {
+            Genre::Undefined => "Undefined",
+            Genre::MovieDrama => "Movie/Drama",
+            Genre::NewsCurrentAffairs => "News/Current affairs",
+            Genre::ShowGameShow => "Show/Game show",
+            Genre::Sports => "Sports",
+            Genre::ChildrenYouth => "Children's/Youth programmes",
+            Genre::MusicBalletDance => "Music/Ballet/Dance",
+            Genre::ArtsCulture => "Arts/Culture (without music)",
+            Genre::SocialPoliticalEconomics => "Social/Political issues/Economics",
+            Genre::EducationScienceFactual => "Education/Science/Factual topics",
+            Genre::LeisureHobbies => "Leisure hobbies",
+            Genre::SpecialCharacteristics => "Special characteristics",
+            Genre::Adult => "Adult",
+            Genre::Reserved(_) => "Reserved",
+            Genre::UserDefined => "User defined",
+        }
+    }
+}
+
+
+/// Returns human-readable name of the content_nibble_level_2
+/// or `None` if value is not defined
+fn get_subgenre_name(level_1: u8, level_2: u8) -> Option<&'static str> {
+    let name = match (level_1, level_2) {
+        (0x1, 0x0) => "movie/drama (general)",
+        (0x1, 0x1) => "detective/thriller",
+        (0x1, 0x2) => "adventure/western/war",
+        (0x1, 0x3) => "science fiction/fantasy/horror",
+        (0x1, 0x4) => "comedy",
+        (0x1, 0x5) => "soap/melodrama/folklore",
+        (0x1, 0x6) => "romance",
+        (0x1, 0x7) => "serious/classical/religious/historical movie/drama",
+        (0x1, 0x8) => "adult movie/drama",
+
+        (0x2, 0x0) => "news/current affairs (general)",
+        (0x2, 0x1) => "news/weather report",
+        (0x2, 0x2) => "news magazine",
+        (0x2, 0x3) => "documentary",
+        (0x2, 0x4) => "discussion/interview/debate",
+
+        (0x3, 0x0) => "show/game show (general)",
+        (0x3, 0x1) => "game show/quiz/contest",
+        (0x3, 0x2) => "variety show",
+        (0x3, 0x3) => "talk show",
+
+        (0x4, 0x0) => "sports (general)",
+        (0x4, 0x1) => "special events (Olympic Games, World Cup, etc.)",
+        (0x4, 0x2) => "sports magazines",
+        (0x4, 0x3) => "football/soccer",
+        (0x4, 0x4) => "tennis/squash",
+        (0x4, 0x5) => "team sports (excluding football)",
+        (0x4, 0x6) => "athletics",
+        (0x4, 0x7) => "motor sport",
+        (0x4, 0x8) => "water sport",
+        (0x4, 0x9) => "winter sports",
+        (0x4, 0xA) => "equestrian",
+        (0x4, 0xB) => "martial sports",
+
+        (0x5, 0x0) => "children's/youth programmes (general)",
+        (0x5, 0x1) => "pre-school children's programmes",
+        (0x5, 0x2) => "entertainment programmes for 6 to 14",
+        (0x5, 0x3) => "entertainment programmes for 10 to 16",
+        (0x5, 0x4) => "informational/educational/school programmes",
+        (0x5, 0x5) => "cartoons/puppets",
+
+        (0x6, 0x0) => "music/ballet/dance (general)",
+        (0x6, 0x1) => "rock/pop",
+        (0x6, 0x2) => "serious music/classical music",
+        (0x6, 0x3) => "folk/traditional music",
+        (0x6, 0x4) => "jazz",
+        (0x6, 0x5) => "musical/opera",
+        (0x6, 0x6) => "ballet",
+
+        (0x7, 0x0) => "arts/culture (without music, general)",
+        (0x7, 0x1) => "performing arts",
+        (0x7, 0x2) => "fine arts",
+        (0x7, 0x3) => "religion",
+        (0x7, 0x4) => "popular culture/traditional arts",
+        (0x7, 0x5) => "literature",
+        (0x7, 0x6) => "film/cinema",
+        (0x7, 0x7) => "experimental film/video",
+        (0x7, 0x8) => "broadcasting/press",
+        (0x7, 0x9) => "new media",
+        (0x7, 0xA) => "arts/culture magazines",
+        (0x7, 0xB) => "fashion",
+
+        (0x8, 0x0) => "social/political issues/economics (general)",
+        (0x8, 0x1) => "magazines/reports/documentary",
+        (0x8, 0x2) => "economics/social advisory",
+        (0x8, 0x3) => "remarkable people",
+
+        (0x9, 0x0) => "education/science/factual topics (general)",
+        (0x9, 0x1) => "nature/animals/environment",
+        (0x9, 0x2) => "technology/natural sciences",
+        (0x9, 0x3) => "medicine/physiology/psychology",
+        (0x9, 0x4) => "foreign countries/expeditions",
+        (0x9, 0x5) => "social/spiritual sciences",
+        (0x9, 0x6) => "further education",
+        (0x9, 0x7) => "languages",
+
+        (0xA, 0x0) => "leisure hobbies (general)",
+        (0xA, 0x1) => "tourism/travel",
+        (0xA, 0x2) => "handicraft",
+        (0xA, 0x3) => "motoring",
+        (0xA, 0x4) => "fitness and health",
+        (0xA, 0x5) => "cooking",
+        (0xA, 0x6) => "advertisement/shopping",
+        (0xA, 0x7) => "gardening",
+
+        (0xB, 0x0) => "original language",
+        (0xB, 0x1) => "black and white",
+        (0xB, 0x2) => "unpublished",
+        (0xB, 0x3) => "live broadcast",
+        (0xB, 0x4) => "plano-stereoscopic",
+        (0xB, 0x5) => "local or regional",
+
+        (0xC, 0x0) => "adult (general)",
+
+        (_, 0xF) => "user defined",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc54i {
+    pub level_1: u8,
+    pub level_2: u8,
+    pub user_byte: u8,
+}
+
+
+impl Desc54i {
+    /// Returns genre defined by the content_nibble_level_1
+    #[inline]
+    pub fn genre(&self) -> Genre {
+        Genre::from(self.level_1)
+    }
+
+    /// Returns human-readable name of the content.
+    /// Name of the genre if content_nibble_level_2 is not defined
+    pub fn name(&self) -> &'static str {
+        get_subgenre_name(self.level_1, self.level_2)
+            .unwrap_or_else(|| self.genre().name())
+    }
+}
+
+
+/// The content descriptor provides classification information for an event.
+///
+/// EN 300 468 - 6.2.9
+#[derive(Debug, Default, Clone)]
+pub struct Desc54 {
+    /// List of content nibbles and user bytes
+    pub items: Vec<Desc54i>,
+}
+
+
+impl Desc54 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - 2) % 2) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = 2;
+        while slice.len() > skip {
+            result.items.push(Desc54i {
+                level_1: slice[skip] >> 4,
+                level_2: slice[skip] & 0x0F,
+                user_byte: slice[skip + 1],
+            });
+            skip += 2;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc54 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x54
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 2
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x54);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            buffer.push(set_bits!(8,
+                item.level_1, 4,
+                item.level_2, 4));
+            buffer.push(item.user_byte);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc54,
+        Desc54i,
+        Genre,
+    };
+
+    static DATA_54: &[u8] = &[0x54, 0x04, 0x14, 0x00, 0xb3, 0x00];
+
+    #[test]
+    fn test_54_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_54);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc54>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+
+        let item = &desc.items[0];
+        assert_eq!(item.genre(), Genre::MovieDrama);
+        assert_eq!(item.genre().name(), "Movie/Drama");
+        assert_eq!(item.name(), "comedy");
+
+        let item = &desc.items[1];
+        assert_eq!(item.genre(), Genre::SpecialCharacteristics);
+        assert_eq!(item.name(), "live broadcast");
+    }
+
+    #[test]
+    fn test_54_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc54 {
+            items: vec![
+                Desc54i {
+                    level_1: 0x1,
+                    level_2: 0x4,
+                    user_byte: 0,
+                },
+                Desc54i {
+                    level_1: 0xB,
+                    level_2: 0x3,
+                    user_byte: 0,
+                },
+            ]
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_54);
+    }
+
+    #[test]
+    fn test_54_name() {
+        let item = Desc54i {
+            level_1: 0x4,
+            level_2: 0xD,
+            user_byte: 0,
+        };
+        assert_eq!(item.name(), "Sports");
+
+        let item = Desc54i {
+            level_1: 0xD,
+            level_2: 0x0,
+            user_byte: 0,
+        };
+        assert_eq!(item.genre(), Genre::Reserved(0xD));
+    }
+}
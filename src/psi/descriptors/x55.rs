@@ -0,0 +1,155 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+#[derive(Debug, Clone)]
+pub struct Desc55i {
+    pub country_code: StringDVB,
+    pub rating: u8,
+}
+
+
+impl Desc55i {
+    /// Returns minimum age in years or `None` if rating
+    /// is undefined or defined by the broadcaster
+    #[inline]
+    pub fn min_age(&self) -> Option<u8> {
+        match self.rating {
+            0x01 ..= 0x0F => Some(self.rating + 3),
+            _ => None,
+        }
+    }
+}
+
+
+/// The parental rating descriptor gives a rating based on age
+/// and allows for extensions based on other rating criteria.
+///
+/// EN 300 468 - 6.2.28
+#[derive(Debug, Default, Clone)]
+pub struct Desc55 {
+    /// 0 - ISO 3166 alpha-3 country code
+    /// 1 - Rating. Minimum age is rating + 3 years
+    pub items: Vec<Desc55i>,
+}
+
+
+impl Desc55 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - 2) % 4) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = 2;
+
+        while slice.len() > skip {
+            let country_code = StringDVB::from(&slice[skip .. skip + 3]);
+            let rating = slice[skip + 3];
+            result.items.push(Desc55i {
+                country_code,
+                rating,
+            });
+            skip += 4;
+        }
+        result
+    }
+
+    /// Returns minimum age for the country defined by ISO 3166 alpha-3 code
+    pub fn get_min_age(&self, country: &str) -> Option<u8> {
+        self.items.iter()
+            .find(|item| item.country_code.as_bytes().eq_ignore_ascii_case(country.as_bytes()))
+            .and_then(Desc55i::min_age)
+    }
+}
+
+
+impl Desc for Desc55 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x55
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 4
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x55);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            item.country_code.assemble(buffer);
+            buffer.push(item.rating);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc55,
+            Desc55i,
+        },
+    };
+
+    static DATA_55: &[u8] = &[
+        0x55, 0x08, 0x47, 0x42, 0x52, 0x09, 0x46, 0x52, 0x41, 0x00,
+    ];
+
+    #[test]
+    fn test_55_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_55);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc55>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+
+        let item = &desc.items[0];
+        assert_eq!(item.country_code, textcode::StringDVB::from_str("GBR", textcode::ISO6937));
+        assert_eq!(item.rating, 0x09);
+        assert_eq!(item.min_age(), Some(12));
+
+        assert_eq!(desc.get_min_age("GBR"), Some(12));
+        assert_eq!(desc.get_min_age("FRA"), None);
+        assert_eq!(desc.get_min_age("DEU"), None);
+    }
+
+    #[test]
+    fn test_55_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc55 {
+            items: vec![
+                Desc55i {
+                    country_code: textcode::StringDVB::from_str("GBR", textcode::ISO6937),
+                    rating: 0x09,
+                },
+                Desc55i {
+                    country_code: textcode::StringDVB::from_str("FRA", textcode::ISO6937),
+                    rating: 0x00,
+                },
+            ]
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_55);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+#[derive(Debug, Clone)]
+pub struct Desc56i {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// Type of teletext page: 0x01 - initial page, 0x02 - subtitle page, ...
+    pub teletext_type: u8,
+    /// Magazine number. Value 0 is used for magazine 8
+    pub magazine_number: u8,
+    /// Page number in BCD
+    pub page_number: u8,
+}
+
+
+impl Desc56i {
+    /// Returns decimal page number, for example 888
+    pub fn page(&self) -> u16 {
+        let magazine = if self.magazine_number == 0 { 8 } else { u16::from(self.magazine_number) };
+        magazine * 100 +
+            u16::from(self.page_number >> 4) * 10 +
+            u16::from(self.page_number & 0x0F)
+    }
+}
+
+
+/// The teletext descriptor is used to identify streams which carry
+/// EBU Teletext data.
+///
+/// EN 300 468 - 6.2.43
+#[derive(Debug, Default, Clone)]
+pub struct Desc56 {
+    pub items: Vec<Desc56i>,
+}
+
+
+impl Desc56 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - 2) % 5) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = 2;
+
+        while slice.len() > skip {
+            result.items.push(Desc56i {
+                lang: StringDVB::from(&slice[skip .. skip + 3]),
+                teletext_type: slice[skip + 3] >> 3,
+                magazine_number: slice[skip + 3] & 0x07,
+                page_number: slice[skip + 4],
+            });
+            skip += 5;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc56 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x56
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 5
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x56);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            item.lang.assemble(buffer);
+            buffer.push(set_bits!(8,
+                item.teletext_type, 5,
+                item.magazine_number, 3));
+            buffer.push(item.page_number);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc56,
+            Desc56i,
+        },
+    };
+
+    static DATA_56: &[u8] = &[
+        0x56, 0x0a, 0x65, 0x6e, 0x67, 0x09, 0x00, 0x65, 0x6e, 0x67, 0x10, 0x88,
+    ];
+
+    #[test]
+    fn test_56_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_56);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc56>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+
+        let item = &desc.items[0];
+        assert_eq!(item.lang, textcode::StringDVB::from_str("eng", textcode::ISO6937));
+        assert_eq!(item.teletext_type, 0x01);
+        assert_eq!(item.magazine_number, 1);
+        assert_eq!(item.page(), 100);
+
+        let item = &desc.items[1];
+        assert_eq!(item.teletext_type, 0x02);
+        assert_eq!(item.magazine_number, 0);
+        assert_eq!(item.page(), 888);
+    }
+
+    #[test]
+    fn test_56_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc56 {
+            items: vec![
+                Desc56i {
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                    teletext_type: 0x01,
+                    magazine_number: 1,
+                    page_number: 0x00,
+                },
+                Desc56i {
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                    teletext_type: 0x02,
+                    magazine_number: 0,
+                    page_number: 0x88,
+                },
+            ]
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_56);
+    }
+}
@@ -127,7 +127,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_58);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc58>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc58>().unwrap();
         assert_eq!(desc.items.len(), 2);
 
         let item = desc.items.get(0).unwrap();
@@ -0,0 +1,142 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    textcode::StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+#[derive(Debug, Clone)]
+pub struct Desc59i {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// Type of subtitles. Same as component_type for stream_content 0x03
+    pub subtitling_type: u8,
+    /// Subtitling segments with the composition page
+    pub composition_page_id: u16,
+    /// Subtitling segments with the ancillary page
+    pub ancillary_page_id: u16,
+}
+
+
+/// The subtitling descriptor is used to identify streams which carry
+/// DVB subtitles.
+///
+/// EN 300 468 - 6.2.41
+#[derive(Debug, Default, Clone)]
+pub struct Desc59 {
+    pub items: Vec<Desc59i>,
+}
+
+
+impl Desc59 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - 2) % 8) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = 2;
+
+        while slice.len() > skip {
+            result.items.push(Desc59i {
+                lang: StringDVB::from(&slice[skip .. skip + 3]),
+                subtitling_type: slice[skip + 3],
+                composition_page_id: slice[skip + 4 ..].get_u16(),
+                ancillary_page_id: slice[skip + 6 ..].get_u16(),
+            });
+            skip += 8;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc59 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x59
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 8
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x59);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            item.lang.assemble(buffer);
+            buffer.push(item.subtitling_type);
+
+            let skip = buffer.len();
+            buffer.resize(skip + 4, 0x00);
+            buffer[skip ..].set_u16(item.composition_page_id);
+            buffer[skip + 2 ..].set_u16(item.ancillary_page_id);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc59,
+            Desc59i,
+        },
+    };
+
+    static DATA_59: &[u8] = &[
+        0x59, 0x08, 0x64, 0x65, 0x75, 0x10, 0x00, 0x01, 0x00, 0x02,
+    ];
+
+    #[test]
+    fn test_59_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_59);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc59>().unwrap();
+        assert_eq!(desc.items.len(), 1);
+
+        let item = &desc.items[0];
+        assert_eq!(item.lang, textcode::StringDVB::from_str("deu", textcode::ISO6937));
+        assert_eq!(item.subtitling_type, 0x10);
+        assert_eq!(item.composition_page_id, 1);
+        assert_eq!(item.ancillary_page_id, 2);
+    }
+
+    #[test]
+    fn test_59_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc59 {
+            items: vec![
+                Desc59i {
+                    lang: textcode::StringDVB::from_str("deu", textcode::ISO6937),
+                    subtitling_type: 0x10,
+                    composition_page_id: 1,
+                    ancillary_page_id: 2,
+                },
+            ]
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_59);
+    }
+}
@@ -136,7 +136,7 @@ mod tests {
         let mut descriptors = Descriptors::default();
         descriptors.parse(DATA_5A);
 
-        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc5A>();
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc5A>().unwrap();
         assert_eq!(desc.frequency, 500000000);
         assert_eq!(desc.bandwidth, constants::BANDWIDTH_DVB_T_8MHZ);
         assert_eq!(desc.priority, 1);
@@ -0,0 +1,147 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::{
+    lang,
+    StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc5Bi {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// Network name
+    pub name: StringDVB,
+}
+
+
+/// The multilingual network name descriptor provides the network name
+/// in text form in one or more languages.
+///
+/// EN 300 468 - 6.2.24
+#[derive(Debug, Default, Clone)]
+pub struct Desc5B {
+    pub items: Vec<Desc5Bi>,
+}
+
+
+impl Desc5B {
+    pub fn check(slice: &[u8]) -> bool {
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            if slice.len() < skip + 4 {
+                return false;
+            }
+            skip += 4 + usize::from(slice[skip + 3]);
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let name_s = skip + 4;
+            let name_e = name_s + usize::from(slice[skip + 3]);
+            result.items.push(Desc5Bi {
+                lang: StringDVB::from(&slice[skip .. skip + 3]),
+                name: StringDVB::from(&slice[name_s .. name_e]),
+            });
+            skip = name_e;
+        }
+
+        result
+    }
+
+    /// Returns network name for the language defined by ISO 639 code
+    pub fn get_name(&self, code: &str) -> Option<&StringDVB> {
+        self.items.iter()
+            .find(|item| lang::is_same(&item.lang.to_string(), code))
+            .map(|item| &item.name)
+    }
+}
+
+
+impl Desc for Desc5B {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x5B
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| acc + 4 + item.name.size())
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x5B);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            item.lang.assemble(buffer);
+            item.name.assemble_sized(buffer);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc5B,
+            Desc5Bi,
+        },
+    };
+
+    static DATA_5B: &[u8] = &[
+        0x5b, 0x0e, 0x65, 0x6e, 0x67, 0x03, 0x4e, 0x65, 0x74, 0x64, 0x65, 0x75, 0x03, 0x4e,
+        0x65, 0x7a,
+    ];
+
+    #[test]
+    fn test_5b_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_5B);
+
+        let desc = descriptors.find::<Desc5B>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+        assert_eq!(desc.get_name("en").unwrap().to_string(), "Net");
+        assert_eq!(desc.get_name("ger").unwrap().to_string(), "Nez");
+        assert!(desc.get_name("fra").is_none());
+    }
+
+    #[test]
+    fn test_5b_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc5B {
+            items: vec![
+                Desc5Bi {
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                    name: textcode::StringDVB::from_str("Net", textcode::ISO6937),
+                },
+                Desc5Bi {
+                    lang: textcode::StringDVB::from_str("deu", textcode::ISO6937),
+                    name: textcode::StringDVB::from_str("Nez", textcode::ISO6937),
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_5B);
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::{
+    lang,
+    StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc5Di {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// Name of the service provider
+    pub provider: StringDVB,
+    /// Name of the service
+    pub name: StringDVB,
+}
+
+
+/// The multilingual service name descriptor provides the names of
+/// the service provider and service in text form in one or more languages.
+///
+/// EN 300 468 - 6.2.25
+#[derive(Debug, Default, Clone)]
+pub struct Desc5D {
+    pub items: Vec<Desc5Di>,
+}
+
+
+impl Desc5D {
+    pub fn check(slice: &[u8]) -> bool {
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            if slice.len() < skip + 5 {
+                return false;
+            }
+            let name_s = skip + 4 + usize::from(slice[skip + 3]);
+            if slice.len() <= name_s {
+                return false;
+            }
+            skip = name_s + 1 + usize::from(slice[name_s]);
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let provider_s = skip + 4;
+            let provider_e = provider_s + usize::from(slice[skip + 3]);
+            let name_s = provider_e + 1;
+            let name_e = name_s + usize::from(slice[provider_e]);
+            result.items.push(Desc5Di {
+                lang: StringDVB::from(&slice[skip .. skip + 3]),
+                provider: StringDVB::from(&slice[provider_s .. provider_e]),
+                name: StringDVB::from(&slice[name_s .. name_e]),
+            });
+            skip = name_e;
+        }
+
+        result
+    }
+
+    #[inline]
+    fn get_item(&self, code: &str) -> Option<&Desc5Di> {
+        self.items.iter().find(|item| lang::is_same(&item.lang.to_string(), code))
+    }
+
+    /// Returns service name for the language defined by ISO 639 code
+    pub fn get_name(&self, code: &str) -> Option<&StringDVB> {
+        self.get_item(code).map(|item| &item.name)
+    }
+
+    /// Returns service provider name for the language defined by ISO 639 code
+    pub fn get_provider(&self, code: &str) -> Option<&StringDVB> {
+        self.get_item(code).map(|item| &item.provider)
+    }
+}
+
+
+impl Desc for Desc5D {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x5D
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| {
+            acc + 5 + item.provider.size() + item.name.size()
+        })
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x5D);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            item.lang.assemble(buffer);
+            item.provider.assemble_sized(buffer);
+            item.name.assemble_sized(buffer);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc5D,
+            Desc5Di,
+        },
+    };
+
+    static DATA_5D: &[u8] = &[
+        0x5d, 0x12, 0x65, 0x6e, 0x67, 0x02, 0x50, 0x72, 0x03, 0x54, 0x56, 0x31, 0x66, 0x72,
+        0x65, 0x00, 0x03, 0x54, 0x56, 0x32,
+    ];
+
+    #[test]
+    fn test_5d_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_5D);
+
+        let desc = descriptors.find::<Desc5D>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+        assert_eq!(desc.get_name("en").unwrap().to_string(), "TV1");
+        assert_eq!(desc.get_provider("en").unwrap().to_string(), "Pr");
+        assert_eq!(desc.get_name("fra").unwrap().to_string(), "TV2");
+        assert!(desc.get_provider("fr").unwrap().is_empty());
+        assert!(desc.get_name("deu").is_none());
+    }
+
+    #[test]
+    fn test_5d_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc5D {
+            items: vec![
+                Desc5Di {
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                    provider: textcode::StringDVB::from_str("Pr", textcode::ISO6937),
+                    name: textcode::StringDVB::from_str("TV1", textcode::ISO6937),
+                },
+                Desc5Di {
+                    lang: textcode::StringDVB::from_str("fre", textcode::ISO6937),
+                    provider: textcode::StringDVB::default(),
+                    name: textcode::StringDVB::from_str("TV2", textcode::ISO6937),
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_5D);
+    }
+}
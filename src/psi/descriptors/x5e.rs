@@ -0,0 +1,153 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::{
+    lang,
+    StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc5Ei {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// Text description of the component
+    pub text: StringDVB,
+}
+
+
+/// The multilingual component descriptor provides a text description of
+/// a component in one or more languages.
+///
+/// EN 300 468 - 6.2.23
+#[derive(Debug, Default, Clone)]
+pub struct Desc5E {
+    /// Identifies the component stream, same as in the stream identifier
+    /// and component descriptors
+    pub component_tag: u8,
+    pub items: Vec<Desc5Ei>,
+}
+
+
+impl Desc5E {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            if slice.len() < skip + 4 {
+                return false;
+            }
+            skip += 4 + usize::from(slice[skip + 3]);
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            component_tag: slice[2],
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let text_s = skip + 4;
+            let text_e = text_s + usize::from(slice[skip + 3]);
+            result.items.push(Desc5Ei {
+                lang: StringDVB::from(&slice[skip .. skip + 3]),
+                text: StringDVB::from(&slice[text_s .. text_e]),
+            });
+            skip = text_e;
+        }
+
+        result
+    }
+
+    /// Returns component description for the language defined by ISO 639 code
+    pub fn get_text(&self, code: &str) -> Option<&StringDVB> {
+        self.items.iter()
+            .find(|item| lang::is_same(&item.lang.to_string(), code))
+            .map(|item| &item.text)
+    }
+}
+
+
+impl Desc for Desc5E {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x5E
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| acc + 4 + item.text.size())
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x5E);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(self.component_tag);
+
+        for item in &self.items {
+            item.lang.assemble(buffer);
+            item.text.assemble_sized(buffer);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc5E,
+            Desc5Ei,
+        },
+    };
+
+    static DATA_5E: &[u8] = &[
+        0x5e, 0x0b, 0x01, 0x65, 0x6e, 0x67, 0x06, 0x53, 0x74, 0x65, 0x72, 0x65, 0x6f,
+    ];
+
+    #[test]
+    fn test_5e_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_5E);
+
+        let desc = descriptors.find::<Desc5E>().unwrap();
+        assert_eq!(desc.component_tag, 1);
+        assert_eq!(desc.items.len(), 1);
+        assert_eq!(desc.get_text("en").unwrap().to_string(), "Stereo");
+    }
+
+    #[test]
+    fn test_5e_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc5E {
+            component_tag: 1,
+            items: vec![
+                Desc5Ei {
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                    text: textcode::StringDVB::from_str("Stereo", textcode::ISO6937),
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_5E);
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 6;
+
+/// EACEM / EICTA
+pub const PRIVATE_DATA_SPECIFIER_EICTA: u32 = 0x0000_0028;
+/// NorDig
+pub const PRIVATE_DATA_SPECIFIER_NORDIG: u32 = 0x0000_0029;
+/// DTG (UK Freeview)
+pub const PRIVATE_DATA_SPECIFIER_DTG: u32 = 0x0000_233A;
+
+
+/// The private data specifier descriptor is used to identify the specifier
+/// of any private descriptors or private fields within descriptors
+/// which follows in the same descriptors loop.
+///
+/// EN 300 468 - 6.2.31
+#[derive(Debug, Default, Clone)]
+pub struct Desc5F {
+    /// Private data specifier value registered in ETSI TS 101 162
+    pub private_data_specifier: u32,
+}
+
+
+impl Desc5F {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            private_data_specifier: slice[2 ..].get_u32(),
+        }
+    }
+}
+
+
+impl Desc for Desc5F {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x5F
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x5F;
+        buffer[skip + 1] = (MIN_SIZE - 2) as u8;
+        buffer[skip + 2 ..].set_u32(self.private_data_specifier);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc5F,
+        PRIVATE_DATA_SPECIFIER_NORDIG,
+    };
+
+    static DATA_5F: &[u8] = &[0x5f, 0x04, 0x00, 0x00, 0x00, 0x29];
+
+    #[test]
+    fn test_5f_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_5F);
+
+        let desc = descriptors.find::<Desc5F>().unwrap();
+        assert_eq!(desc.private_data_specifier, PRIVATE_DATA_SPECIFIER_NORDIG);
+    }
+
+    #[test]
+    fn test_5f_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc5F {
+            private_data_specifier: PRIVATE_DATA_SPECIFIER_NORDIG,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_5F);
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 8;
+
+
+/// The service move descriptor is used if it is required to move
+/// a service from one TS to another. Receiver should retune to the new
+/// service location.
+///
+/// EN 300 468 - 6.2.36
+#[derive(Debug, Default, Clone)]
+pub struct Desc60 {
+    /// Original network of the TS to which the service is moved
+    pub new_onid: u16,
+    /// Transport stream to which the service is moved
+    pub new_tsid: u16,
+    /// Service id in the new TS
+    pub new_service_id: u16,
+}
+
+
+impl Desc60 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            new_onid: slice[2 ..].get_u16(),
+            new_tsid: slice[4 ..].get_u16(),
+            new_service_id: slice[6 ..].get_u16(),
+        }
+    }
+}
+
+
+impl Desc for Desc60 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x60
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x60;
+        buffer[skip + 1] = (MIN_SIZE - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.new_onid);
+        buffer[skip + 4 ..].set_u16(self.new_tsid);
+        buffer[skip + 6 ..].set_u16(self.new_service_id);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc60,
+    };
+
+    static DATA_60: &[u8] = &[0x60, 0x06, 0x00, 0x46, 0x00, 0x02, 0x01, 0x01];
+
+    #[test]
+    fn test_60_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_60);
+
+        let desc = descriptors.find::<Desc60>().unwrap();
+        assert_eq!(desc.new_onid, 70);
+        assert_eq!(desc.new_tsid, 2);
+        assert_eq!(desc.new_service_id, 257);
+    }
+
+    #[test]
+    fn test_60_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc60 {
+            new_onid: 70,
+            new_tsid: 2,
+            new_service_id: 257,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_60);
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The short smoothing buffer descriptor enables the bit-rate of a service
+/// to be signalled in the SIT or EIT.
+///
+/// EN 300 468 - 6.2.38
+#[derive(Debug, Default, Clone)]
+pub struct Desc61 {
+    /// Size of the smoothing buffer:
+    /// * `0` - DVB reserved
+    /// * `1` - 1 536 bytes
+    /// * `2` - DVB reserved
+    /// * `3` - DVB reserved
+    pub sb_size: u8,
+    /// Value of the leak rate out of the buffer.
+    /// See EN 300 468 Table 82 for the bit-rate values
+    pub sb_leak_rate: u8,
+    /// DVB reserved bytes
+    pub reserved: Vec<u8>,
+}
+
+
+impl Desc61 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            sb_size: slice[2] >> 6,
+            sb_leak_rate: slice[2] & 0x3F,
+            reserved: Vec::from(&slice[MIN_SIZE ..]),
+        }
+    }
+}
+
+
+impl Desc for Desc61 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x61
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.reserved.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x61);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.sb_size, 2,
+            self.sb_leak_rate, 6));
+        buffer.extend_from_slice(&self.reserved);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc61,
+    };
+
+    static DATA_61: &[u8] = &[0x61, 0x01, 0x4d];
+
+    #[test]
+    fn test_61_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_61);
+
+        let desc = descriptors.find::<Desc61>().unwrap();
+        assert_eq!(desc.sb_size, 1);
+        assert_eq!(desc.sb_leak_rate, 13);
+        assert!(desc.reserved.is_empty());
+    }
+
+    #[test]
+    fn test_61_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc61 {
+            sb_size: 1,
+            sb_leak_rate: 13,
+            reserved: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_61);
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    psi::BCD,
+};
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// Frequency coding is not defined
+pub const CODING_TYPE_UNDEFINED: u8 = 0;
+/// Satellite frequency in KHz
+pub const CODING_TYPE_SATELLITE: u8 = 1;
+/// Cable frequency in Hz
+pub const CODING_TYPE_CABLE: u8 = 2;
+/// Terrestrial frequency in Hz
+pub const CODING_TYPE_TERRESTRIAL: u8 = 3;
+
+
+/// The frequency list descriptor lists the additional frequencies
+/// used in transmission of the multiplex on other frequencies.
+///
+/// EN 300 468 - 6.2.17
+#[derive(Debug, Default, Clone)]
+pub struct Desc62 {
+    /// Frequency coding. Defines units of the frequencies:
+    /// * `CODING_TYPE_SATELLITE` - KHz, same as in the `Desc43`
+    /// * `CODING_TYPE_CABLE` - Hz, same as in the `Desc44`
+    /// * `CODING_TYPE_TERRESTRIAL` - Hz, same as in the `Desc5A`
+    pub coding_type: u8,
+    /// List of the centre frequencies
+    pub frequencies: Vec<u32>,
+}
+
+
+impl Desc62 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - MIN_SIZE) % 4) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let coding_type = slice[2] & 0x03;
+        let mut result = Self {
+            coding_type,
+            frequencies: Vec::new(),
+        };
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let value = slice[skip ..].get_u32();
+            let frequency = match coding_type {
+                CODING_TYPE_SATELLITE => value.from_bcd() * 10,
+                CODING_TYPE_CABLE => value.from_bcd() * 100,
+                CODING_TYPE_TERRESTRIAL => value * 10,
+                _ => value,
+            };
+            result.frequencies.push(frequency);
+            skip += 4;
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc62 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x62
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.frequencies.len() * 4
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x62);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0xFC | self.coding_type);
+
+        for frequency in &self.frequencies {
+            let value = match self.coding_type {
+                CODING_TYPE_SATELLITE => (frequency / 10).to_bcd(),
+                CODING_TYPE_CABLE => (frequency / 100).to_bcd(),
+                CODING_TYPE_TERRESTRIAL => frequency / 10,
+                _ => *frequency,
+            };
+
+            let skip = buffer.len();
+            buffer.resize(skip + 4, 0x00);
+            buffer[skip ..].set_u32(value);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc62,
+        CODING_TYPE_TERRESTRIAL,
+    };
+
+    static DATA_62: &[u8] = &[
+        0x62, 0x09, 0xff, 0x04, 0xc7, 0xc1, 0x40, 0x04, 0xd3, 0xf6, 0x40,
+    ];
+
+    #[test]
+    fn test_62_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_62);
+
+        let desc = descriptors.find::<Desc62>().unwrap();
+        assert_eq!(desc.coding_type, CODING_TYPE_TERRESTRIAL);
+        assert_eq!(desc.frequencies, vec![802000000, 810000000]);
+    }
+
+    #[test]
+    fn test_62_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc62 {
+            coding_type: CODING_TYPE_TERRESTRIAL,
+            frequencies: vec![802000000, 810000000],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_62);
+    }
+}
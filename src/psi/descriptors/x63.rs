@@ -0,0 +1,119 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 10;
+
+
+/// The partial transport stream descriptor is used in the SIT
+/// to describe bitrates of the partial TS.
+///
+/// EN 300 468 - 7.2.1
+#[derive(Debug, Default, Clone)]
+pub struct Desc63 {
+    /// Maximum momentary transport packet rate in units of 400 bit/s.
+    pub peak_rate: u32,
+    /// Minimum smoothing buffer leak rate in units of 400 bit/s.
+    /// `0x3FFFFF` - rate is undefined
+    pub minimum_overall_smoothing_rate: u32,
+    /// Maximum smoothing buffer size in bytes.
+    /// `0x3FFF` - buffer size is undefined
+    pub maximum_overall_smoothing_buffer: u16,
+}
+
+
+impl Desc63 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            peak_rate: slice[2 ..].get_u24() & 0x003F_FFFF,
+            minimum_overall_smoothing_rate: slice[5 ..].get_u24() & 0x003F_FFFF,
+            maximum_overall_smoothing_buffer: slice[8 ..].get_u16() & 0x3FFF,
+        }
+    }
+}
+
+
+impl Desc for Desc63 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x63
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0x63;
+        buffer[skip + 1] = (size - 2) as u8;
+        buffer[skip + 2 ..].set_u24(0x00C0_0000 | (self.peak_rate & 0x003F_FFFF));
+        buffer[skip + 5 ..].set_u24(0x00C0_0000 | (self.minimum_overall_smoothing_rate & 0x003F_FFFF));
+        buffer[skip + 8 ..].set_u16(0xC000 | (self.maximum_overall_smoothing_buffer & 0x3FFF));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc63,
+    };
+
+    static DATA_63: &[u8] = &[0x63, 0x08, 0xc0, 0x27, 0x10, 0xff, 0xff, 0xff, 0xff, 0xff];
+
+    #[test]
+    fn test_63_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_63);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc63>().unwrap();
+        assert_eq!(desc.peak_rate, 10000);
+        assert_eq!(desc.minimum_overall_smoothing_rate, 0x3FFFFF);
+        assert_eq!(desc.maximum_overall_smoothing_buffer, 0x3FFF);
+    }
+
+    #[test]
+    fn test_63_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc63 {
+            peak_rate: 10000,
+            minimum_overall_smoothing_rate: 0x3FFFFF,
+            maximum_overall_smoothing_buffer: 0x3FFF,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_63);
+
+        // values out of range do not overwrite reserved bits
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc63 {
+            peak_rate: 0x0040_0000 | 10000,
+            minimum_overall_smoothing_rate: 0xFFFF_FFFF,
+            maximum_overall_smoothing_buffer: 0xFFFF,
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_63);
+    }
+}
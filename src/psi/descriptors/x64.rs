@@ -0,0 +1,140 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    textcode::StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 10;
+
+
+/// The data broadcast descriptor identifies the type of the data component
+/// and may be used to provide a text description of the data component.
+///
+/// EN 300 468 - 6.2.11
+#[derive(Debug, Default, Clone)]
+pub struct Desc64 {
+    /// Data broadcast specification that is used to broadcast the data.
+    /// Values are registered in ETSI TS 101 162
+    pub data_broadcast_id: u16,
+    /// Same as in the stream identifier descriptor of the data component
+    pub component_tag: u8,
+    /// Selector bytes defined by the data broadcast specification
+    pub selector: Vec<u8>,
+    /// ISO 639 language code of the text description
+    pub lang: StringDVB,
+    /// Text description of the data component
+    pub text: StringDVB,
+}
+
+
+impl Desc64 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let selector_length = usize::from(slice[5]);
+        if slice.len() < MIN_SIZE + selector_length {
+            return false;
+        }
+
+        let text_length = usize::from(slice[9 + selector_length]);
+        slice.len() == MIN_SIZE + selector_length + text_length
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let selector_e = 6 + usize::from(slice[5]);
+        let text_s = selector_e + 4;
+        let text_e = text_s + usize::from(slice[selector_e + 3]);
+
+        Self {
+            data_broadcast_id: slice[2 ..].get_u16(),
+            component_tag: slice[4],
+            selector: Vec::from(&slice[6 .. selector_e]),
+            lang: StringDVB::from(&slice[selector_e .. selector_e + 3]),
+            text: StringDVB::from(&slice[text_s .. text_e]),
+        }
+    }
+}
+
+
+impl Desc for Desc64 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x64
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.selector.len() + self.text.size()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + 6, 0x00);
+        buffer[skip] = 0x64;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.data_broadcast_id);
+        buffer[skip + 4] = self.component_tag;
+        buffer[skip + 5] = self.selector.len() as u8;
+        buffer.extend_from_slice(&self.selector);
+
+        self.lang.assemble(buffer);
+        self.text.assemble_sized(buffer);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc64,
+        },
+    };
+
+    static DATA_64: &[u8] = &[
+        0x64, 0x0d, 0x00, 0x0b, 0x0a, 0x02, 0x01, 0x02, 0x65, 0x6e, 0x67, 0x03, 0x53, 0x53,
+        0x55,
+    ];
+
+    #[test]
+    fn test_64_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_64);
+
+        let desc = descriptors.find::<Desc64>().unwrap();
+        assert_eq!(desc.data_broadcast_id, 0x000B);
+        assert_eq!(desc.component_tag, 0x0A);
+        assert_eq!(desc.selector, [0x01, 0x02]);
+        assert_eq!(desc.lang, textcode::StringDVB::from_str("eng", textcode::ISO6937));
+        assert_eq!(desc.text.to_string(), "SSU");
+    }
+
+    #[test]
+    fn test_64_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc64 {
+            data_broadcast_id: 0x000B,
+            component_tag: 0x0A,
+            selector: vec![0x01, 0x02],
+            lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+            text: textcode::StringDVB::from_str("SSU", textcode::ISO6937),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_64);
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 4;
+
+
+/// The data broadcast id descriptor identifies the type of the data
+/// component in the PMT.
+///
+/// EN 300 468 - 6.2.12
+#[derive(Debug, Default, Clone)]
+pub struct Desc66 {
+    /// Data broadcast specification that is used to broadcast the data.
+    /// Values are registered in ETSI TS 101 162
+    pub data_broadcast_id: u16,
+    /// Selector bytes defined by the data broadcast specification
+    pub selector: Vec<u8>,
+}
+
+
+impl Desc66 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            data_broadcast_id: slice[2 ..].get_u16(),
+            selector: Vec::from(&slice[MIN_SIZE ..]),
+        }
+    }
+}
+
+
+impl Desc for Desc66 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x66
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.selector.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x66;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2 ..].set_u16(self.data_broadcast_id);
+        buffer.extend_from_slice(&self.selector);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc66,
+    };
+
+    static DATA_66: &[u8] = &[0x66, 0x05, 0x00, 0x0a, 0x00, 0x00, 0x01];
+
+    #[test]
+    fn test_66_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_66);
+
+        let desc = descriptors.find::<Desc66>().unwrap();
+        assert_eq!(desc.data_broadcast_id, 0x000A);
+        assert_eq!(desc.selector, [0x00, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_66_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc66 {
+            data_broadcast_id: 0x000A,
+            selector: vec![0x00, 0x00, 0x01],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_66);
+    }
+}
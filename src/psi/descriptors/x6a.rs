@@ -0,0 +1,144 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The AC-3 descriptor is used to identify streams which carry
+/// AC-3 audio.
+///
+/// EN 300 468 - D.3
+#[derive(Debug, Default, Clone)]
+pub struct Desc6A {
+    /// Type of the audio service
+    pub component_type: Option<u8>,
+    /// AC-3 coding version
+    pub bsid: Option<u8>,
+    /// Main audio service identifier
+    pub mainid: Option<u8>,
+    /// Associated main service for the associated service
+    pub asvc: Option<u8>,
+    /// Additional information
+    pub additional_info: Vec<u8>,
+}
+
+
+impl Desc6A {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let flags = slice[2] >> 4;
+        MIN_SIZE + flags.count_ones() as usize <= slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut skip = 3;
+        let mut next = |flag: u8| {
+            if slice[2] & flag != 0 {
+                skip += 1;
+                Some(slice[skip - 1])
+            } else {
+                None
+            }
+        };
+
+        let component_type = next(0x80);
+        let bsid = next(0x40);
+        let mainid = next(0x20);
+        let asvc = next(0x10);
+
+        Self {
+            component_type,
+            bsid,
+            mainid,
+            asvc,
+            additional_info: Vec::from(&slice[skip ..]),
+        }
+    }
+
+    #[inline]
+    fn fields(&self) -> [Option<u8>; 4] {
+        [self.component_type, self.bsid, self.mainid, self.asvc]
+    }
+}
+
+
+impl Desc for Desc6A {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x6A
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE +
+            self.fields().iter().filter(|v| v.is_some()).count() +
+            self.additional_info.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x6A);
+        buffer.push((self.size() - 2) as u8);
+
+        let fields = self.fields();
+        let mut flags = 0x00;
+        for (i, v) in fields.iter().enumerate() {
+            if v.is_some() {
+                flags |= 0x80 >> i;
+            }
+        }
+        buffer.push(flags);
+        buffer.extend(fields.iter().flatten());
+        buffer.extend_from_slice(&self.additional_info);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Desc,
+        Descriptors,
+        Desc6A,
+    };
+
+    static DATA_6A: &[u8] = &[0x6a, 0x03, 0xa0, 0x42, 0x01];
+
+    #[test]
+    fn test_6a_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_6A);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc6A>().unwrap();
+        assert_eq!(desc.size(), DATA_6A.len());
+        assert_eq!(desc.component_type, Some(0x42));
+        assert_eq!(desc.bsid, None);
+        assert_eq!(desc.mainid, Some(0x01));
+        assert_eq!(desc.asvc, None);
+        assert!(desc.additional_info.is_empty());
+    }
+
+    #[test]
+    fn test_6a_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc6A {
+            component_type: Some(0x42),
+            mainid: Some(0x01),
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_6A);
+    }
+}
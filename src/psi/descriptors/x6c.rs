@@ -0,0 +1,234 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+/// Size of the cell without subcells
+const CELL_SIZE: usize = 10;
+/// Size of the subcell
+const SUBCELL_SIZE: usize = 8;
+
+
+/// Geographical area of the cell or subcell.
+/// Latitude and longitude are in units of 90/2^15 and 180/2^15 degrees
+#[derive(Debug, Default, Clone)]
+pub struct CellArea {
+    /// Latitude of the south-west corner of the rectangle
+    pub latitude: i16,
+    /// Longitude of the south-west corner of the rectangle
+    pub longitude: i16,
+    /// Extent of latitude of the rectangle
+    pub extent_of_latitude: u16,
+    /// Extent of longitude of the rectangle
+    pub extent_of_longitude: u16,
+}
+
+
+impl CellArea {
+    fn parse(slice: &[u8]) -> Self {
+        let extent = slice[4 ..].get_u24();
+        Self {
+            latitude: slice.get_u16() as i16,
+            longitude: slice[2 ..].get_u16() as i16,
+            extent_of_latitude: (extent >> 12) as u16,
+            extent_of_longitude: (extent & 0x0FFF) as u16,
+        }
+    }
+
+    fn assemble(&self, buffer: &mut [u8]) {
+        buffer.set_u16(self.latitude as u16);
+        buffer[2 ..].set_u16(self.longitude as u16);
+        buffer[4 ..].set_u24(
+            (u32::from(self.extent_of_latitude & 0x0FFF) << 12) |
+            u32::from(self.extent_of_longitude & 0x0FFF));
+    }
+}
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc6Cs {
+    /// Identifies a subcell within a cell
+    pub cell_id_extension: u8,
+    /// Coverage area of the subcell
+    pub area: CellArea,
+}
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc6Ci {
+    /// Identifies a cell
+    pub cell_id: u16,
+    /// Coverage area of the cell
+    pub area: CellArea,
+    /// List of the subcells
+    pub subcells: Vec<Desc6Cs>,
+}
+
+
+/// The cell list descriptor provides a list of all cells of the network
+/// and describes their coverage areas.
+///
+/// EN 300 468 - 6.2.7
+#[derive(Debug, Default, Clone)]
+pub struct Desc6C {
+    pub items: Vec<Desc6Ci>,
+}
+
+
+impl Desc6C {
+    pub fn check(slice: &[u8]) -> bool {
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            if slice.len() < skip + CELL_SIZE {
+                return false;
+            }
+            let subcell_loop_length = usize::from(slice[skip + CELL_SIZE - 1]);
+            if (subcell_loop_length % SUBCELL_SIZE) != 0 {
+                return false;
+            }
+            skip += CELL_SIZE + subcell_loop_length;
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let mut item = Desc6Ci {
+                cell_id: slice[skip ..].get_u16(),
+                area: CellArea::parse(&slice[skip + 2 ..]),
+                subcells: Vec::new(),
+            };
+
+            let subcell_loop_end = skip + CELL_SIZE + usize::from(slice[skip + CELL_SIZE - 1]);
+            skip += CELL_SIZE;
+            while subcell_loop_end > skip {
+                item.subcells.push(Desc6Cs {
+                    cell_id_extension: slice[skip],
+                    area: CellArea::parse(&slice[skip + 1 ..]),
+                });
+                skip += SUBCELL_SIZE;
+            }
+
+            result.items.push(item);
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc6C {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x6C
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| {
+            acc + CELL_SIZE + item.subcells.len() * SUBCELL_SIZE
+        })
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x6C);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            let skip = buffer.len();
+            buffer.resize(skip + CELL_SIZE, 0x00);
+            buffer[skip ..].set_u16(item.cell_id);
+            item.area.assemble(&mut buffer[skip + 2 ..]);
+            buffer[skip + CELL_SIZE - 1] = (item.subcells.len() * SUBCELL_SIZE) as u8;
+
+            for subcell in &item.subcells {
+                let skip = buffer.len();
+                buffer.resize(skip + SUBCELL_SIZE, 0x00);
+                buffer[skip] = subcell.cell_id_extension;
+                subcell.area.assemble(&mut buffer[skip + 1 ..]);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        CellArea,
+        Desc6C,
+        Desc6Ci,
+        Desc6Cs,
+    };
+
+    static DATA_6C: &[u8] = &[
+        0x6c, 0x12, 0x00, 0x01, 0x24, 0x00, 0xfe, 0x00, 0x01, 0x00, 0x20, 0x08, 0x01, 0x24, 0x10,
+        0xfe, 0x10, 0x00, 0x80, 0x08,
+    ];
+
+    #[test]
+    fn test_6c_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_6C);
+
+        let desc = descriptors.find::<Desc6C>().unwrap();
+        assert_eq!(desc.items.len(), 1);
+
+        let item = &desc.items[0];
+        assert_eq!(item.cell_id, 1);
+        assert_eq!(item.area.latitude, 0x2400);
+        assert_eq!(item.area.longitude, -512);
+        assert_eq!(item.area.extent_of_latitude, 0x010);
+        assert_eq!(item.area.extent_of_longitude, 0x020);
+        assert_eq!(item.subcells.len(), 1);
+        assert_eq!(item.subcells[0].cell_id_extension, 1);
+        assert_eq!(item.subcells[0].area.extent_of_latitude, 0x008);
+        assert_eq!(item.subcells[0].area.extent_of_longitude, 0x008);
+    }
+
+    #[test]
+    fn test_6c_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc6C {
+            items: vec![
+                Desc6Ci {
+                    cell_id: 1,
+                    area: CellArea {
+                        latitude: 0x2400,
+                        longitude: -512,
+                        extent_of_latitude: 0x010,
+                        extent_of_longitude: 0x020,
+                    },
+                    subcells: vec![
+                        Desc6Cs {
+                            cell_id_extension: 1,
+                            area: CellArea {
+                                latitude: 0x2410,
+                                longitude: -496,
+                                extent_of_latitude: 0x008,
+                                extent_of_longitude: 0x008,
+                            },
+                        },
+                    ],
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_6C);
+    }
+}
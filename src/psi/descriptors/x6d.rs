@@ -0,0 +1,182 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+/// Size of the cell without subcells
+const CELL_SIZE: usize = 7;
+/// Size of the subcell
+const SUBCELL_SIZE: usize = 5;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc6Ds {
+    /// Identifies a subcell within a cell
+    pub cell_id_extension: u8,
+    /// Frequency of the transposer in Hz
+    pub transposer_frequency: u32,
+}
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc6Di {
+    /// Identifies a cell
+    pub cell_id: u16,
+    /// Frequency of the cell in Hz
+    pub frequency: u32,
+    /// List of the subcells
+    pub subcells: Vec<Desc6Ds>,
+}
+
+
+/// The cell frequency link descriptor announces cells and subcells
+/// and links them to the frequencies.
+///
+/// EN 300 468 - 6.2.6
+#[derive(Debug, Default, Clone)]
+pub struct Desc6D {
+    pub items: Vec<Desc6Di>,
+}
+
+
+impl Desc6D {
+    pub fn check(slice: &[u8]) -> bool {
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            if slice.len() < skip + CELL_SIZE {
+                return false;
+            }
+            let subcell_loop_length = usize::from(slice[skip + CELL_SIZE - 1]);
+            if (subcell_loop_length % SUBCELL_SIZE) != 0 {
+                return false;
+            }
+            skip += CELL_SIZE + subcell_loop_length;
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let mut item = Desc6Di {
+                cell_id: slice[skip ..].get_u16(),
+                frequency: slice[skip + 2 ..].get_u32() * 10,
+                subcells: Vec::new(),
+            };
+
+            let subcell_loop_end = skip + CELL_SIZE + usize::from(slice[skip + CELL_SIZE - 1]);
+            skip += CELL_SIZE;
+            while subcell_loop_end > skip {
+                item.subcells.push(Desc6Ds {
+                    cell_id_extension: slice[skip],
+                    transposer_frequency: slice[skip + 1 ..].get_u32() * 10,
+                });
+                skip += SUBCELL_SIZE;
+            }
+
+            result.items.push(item);
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc6D {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x6D
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| {
+            acc + CELL_SIZE + item.subcells.len() * SUBCELL_SIZE
+        })
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x6D);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            let skip = buffer.len();
+            buffer.resize(skip + CELL_SIZE, 0x00);
+            buffer[skip ..].set_u16(item.cell_id);
+            buffer[skip + 2 ..].set_u32(item.frequency / 10);
+            buffer[skip + CELL_SIZE - 1] = (item.subcells.len() * SUBCELL_SIZE) as u8;
+
+            for subcell in &item.subcells {
+                let skip = buffer.len();
+                buffer.resize(skip + SUBCELL_SIZE, 0x00);
+                buffer[skip] = subcell.cell_id_extension;
+                buffer[skip + 1 ..].set_u32(subcell.transposer_frequency / 10);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc6D,
+        Desc6Di,
+        Desc6Ds,
+    };
+
+    static DATA_6D: &[u8] = &[
+        0x6d, 0x0c, 0x00, 0x01, 0x04, 0xc7, 0xc1, 0x40, 0x05, 0x01, 0x04, 0xd3, 0xf6, 0x40,
+    ];
+
+    #[test]
+    fn test_6d_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_6D);
+
+        let desc = descriptors.find::<Desc6D>().unwrap();
+        assert_eq!(desc.items.len(), 1);
+
+        let item = &desc.items[0];
+        assert_eq!(item.cell_id, 1);
+        assert_eq!(item.frequency, 802000000);
+        assert_eq!(item.subcells.len(), 1);
+        assert_eq!(item.subcells[0].cell_id_extension, 1);
+        assert_eq!(item.subcells[0].transposer_frequency, 810000000);
+    }
+
+    #[test]
+    fn test_6d_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc6D {
+            items: vec![
+                Desc6Di {
+                    cell_id: 1,
+                    frequency: 802000000,
+                    subcells: vec![
+                        Desc6Ds {
+                            cell_id_extension: 1,
+                            transposer_frequency: 810000000,
+                        },
+                    ],
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_6D);
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+/// HbbTV application type
+pub const APPLICATION_TYPE_HBBTV: u16 = 0x0010;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc6Fi {
+    /// Type of the application signalled in the AIT
+    pub application_type: u16,
+    /// Version of the AIT sub-table for the application type
+    pub ait_version: u8,
+}
+
+
+/// The application signalling descriptor is placed in the PMT
+/// elementary stream loop of the stream carrying the AIT.
+///
+/// TS 102 809 - 5.3.5.1
+#[derive(Debug, Default, Clone)]
+pub struct Desc6F {
+    /// List of pairs application type and AIT version
+    pub items: Vec<Desc6Fi>,
+}
+
+
+impl Desc6F {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - MIN_SIZE) % 3) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            result.items.push(Desc6Fi {
+                application_type: slice[skip ..].get_u16() & 0x7FFF,
+                ait_version: slice[skip + 2] & 0x1F,
+            });
+            skip += 3;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc6F {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x6F
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 3
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let mut skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0x6F;
+        buffer[skip + 1] = (size - 2) as u8;
+        skip += 2;
+
+        for item in &self.items {
+            buffer[skip ..].set_u16(0x8000 | item.application_type);
+            buffer[skip + 2] = 0xE0 | item.ait_version;
+            skip += 3;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc6F,
+        Desc6Fi,
+        APPLICATION_TYPE_HBBTV,
+    };
+
+    static DATA_6F: &[u8] = &[0x6f, 0x03, 0x80, 0x10, 0xe3];
+
+    #[test]
+    fn test_6f_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_6F);
+
+        let desc = descriptors.find::<Desc6F>().unwrap();
+        assert_eq!(desc.items.len(), 1);
+        assert_eq!(desc.items[0].application_type, APPLICATION_TYPE_HBBTV);
+        assert_eq!(desc.items[0].ait_version, 3);
+    }
+
+    #[test]
+    fn test_6f_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc6F {
+            items: vec![
+                Desc6Fi {
+                    application_type: APPLICATION_TYPE_HBBTV,
+                    ait_version: 3,
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_6F);
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The service availability descriptor provides an identification of
+/// the cells in which the service is available or not available.
+///
+/// EN 300 468 - 6.2.34
+#[derive(Debug, Default, Clone)]
+pub struct Desc72 {
+    /// * `true` - service is available in the listed cells
+    /// * `false` - service is not available in the listed cells
+    pub availability_flag: bool,
+    /// List of cell identifiers
+    pub cells: Vec<u16>,
+}
+
+
+impl Desc72 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - MIN_SIZE) % 2) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            availability_flag: (slice[2] & 0x80) != 0,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            result.cells.push(slice[skip ..].get_u16());
+            skip += 2;
+        }
+
+        result
+    }
+
+    /// Returns `true` if service is available in the cell
+    pub fn is_available(&self, cell_id: u16) -> bool {
+        self.cells.contains(&cell_id) == self.availability_flag
+    }
+}
+
+
+impl Desc for Desc72 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x72
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.cells.len() * 2
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let mut skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0x72;
+        buffer[skip + 1] = (size - 2) as u8;
+        buffer[skip + 2] = if self.availability_flag { 0xFF } else { 0x7F };
+        skip += 3;
+
+        for item in &self.cells {
+            buffer[skip ..].set_u16(*item);
+            skip += 2;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc72,
+    };
+
+    static DATA_72: &[u8] = &[0x72, 0x05, 0x7f, 0x00, 0x01, 0x00, 0x02];
+
+    #[test]
+    fn test_72_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_72);
+
+        let desc = descriptors.find::<Desc72>().unwrap();
+        assert!(! desc.availability_flag);
+        assert_eq!(desc.cells, vec![1, 2]);
+        assert!(! desc.is_available(1));
+        assert!(desc.is_available(3));
+    }
+
+    #[test]
+    fn test_72_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc72 {
+            availability_flag: false,
+            cells: vec![1, 2],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_72);
+    }
+}
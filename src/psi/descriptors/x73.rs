@@ -0,0 +1,88 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+/// The default authority descriptor defines the authority part of the CRIDs
+/// for the events in its scope. Could be placed in the NIT network loop,
+/// NIT transport stream loop or SDT service loop.
+///
+/// TS 102 323 - 5.2.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc73 {
+    /// Default authority name without `crid://` prefix
+    pub authority: String,
+}
+
+
+impl Desc73 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            authority: String::from_utf8_lossy(&slice[MIN_SIZE ..]).into_owned(),
+        }
+    }
+}
+
+
+impl Desc for Desc73 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x73
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.authority.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x73);
+        buffer.push((self.size() - 2) as u8);
+        buffer.extend_from_slice(self.authority.as_bytes());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc73,
+    };
+
+    static DATA_73: &[u8] = &[0x73, 0x07, 0x62, 0x62, 0x63, 0x2e, 0x63, 0x6f, 0x6d];
+
+    #[test]
+    fn test_73_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_73);
+
+        let desc = descriptors.find::<Desc73>().unwrap();
+        assert_eq!(desc.authority, "bbc.com");
+    }
+
+    #[test]
+    fn test_73_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc73 {
+            authority: "bbc.com".to_owned(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_73);
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+/// CRID references a single programme
+pub const CRID_TYPE_PROGRAMME: u8 = 0x01;
+/// CRID references a series of programmes
+pub const CRID_TYPE_SERIES: u8 = 0x02;
+/// CRID references a recommendation
+pub const CRID_TYPE_RECOMMENDATION: u8 = 0x03;
+
+/// CRID is carried explicitly in the descriptor
+pub const CRID_LOCATION_DESCRIPTOR: u8 = 0;
+/// CRID is carried in the Content Identifier Table
+pub const CRID_LOCATION_CIT: u8 = 1;
+
+
+/// Location of the CRID
+#[derive(Debug, Clone)]
+pub enum Desc76Crid {
+    /// CRID carried in the descriptor. Could be complete
+    /// or started with `/` if the default authority should be applied
+    Descriptor(String),
+    /// Reference to the CRID in the Content Identifier Table
+    Reference(u16),
+}
+
+
+#[derive(Debug, Clone)]
+pub struct Desc76i {
+    /// Type of the CRID
+    pub crid_type: u8,
+    /// CRID value or reference
+    pub crid: Desc76Crid,
+}
+
+
+impl Desc76i {
+    #[inline]
+    fn size(&self) -> usize {
+        match &self.crid {
+            Desc76Crid::Descriptor(v) => 2 + v.len(),
+            Desc76Crid::Reference(_) => 3,
+        }
+    }
+}
+
+
+/// The content identifier descriptor is used to assign one or more CRIDs
+/// to the event.
+///
+/// TS 102 323 - 12.1
+#[derive(Debug, Default, Clone)]
+pub struct Desc76 {
+    pub items: Vec<Desc76i>,
+}
+
+
+impl Desc76 {
+    pub fn check(slice: &[u8]) -> bool {
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            skip += match slice[skip] & 0x03 {
+                CRID_LOCATION_DESCRIPTOR if slice.len() > skip + 1 => {
+                    2 + usize::from(slice[skip + 1])
+                }
+                CRID_LOCATION_CIT => 3,
+                _ => return false,
+            };
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let crid_type = slice[skip] >> 2;
+            let crid = if slice[skip] & 0x03 == CRID_LOCATION_DESCRIPTOR {
+                let crid_s = skip + 2;
+                let crid_e = crid_s + usize::from(slice[skip + 1]);
+                skip = crid_e;
+                Desc76Crid::Descriptor(String::from_utf8_lossy(&slice[crid_s .. crid_e]).into_owned())
+            } else {
+                let crid_ref = slice[skip + 1 ..].get_u16();
+                skip += 3;
+                Desc76Crid::Reference(crid_ref)
+            };
+
+            result.items.push(Desc76i {
+                crid_type,
+                crid,
+            });
+        }
+
+        result
+    }
+
+    /// Returns CRID value by the CRID type if it carried in the descriptor
+    pub fn get_crid(&self, crid_type: u8) -> Option<&str> {
+        self.items.iter()
+            .filter(|item| item.crid_type == crid_type)
+            .find_map(|item| match &item.crid {
+                Desc76Crid::Descriptor(v) => Some(v.as_str()),
+                Desc76Crid::Reference(_) => None,
+            })
+    }
+}
+
+
+impl Desc for Desc76 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x76
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| acc + item.size())
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x76);
+        buffer.push((self.size() - 2) as u8);
+
+        for item in &self.items {
+            match &item.crid {
+                Desc76Crid::Descriptor(v) => {
+                    buffer.push(set_bits!(8,
+                        item.crid_type, 6,
+                        CRID_LOCATION_DESCRIPTOR, 2));
+                    buffer.push(v.len() as u8);
+                    buffer.extend_from_slice(v.as_bytes());
+                }
+                Desc76Crid::Reference(v) => {
+                    buffer.push(set_bits!(8,
+                        item.crid_type, 6,
+                        CRID_LOCATION_CIT, 2));
+                    let skip = buffer.len();
+                    buffer.resize(skip + 2, 0x00);
+                    buffer[skip ..].set_u16(*v);
+                }
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc76,
+        Desc76i,
+        Desc76Crid,
+        CRID_TYPE_PROGRAMME,
+        CRID_TYPE_SERIES,
+        CRID_TYPE_RECOMMENDATION,
+    };
+
+    static DATA_76: &[u8] = &[
+        0x76, 0x0f, 0x04, 0x05, 0x2f, 0x41, 0x42, 0x43, 0x31, 0x08, 0x03, 0x2f, 0x53, 0x31,
+        0x0d, 0x00, 0x10,
+    ];
+
+    #[test]
+    fn test_76_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_76);
+
+        let desc = descriptors.find::<Desc76>().unwrap();
+        assert_eq!(desc.items.len(), 3);
+        assert_eq!(desc.get_crid(CRID_TYPE_PROGRAMME), Some("/ABC1"));
+        assert_eq!(desc.get_crid(CRID_TYPE_SERIES), Some("/S1"));
+        assert_eq!(desc.get_crid(CRID_TYPE_RECOMMENDATION), None);
+        match desc.items[2].crid {
+            Desc76Crid::Reference(v) => assert_eq!(v, 0x10),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_76_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc76 {
+            items: vec![
+                Desc76i {
+                    crid_type: CRID_TYPE_PROGRAMME,
+                    crid: Desc76Crid::Descriptor("/ABC1".to_owned()),
+                },
+                Desc76i {
+                    crid_type: CRID_TYPE_SERIES,
+                    crid: Desc76Crid::Descriptor("/S1".to_owned()),
+                },
+                Desc76i {
+                    crid_type: CRID_TYPE_RECOMMENDATION,
+                    crid: Desc76Crid::Reference(0x10),
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_76);
+    }
+}
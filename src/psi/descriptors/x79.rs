@@ -0,0 +1,164 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// Transport Stream
+pub const TS_GS_MODE_TS: u8 = 3;
+
+
+/// S2 satellite delivery system descriptor.
+/// Used with satellite delivery system descriptor for DVB-S2 multistream
+/// and physical layer scrambling.
+///
+/// EN 300 468 - 6.2.13.3
+#[derive(Debug, Clone)]
+pub struct Desc79 {
+    /// Physical layer scrambling sequence index
+    pub scrambling_sequence_index: Option<u32>,
+    /// Input stream identifier for multiple input stream
+    pub input_stream_identifier: Option<u8>,
+    /// Time slice number if time slicing is used
+    pub timeslice_number: Option<u8>,
+    /// Stream type: `TS_GS_MODE_TS` or generic stream
+    pub ts_gs_mode: u8,
+}
+
+
+impl Default for Desc79 {
+    fn default() -> Self {
+        Self {
+            scrambling_sequence_index: None,
+            input_stream_identifier: None,
+            timeslice_number: None,
+            ts_gs_mode: TS_GS_MODE_TS,
+        }
+    }
+}
+
+
+impl Desc79 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let flags = slice[2];
+        let size = MIN_SIZE +
+            if (flags & 0x80) != 0 { 3 } else { 0 } +
+            if (flags & 0x40) != 0 { 1 } else { 0 } +
+            if (flags & 0x10) == 0 { 1 } else { 0 };
+        slice.len() == size
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let flags = slice[2];
+        let mut result = Self {
+            ts_gs_mode: flags & 0x03,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        if (flags & 0x80) != 0 {
+            result.scrambling_sequence_index = Some(slice[skip ..].get_u24() & 0x0003_FFFF);
+            skip += 3;
+        }
+        if (flags & 0x40) != 0 {
+            result.input_stream_identifier = Some(slice[skip]);
+            skip += 1;
+        }
+        if (flags & 0x10) == 0 {
+            result.timeslice_number = Some(slice[skip]);
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc79 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x79
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE +
+            self.scrambling_sequence_index.map_or(0, |_| 3) +
+            self.input_stream_identifier.map_or(0, |_| 1) +
+            self.timeslice_number.map_or(0, |_| 1)
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x79);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(set_bits!(8,
+            u8::from(self.scrambling_sequence_index.is_some()), 1,
+            u8::from(self.input_stream_identifier.is_some()), 1,
+            0, 1,
+            u8::from(self.timeslice_number.is_none()), 1,
+            0x03, 2,
+            self.ts_gs_mode, 2));
+
+        if let Some(index) = self.scrambling_sequence_index {
+            let skip = buffer.len();
+            buffer.resize(skip + 3, 0x00);
+            buffer[skip ..].set_u24(0xFC_0000 | (index & 0x0003_FFFF));
+        }
+        if let Some(isi) = self.input_stream_identifier {
+            buffer.push(isi);
+        }
+        if let Some(timeslice_number) = self.timeslice_number {
+            buffer.push(timeslice_number);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc79,
+        TS_GS_MODE_TS,
+    };
+
+    static DATA_79: &[u8] = &[0x79, 0x05, 0xdf, 0xfc, 0x00, 0x10, 0x02];
+
+    #[test]
+    fn test_79_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_79);
+
+        let desc = descriptors.find::<Desc79>().unwrap();
+        assert_eq!(desc.scrambling_sequence_index, Some(0x10));
+        assert_eq!(desc.input_stream_identifier, Some(2));
+        assert_eq!(desc.timeslice_number, None);
+        assert_eq!(desc.ts_gs_mode, TS_GS_MODE_TS);
+    }
+
+    #[test]
+    fn test_79_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc79 {
+            scrambling_sequence_index: Some(0x10),
+            input_stream_identifier: Some(2),
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_79);
+    }
+}
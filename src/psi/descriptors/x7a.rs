@@ -0,0 +1,175 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The enhanced AC-3 descriptor is used to identify streams which carry
+/// E-AC-3 audio.
+///
+/// EN 300 468 - D.5
+#[derive(Debug, Default, Clone)]
+pub struct Desc7A {
+    /// Type of the audio service
+    pub component_type: Option<u8>,
+    /// E-AC-3 coding version
+    pub bsid: Option<u8>,
+    /// Main audio service identifier
+    pub mainid: Option<u8>,
+    /// Associated main service for the associated service
+    pub asvc: Option<u8>,
+    /// Stream contains mixing metadata
+    pub mix_info_exists: bool,
+    /// Type of the independent substream 1
+    pub substream1: Option<u8>,
+    /// Type of the independent substream 2
+    pub substream2: Option<u8>,
+    /// Type of the independent substream 3
+    pub substream3: Option<u8>,
+    /// Additional information
+    pub additional_info: Vec<u8>,
+}
+
+
+impl Desc7A {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let flags = slice[2] & 0xF7;
+        MIN_SIZE + flags.count_ones() as usize <= slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut skip = 3;
+        let mut next = |flag: u8| {
+            if slice[2] & flag != 0 {
+                skip += 1;
+                Some(slice[skip - 1])
+            } else {
+                None
+            }
+        };
+
+        let component_type = next(0x80);
+        let bsid = next(0x40);
+        let mainid = next(0x20);
+        let asvc = next(0x10);
+        let substream1 = next(0x04);
+        let substream2 = next(0x02);
+        let substream3 = next(0x01);
+
+        Self {
+            component_type,
+            bsid,
+            mainid,
+            asvc,
+            mix_info_exists: (slice[2] & 0x08) != 0,
+            substream1,
+            substream2,
+            substream3,
+            additional_info: Vec::from(&slice[skip ..]),
+        }
+    }
+
+    /// Optional fields in order of the flags.
+    /// Position 4 is the mixinfoexists flag without field
+    #[inline]
+    fn fields(&self) -> [Option<u8>; 8] {
+        [
+            self.component_type,
+            self.bsid,
+            self.mainid,
+            self.asvc,
+            None,
+            self.substream1,
+            self.substream2,
+            self.substream3,
+        ]
+    }
+}
+
+
+impl Desc for Desc7A {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x7A
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE +
+            self.fields().iter().filter(|v| v.is_some()).count() +
+            self.additional_info.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7A);
+        buffer.push((self.size() - 2) as u8);
+
+        let fields = self.fields();
+        let mut flags = if self.mix_info_exists { 0x08 } else { 0x00 };
+        for (i, v) in fields.iter().enumerate() {
+            if v.is_some() {
+                flags |= 0x80 >> i;
+            }
+        }
+        buffer.push(flags);
+        buffer.extend(fields.iter().flatten());
+        buffer.extend_from_slice(&self.additional_info);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Desc,
+        Descriptors,
+        Desc7A,
+    };
+
+    static DATA_7A: &[u8] = &[0x7a, 0x04, 0xcc, 0x42, 0x10, 0x15];
+
+    #[test]
+    fn test_7a_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7A);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc7A>().unwrap();
+        assert_eq!(desc.size(), DATA_7A.len());
+        assert_eq!(desc.component_type, Some(0x42));
+        assert_eq!(desc.bsid, Some(0x10));
+        assert_eq!(desc.mainid, None);
+        assert_eq!(desc.asvc, None);
+        assert!(desc.mix_info_exists);
+        assert_eq!(desc.substream1, Some(0x15));
+        assert_eq!(desc.substream2, None);
+        assert_eq!(desc.substream3, None);
+    }
+
+    #[test]
+    fn test_7a_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7A {
+            component_type: Some(0x42),
+            bsid: Some(0x10),
+            mix_info_exists: true,
+            substream1: Some(0x15),
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7A);
+    }
+}
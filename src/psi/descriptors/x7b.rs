@@ -0,0 +1,134 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 7;
+
+
+/// The DTS audio descriptor is used to identify streams which carry
+/// DTS audio.
+///
+/// EN 300 468 - G.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc7B {
+    /// Sampling frequency code
+    pub sample_rate_code: u8,
+    /// Transmission bit rate code
+    pub bit_rate_code: u8,
+    /// Number of PCM sample blocks in the frame
+    pub nblks: u8,
+    /// Byte size of the frame
+    pub fsize: u16,
+    /// Number of audio channels and the channel arrangement
+    pub surround_mode: u8,
+    /// Low frequency effects channel is present
+    pub lfe_flag: bool,
+    /// Extended surround: 0 - none, 1 - matrixed, 2 - discrete
+    pub extended_surround_flag: u8,
+    /// Additional information
+    pub additional_info: Vec<u8>,
+}
+
+
+impl Desc7B {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let value = slice[2 .. 7].iter().fold(0u64, |v, &b| (v << 8) | u64::from(b));
+
+        Self {
+            sample_rate_code: ((value >> 36) & 0x0F) as u8,
+            bit_rate_code: ((value >> 30) & 0x3F) as u8,
+            nblks: ((value >> 23) & 0x7F) as u8,
+            fsize: ((value >> 9) & 0x3FFF) as u16,
+            surround_mode: ((value >> 3) & 0x3F) as u8,
+            lfe_flag: ((value >> 2) & 0x01) != 0,
+            extended_surround_flag: (value & 0x03) as u8,
+            additional_info: Vec::from(&slice[7 ..]),
+        }
+    }
+}
+
+
+impl Desc for Desc7B {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x7B
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.additional_info.len()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7B);
+        buffer.push((self.size() - 2) as u8);
+
+        let value =
+            (u64::from(self.sample_rate_code & 0x0F) << 36) |
+            (u64::from(self.bit_rate_code & 0x3F) << 30) |
+            (u64::from(self.nblks & 0x7F) << 23) |
+            (u64::from(self.fsize & 0x3FFF) << 9) |
+            (u64::from(self.surround_mode & 0x3F) << 3) |
+            (u64::from(self.lfe_flag) << 2) |
+            u64::from(self.extended_surround_flag & 0x03);
+
+        buffer.extend_from_slice(&value.to_be_bytes()[3 ..]);
+        buffer.extend_from_slice(&self.additional_info);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc7B,
+    };
+
+    static DATA_7B: &[u8] = &[0x7b, 0x05, 0xd3, 0xc7, 0x8f, 0xb8, 0x4c];
+
+    #[test]
+    fn test_7b_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7B);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc7B>().unwrap();
+        assert_eq!(desc.sample_rate_code, 13);
+        assert_eq!(desc.bit_rate_code, 15);
+        assert_eq!(desc.nblks, 15);
+        assert_eq!(desc.fsize, 2012);
+        assert_eq!(desc.surround_mode, 9);
+        assert!(desc.lfe_flag);
+        assert_eq!(desc.extended_surround_flag, 0);
+    }
+
+    #[test]
+    fn test_7b_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7B {
+            sample_rate_code: 13,
+            bit_rate_code: 15,
+            nblks: 15,
+            fsize: 2012,
+            surround_mode: 9,
+            lfe_flag: true,
+            extended_surround_flag: 0,
+            additional_info: Vec::new(),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7B);
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// The AAC descriptor is used to identify streams which carry
+/// MPEG-4 AAC, HE AAC or HE AAC v2 audio.
+///
+/// EN 300 468 - H.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc7C {
+    /// Profile and level of the audio stream (ISO 14496-3)
+    pub profile_and_level: u8,
+    /// Stream contains SAOC-DE parametric data
+    pub saoc_de: bool,
+    /// Type of the audio service. Same as component_type for stream_content 0x06
+    pub aac_type: Option<u8>,
+    /// Additional information
+    pub additional_info: Vec<u8>,
+}
+
+
+impl Desc7C {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        if slice.len() == MIN_SIZE {
+            return true;
+        }
+
+        let aac_type_flag = (slice[3] & 0x80) != 0;
+        slice.len() >= MIN_SIZE + 1 + usize::from(aac_type_flag)
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            profile_and_level: slice[2],
+            .. Default::default()
+        };
+
+        if slice.len() > MIN_SIZE {
+            result.saoc_de = (slice[3] & 0x40) != 0;
+
+            let mut skip = 4;
+            if (slice[3] & 0x80) != 0 {
+                result.aac_type = Some(slice[4]);
+                skip += 1;
+            }
+
+            result.additional_info.extend_from_slice(&slice[skip ..]);
+        }
+
+        result
+    }
+
+    /// Flags byte is required if any of the optional fields defined
+    #[inline]
+    fn has_flags(&self) -> bool {
+        self.saoc_de || self.aac_type.is_some() || ! self.additional_info.is_empty()
+    }
+}
+
+
+impl Desc for Desc7C {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x7C
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        if self.has_flags() {
+            MIN_SIZE + 1 + usize::from(self.aac_type.is_some()) + self.additional_info.len()
+        } else {
+            MIN_SIZE
+        }
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7C);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(self.profile_and_level);
+
+        if self.has_flags() {
+            buffer.push(set_bits!(8,
+                u8::from(self.aac_type.is_some()), 1,
+                u8::from(self.saoc_de), 1,
+                0b0011_1111, 6));
+
+            if let Some(aac_type) = self.aac_type {
+                buffer.push(aac_type);
+            }
+
+            buffer.extend_from_slice(&self.additional_info);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Desc,
+        Descriptors,
+        Desc7C,
+    };
+
+    static DATA_7C_SHORT: &[u8] = &[0x7c, 0x01, 0x58];
+    static DATA_7C: &[u8] = &[0x7c, 0x03, 0x51, 0xbf, 0x43];
+
+    #[test]
+    fn test_7c_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7C_SHORT);
+        descriptors.parse(DATA_7C);
+
+        let desc = descriptors.get(0).unwrap().downcast_ref::<Desc7C>().unwrap();
+        assert_eq!(desc.size(), DATA_7C_SHORT.len());
+        assert_eq!(desc.profile_and_level, 0x58);
+        assert_eq!(desc.aac_type, None);
+
+        let desc = descriptors.get(1).unwrap().downcast_ref::<Desc7C>().unwrap();
+        assert_eq!(desc.size(), DATA_7C.len());
+        assert_eq!(desc.profile_and_level, 0x51);
+        assert!(! desc.saoc_de);
+        assert_eq!(desc.aac_type, Some(0x43));
+    }
+
+    #[test]
+    fn test_7c_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7C {
+            profile_and_level: 0x51,
+            aac_type: Some(0x43),
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7C);
+    }
+}
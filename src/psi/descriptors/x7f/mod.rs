@@ -0,0 +1,179 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use super::Desc;
+
+mod x00; pub use x00::*;
+mod x04; pub use x04::*;
+mod x06; pub use x06::*;
+mod x08; pub use x08::*;
+mod x09; pub use x09::*;
+mod x0d; pub use x0d::*;
+mod x15; pub use x15::*;
+mod x17; pub use x17::*;
+
+
+const MIN_SIZE: usize = 3;
+
+
+/// Extension descriptor with unsupported descriptor_tag_extension
+#[derive(Debug, Default, Clone)]
+pub struct Desc7FRaw {
+    /// Descriptor tag extension
+    pub tag_extension: u8,
+    /// Descriptor data after the tag extension
+    pub data: Vec<u8>,
+}
+
+
+impl Desc7FRaw {
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            tag_extension: slice[2],
+            data: Vec::from(&slice[3 ..]),
+        }
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        MIN_SIZE + self.data.len()
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(self.tag_extension);
+        buffer.extend_from_slice(&self.data);
+    }
+}
+
+
+/// The extension descriptor is used to extend the 8-bit namespace
+/// of the descriptor_tag field.
+/// Each variant parses and assembles the complete descriptor
+/// with descriptor_tag and descriptor_tag_extension.
+///
+/// EN 300 468 - 6.2.16
+#[derive(Debug, Clone)]
+pub enum Desc7F {
+    /// 0x00 - image icon descriptor
+    ImageIcon(Desc7F00),
+    /// 0x04 - T2 delivery system descriptor
+    T2Delivery(Desc7F04),
+    /// 0x06 - supplementary audio descriptor
+    SupplementaryAudio(Desc7F06),
+    /// 0x08 - message descriptor
+    Message(Desc7F08),
+    /// 0x09 - target region descriptor
+    TargetRegion(Desc7F09),
+    /// 0x0D - C2 delivery system descriptor
+    C2Delivery(Desc7F0D),
+    /// 0x15 - AC-4 descriptor
+    AC4(Desc7F15),
+    /// 0x17 - S2X satellite delivery system descriptor
+    S2XDelivery(Desc7F17),
+    /// Extension not supported in core or with invalid length
+    Raw(Desc7FRaw),
+}
+
+
+macro_rules! desc7f_impl {
+    ( $( $variant:ident ( $desc:ty, $ext:literal ) ),* $(,)? ) => {
+        impl Desc7F {
+            pub fn check(slice: &[u8]) -> bool {
+                slice.len() >= MIN_SIZE
+            }
+
+            pub fn parse(slice: &[u8]) -> Self {
+                match slice[2] {
+                    $( $ext if <$desc>::check(slice) => Desc7F::$variant(<$desc>::parse(slice)), )*
+                    _ => Desc7F::Raw(Desc7FRaw::parse(slice)),
+                }
+            }
+
+            /// Returns descriptor_tag_extension
+            pub fn tag_extension(&self) -> u8 {
+                match self {
+                    $( Desc7F::$variant(_) => $ext, )*
+                    Desc7F::Raw(v) => v.tag_extension,
+                }
+            }
+        }
+
+
+        impl Desc for Desc7F {
+            #[inline]
+            fn tag(&self) -> u8 {
+                0x7F
+            }
+
+            fn size(&self) -> usize {
+                match self {
+                    $( Desc7F::$variant(v) => v.size(), )*
+                    Desc7F::Raw(v) => v.size(),
+                }
+            }
+
+            fn assemble(&self, buffer: &mut Vec<u8>) {
+                match self {
+                    $( Desc7F::$variant(v) => v.assemble(buffer), )*
+                    Desc7F::Raw(v) => v.assemble(buffer),
+                }
+            }
+        }
+
+
+        $(
+            impl From<$desc> for Desc7F {
+                #[inline]
+                fn from(desc: $desc) -> Self {
+                    Desc7F::$variant(desc)
+                }
+            }
+        )*
+    };
+}
+
+
+desc7f_impl! {
+    ImageIcon(Desc7F00, 0x00),
+    T2Delivery(Desc7F04, 0x04),
+    SupplementaryAudio(Desc7F06, 0x06),
+    Message(Desc7F08, 0x08),
+    TargetRegion(Desc7F09, 0x09),
+    C2Delivery(Desc7F0D, 0x0D),
+    AC4(Desc7F15, 0x15),
+    S2XDelivery(Desc7F17, 0x17),
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc7F,
+    };
+
+    static DATA_7F_RAW: &[u8] = &[0x7f, 0x03, 0x20, 0x01, 0x02];
+
+    #[test]
+    fn test_7f_raw() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F_RAW);
+
+        let desc = descriptors.iter().next().unwrap().downcast_ref::<Desc7F>().unwrap();
+        assert_eq!(desc.tag_extension(), 0x20);
+        match desc {
+            Desc7F::Raw(v) => assert_eq!(v.data.as_slice(), &[0x01, 0x02]),
+            _ => unreachable!(),
+        }
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+        assert_eq!(assembled.as_slice(), DATA_7F_RAW);
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+
+
+const MIN_SIZE: usize = 5;
+
+
+/// Icon data is carried in the descriptor
+pub const ICON_TRANSPORT_LOCAL: u8 = 0x00;
+/// Icon is referenced by the URL
+pub const ICON_TRANSPORT_URL: u8 = 0x01;
+
+
+/// Position of the icon on the screen
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F00p {
+    /// Coordinate system: 0 - 720x576, 1 - 1280x720, 2 - 1920x1080
+    pub coordinate_system: u8,
+    /// Horizontal position of the top left corner of the icon
+    pub horizontal_origin: u16,
+    /// Vertical position of the top left corner of the icon
+    pub vertical_origin: u16,
+}
+
+
+/// Fields defined only in the first descriptor of the icon
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F00h {
+    /// Icon delivery mode: `ICON_TRANSPORT_LOCAL` or `ICON_TRANSPORT_URL`
+    pub transport_mode: u8,
+    /// Icon position on the screen
+    pub position: Option<Desc7F00p>,
+    /// MIME type of the icon
+    pub icon_type: Vec<u8>,
+}
+
+
+/// The image icon descriptor carries inline icon data or the URL
+/// to the icon. Icon data may be split into several descriptors.
+///
+/// EN 300 468 - 6.4.8
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F00 {
+    /// Part number of the icon
+    pub descriptor_number: u8,
+    /// Number of the last part of the icon
+    pub last_descriptor_number: u8,
+    /// Identifies the icon
+    pub icon_id: u8,
+    /// Icon properties. Defined if descriptor_number is 0
+    pub header: Option<Desc7F00h>,
+    /// Icon data or URL for the `ICON_TRANSPORT_URL` mode
+    pub data: Vec<u8>,
+}
+
+
+impl Desc7F00 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut skip = MIN_SIZE;
+        if (slice[3] >> 4) == 0 {
+            if slice.len() <= skip {
+                return false;
+            }
+
+            let transport_mode = slice[skip] >> 6;
+            let position_flag = (slice[skip] & 0x20) != 0;
+            skip += 1;
+            if position_flag {
+                skip += 3;
+            }
+
+            if slice.len() <= skip {
+                return false;
+            }
+            skip += 1 + usize::from(slice[skip]);
+
+            if transport_mode > ICON_TRANSPORT_URL {
+                return skip == slice.len();
+            }
+        }
+
+        if slice.len() <= skip {
+            return false;
+        }
+        skip += 1 + usize::from(slice[skip]);
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            descriptor_number: slice[3] >> 4,
+            last_descriptor_number: slice[3] & 0x0F,
+            icon_id: slice[4] & 0x07,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        if result.descriptor_number == 0 {
+            let mut header = Desc7F00h {
+                transport_mode: slice[skip] >> 6,
+                .. Default::default()
+            };
+
+            if (slice[skip] & 0x20) != 0 {
+                header.position = Some(Desc7F00p {
+                    coordinate_system: (slice[skip] >> 2) & 0x07,
+                    horizontal_origin: (slice[skip + 1 ..].get_u24() >> 12) as u16,
+                    vertical_origin: (slice[skip + 1 ..].get_u24() & 0x0FFF) as u16,
+                });
+                skip += 3;
+            }
+            skip += 1;
+
+            let icon_type_length = usize::from(slice[skip]);
+            skip += 1;
+            header.icon_type.extend_from_slice(&slice[skip .. skip + icon_type_length]);
+            skip += icon_type_length;
+
+            let transport_mode = header.transport_mode;
+            result.header = Some(header);
+
+            if transport_mode > ICON_TRANSPORT_URL {
+                return result;
+            }
+        }
+
+        let data_length = usize::from(slice[skip]);
+        skip += 1;
+        result.data.extend_from_slice(&slice[skip .. skip + data_length]);
+
+        result
+    }
+
+    pub fn size(&self) -> usize {
+        let mut size = MIN_SIZE;
+
+        if let Some(header) = &self.header {
+            size += 1 + 1 + header.icon_type.len();
+            if header.position.is_some() {
+                size += 3;
+            }
+            if header.transport_mode > ICON_TRANSPORT_URL {
+                return size;
+            }
+        }
+
+        size + 1 + self.data.len()
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0x00);
+        buffer.push(set_bits!(8,
+            self.descriptor_number, 4,
+            self.last_descriptor_number, 4));
+        buffer.push(set_bits!(8,
+            0x1F, 5,
+            self.icon_id, 3));
+
+        if let Some(header) = &self.header {
+            match &header.position {
+                Some(position) => {
+                    buffer.push(set_bits!(8,
+                        header.transport_mode, 2,
+                        1, 1,
+                        position.coordinate_system, 3,
+                        0x03, 2));
+
+                    let skip = buffer.len();
+                    buffer.resize(skip + 3, 0x00);
+                    buffer[skip ..].set_u24(
+                        (u32::from(position.horizontal_origin & 0x0FFF) << 12) |
+                        u32::from(position.vertical_origin & 0x0FFF));
+                }
+                None => {
+                    buffer.push(set_bits!(8,
+                        header.transport_mode, 2,
+                        0, 1,
+                        0x1F, 5));
+                }
+            }
+
+            buffer.push(header.icon_type.len() as u8);
+            buffer.extend_from_slice(&header.icon_type);
+
+            if header.transport_mode > ICON_TRANSPORT_URL {
+                return;
+            }
+        }
+
+        buffer.push(self.data.len() as u8);
+        buffer.extend_from_slice(&self.data);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc7F,
+        Desc7F00,
+        Desc7F00h,
+        Desc7F00p,
+        ICON_TRANSPORT_URL,
+    };
+
+    static DATA_7F00: &[u8] = &[
+        0x7f, 0x15, 0x00, 0x00, 0xf9, 0x67, 0x0a, 0x00, 0x14, 0x09, 0x69, 0x6d, 0x61, 0x67,
+        0x65, 0x2f, 0x70, 0x6e, 0x67, 0x03, 0x61, 0x2f, 0x62,
+    ];
+
+    #[test]
+    fn test_7f00_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F00);
+
+        let desc = match descriptors.find::<Desc7F>().unwrap() {
+            Desc7F::ImageIcon(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.descriptor_number, 0);
+        assert_eq!(desc.last_descriptor_number, 0);
+        assert_eq!(desc.icon_id, 1);
+
+        let header = desc.header.as_ref().unwrap();
+        assert_eq!(header.transport_mode, ICON_TRANSPORT_URL);
+        assert_eq!(header.icon_type.as_slice(), b"image/png");
+
+        let position = header.position.as_ref().unwrap();
+        assert_eq!(position.coordinate_system, 1);
+        assert_eq!(position.horizontal_origin, 160);
+        assert_eq!(position.vertical_origin, 20);
+
+        assert_eq!(desc.data.as_slice(), b"a/b");
+    }
+
+    #[test]
+    fn test_7f00_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F00 {
+            descriptor_number: 0,
+            last_descriptor_number: 0,
+            icon_id: 1,
+            header: Some(Desc7F00h {
+                transport_mode: ICON_TRANSPORT_URL,
+                position: Some(Desc7F00p {
+                    coordinate_system: 1,
+                    horizontal_origin: 160,
+                    vertical_origin: 20,
+                }),
+                icon_type: b"image/png".to_vec(),
+            }),
+            data: b"a/b".to_vec(),
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F00);
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+
+
+const MIN_SIZE: usize = 6;
+
+
+/// Transposer of the cell
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F04s {
+    /// Identifies a sub-cell within a cell
+    pub cell_id_extension: u8,
+    /// Centre frequency of the transposer in Hz
+    pub transposer_frequency: u32,
+}
+
+
+/// Cell of the T2 system
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F04c {
+    /// Identifies a cell
+    pub cell_id: u16,
+    /// Centre frequencies in Hz.
+    /// Contains single frequency if tfs_flag is not set
+    pub frequencies: Vec<u32>,
+    /// List of the transposers
+    pub subcells: Vec<Desc7F04s>,
+}
+
+
+/// Optional part of the T2 delivery system descriptor
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F04p {
+    /// SISO/MISO mode: 0 - SISO, 1 - MISO
+    pub siso_miso: u8,
+    /// Used bandwidth
+    pub bandwidth: u8,
+    /// Guard interval value
+    pub guard_interval: u8,
+    /// FFT size of the OFDM symbols
+    pub transmission_mode: u8,
+    /// Indicates whether other frequencies are in use
+    pub other_frequency_flag: u8,
+    /// Indicates whether TFS (Time-Frequency Slicing) is in use
+    pub tfs_flag: u8,
+    /// List of the cells
+    pub cells: Vec<Desc7F04c>,
+}
+
+
+impl Desc7F04p {
+    fn size(&self) -> usize {
+        let mut size = 2;
+        for cell in &self.cells {
+            size += 2;
+            if self.tfs_flag != 0 {
+                size += 1;
+            }
+            size += cell.frequencies.len() * 4;
+            size += 1 + cell.subcells.len() * 5;
+        }
+        size
+    }
+}
+
+
+/// The T2 delivery system descriptor is used to signal DVB-T2 transmission
+/// parameters.
+///
+/// EN 300 468 - 6.4.6.3
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F04 {
+    /// Identifies the data PLP
+    pub plp_id: u8,
+    /// Identifies the T2 system
+    pub t2_system_id: u16,
+    /// Transmission parameters and cells
+    pub params: Option<Desc7F04p>,
+}
+
+
+impl Desc7F04 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        if slice.len() == MIN_SIZE {
+            return true;
+        }
+
+        if slice.len() < MIN_SIZE + 2 {
+            return false;
+        }
+
+        let tfs_flag = (slice[7] & 0x01) != 0;
+        let mut skip = MIN_SIZE + 2;
+        while slice.len() > skip {
+            skip += 2;
+            if tfs_flag {
+                if slice.len() <= skip || (usize::from(slice[skip]) % 4) != 0 {
+                    return false;
+                }
+                skip += 1 + usize::from(slice[skip]);
+            } else {
+                skip += 4;
+            }
+
+            if slice.len() <= skip || (usize::from(slice[skip]) % 5) != 0 {
+                return false;
+            }
+            skip += 1 + usize::from(slice[skip]);
+        }
+
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            plp_id: slice[3],
+            t2_system_id: slice[4 ..].get_u16(),
+            params: None,
+        };
+
+        if slice.len() == MIN_SIZE {
+            return result;
+        }
+
+        let mut params = Desc7F04p {
+            siso_miso: slice[6] >> 6,
+            bandwidth: (slice[6] >> 2) & 0x0F,
+            guard_interval: slice[7] >> 5,
+            transmission_mode: (slice[7] >> 2) & 0x07,
+            other_frequency_flag: (slice[7] >> 1) & 0x01,
+            tfs_flag: slice[7] & 0x01,
+            cells: Vec::new(),
+        };
+
+        let mut skip = MIN_SIZE + 2;
+        while slice.len() > skip {
+            let mut cell = Desc7F04c {
+                cell_id: slice[skip ..].get_u16(),
+                .. Default::default()
+            };
+            skip += 2;
+
+            let frequency_loop_length = if params.tfs_flag != 0 {
+                skip += 1;
+                usize::from(slice[skip - 1])
+            } else {
+                4
+            };
+            let frequency_loop_end = skip + frequency_loop_length;
+            while frequency_loop_end > skip {
+                cell.frequencies.push(slice[skip ..].get_u32() * 10);
+                skip += 4;
+            }
+
+            let subcell_loop_end = skip + 1 + usize::from(slice[skip]);
+            skip += 1;
+            while subcell_loop_end > skip {
+                cell.subcells.push(Desc7F04s {
+                    cell_id_extension: slice[skip],
+                    transposer_frequency: slice[skip + 1 ..].get_u32() * 10,
+                });
+                skip += 5;
+            }
+
+            params.cells.push(cell);
+        }
+
+        result.params = Some(params);
+        result
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        MIN_SIZE + self.params.as_ref().map_or(0, Desc7F04p::size)
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x7F;
+        buffer[skip + 1] = (self.size() - 2) as u8;
+        buffer[skip + 2] = 0x04;
+        buffer[skip + 3] = self.plp_id;
+        buffer[skip + 4 ..].set_u16(self.t2_system_id);
+
+        let params = match &self.params {
+            Some(v) => v,
+            None => return,
+        };
+
+        buffer.push(set_bits!(8,
+            params.siso_miso, 2,
+            params.bandwidth, 4,
+            0x03, 2));
+        buffer.push(set_bits!(8,
+            params.guard_interval, 3,
+            params.transmission_mode, 3,
+            params.other_frequency_flag, 1,
+            params.tfs_flag, 1));
+
+        for cell in &params.cells {
+            let skip = buffer.len();
+            buffer.resize(skip + 2, 0x00);
+            buffer[skip ..].set_u16(cell.cell_id);
+
+            if params.tfs_flag != 0 {
+                buffer.push((cell.frequencies.len() * 4) as u8);
+            }
+            for frequency in &cell.frequencies {
+                let skip = buffer.len();
+                buffer.resize(skip + 4, 0x00);
+                buffer[skip ..].set_u32(*frequency / 10);
+            }
+
+            buffer.push((cell.subcells.len() * 5) as u8);
+            for subcell in &cell.subcells {
+                let skip = buffer.len();
+                buffer.resize(skip + 5, 0x00);
+                buffer[skip] = subcell.cell_id_extension;
+                buffer[skip + 1 ..].set_u32(subcell.transposer_frequency / 10);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Desc,
+        Descriptors,
+        Desc7F,
+        Desc7F04,
+        Desc7F04p,
+        Desc7F04c,
+        Desc7F04s,
+    };
+
+    static DATA_7F04_SHORT: &[u8] = &[0x7f, 0x04, 0x04, 0x00, 0x20, 0x85];
+    static DATA_7F04: &[u8] = &[
+        0x7f, 0x13, 0x04, 0x00, 0x20, 0x85, 0x03, 0x79, 0x00, 0x01, 0x04, 0x04, 0xc7, 0xc1,
+        0x40, 0x05, 0x01, 0x04, 0xd3, 0xf6, 0x40,
+    ];
+
+    #[test]
+    fn test_7f04_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F04_SHORT);
+        descriptors.parse(DATA_7F04);
+
+        let desc = descriptors.get(0).unwrap().downcast_ref::<Desc7F>().unwrap();
+        assert_eq!(desc.size(), DATA_7F04_SHORT.len());
+        let desc = match desc {
+            Desc7F::T2Delivery(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.plp_id, 0);
+        assert_eq!(desc.t2_system_id, 0x2085);
+        assert!(desc.params.is_none());
+
+        let desc = descriptors.get(1).unwrap().downcast_ref::<Desc7F>().unwrap();
+        assert_eq!(desc.size(), DATA_7F04.len());
+        let desc = match desc {
+            Desc7F::T2Delivery(v) => v,
+            _ => unreachable!(),
+        };
+        let params = desc.params.as_ref().unwrap();
+        assert_eq!(params.siso_miso, 0);
+        assert_eq!(params.bandwidth, 0);
+        assert_eq!(params.guard_interval, 3);
+        assert_eq!(params.transmission_mode, 6);
+        assert_eq!(params.other_frequency_flag, 0);
+        assert_eq!(params.tfs_flag, 1);
+        assert_eq!(params.cells.len(), 1);
+
+        let cell = &params.cells[0];
+        assert_eq!(cell.cell_id, 1);
+        assert_eq!(cell.frequencies, vec![802000000]);
+        assert_eq!(cell.subcells.len(), 1);
+        assert_eq!(cell.subcells[0].cell_id_extension, 1);
+        assert_eq!(cell.subcells[0].transposer_frequency, 810000000);
+    }
+
+    #[test]
+    fn test_7f04_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F04 {
+            plp_id: 0,
+            t2_system_id: 0x2085,
+            params: Some(Desc7F04p {
+                siso_miso: 0,
+                bandwidth: 0,
+                guard_interval: 3,
+                transmission_mode: 6,
+                other_frequency_flag: 0,
+                tfs_flag: 1,
+                cells: vec![
+                    Desc7F04c {
+                        cell_id: 1,
+                        frequencies: vec![802000000],
+                        subcells: vec![
+                            Desc7F04s {
+                                cell_id_extension: 1,
+                                transposer_frequency: 810000000,
+                            },
+                        ],
+                    },
+                ],
+            }),
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F04);
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+
+
+const MIN_SIZE: usize = 4;
+
+
+/// The supplementary audio descriptor provides additional information
+/// about the audio stream: audio description, clean audio, spoken subtitles.
+///
+/// EN 300 468 - 6.4.11
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F06 {
+    /// Mix type: 0 - supplementary stream, 1 - complete and independent stream
+    pub mix_type: u8,
+    /// Editorial classification of the audio stream
+    pub editorial_classification: u8,
+    /// ISO 639 language code of the audio stream
+    pub lang: Option<StringDVB>,
+    /// Private data
+    pub private_data: Vec<u8>,
+}
+
+
+impl Desc7F06 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let language_code_present = (slice[3] & 0x01) != 0;
+        ! language_code_present || slice.len() >= MIN_SIZE + 3
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            mix_type: slice[3] >> 7,
+            editorial_classification: (slice[3] >> 2) & 0x1F,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        if (slice[3] & 0x01) != 0 {
+            result.lang = Some(StringDVB::from(&slice[skip .. skip + 3]));
+            skip += 3;
+        }
+
+        result.private_data.extend_from_slice(&slice[skip ..]);
+        result
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        MIN_SIZE +
+            self.lang.as_ref().map_or(0, |_| 3) +
+            self.private_data.len()
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0x06);
+        buffer.push(set_bits!(8,
+            self.mix_type, 1,
+            self.editorial_classification, 5,
+            1, 1,
+            u8::from(self.lang.is_some()), 1));
+
+        if let Some(lang) = &self.lang {
+            lang.assemble(buffer);
+        }
+
+        buffer.extend_from_slice(&self.private_data);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc7F,
+            Desc7F06,
+        },
+    };
+
+    static DATA_7F06: &[u8] = &[0x7f, 0x05, 0x06, 0x87, 0x65, 0x6e, 0x67];
+
+    #[test]
+    fn test_7f06_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F06);
+
+        let desc = match descriptors.find::<Desc7F>().unwrap() {
+            Desc7F::SupplementaryAudio(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.mix_type, 1);
+        assert_eq!(desc.editorial_classification, 0x01);
+        assert_eq!(desc.lang, Some(textcode::StringDVB::from_str("eng", textcode::ISO6937)));
+        assert!(desc.private_data.is_empty());
+    }
+
+    #[test]
+    fn test_7f06_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F06 {
+            mix_type: 1,
+            editorial_classification: 0x01,
+            lang: Some(textcode::StringDVB::from_str("eng", textcode::ISO6937)),
+            private_data: Vec::new(),
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F06);
+    }
+}
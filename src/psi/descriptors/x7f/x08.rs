@@ -0,0 +1,102 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+
+
+const MIN_SIZE: usize = 7;
+
+
+/// The message descriptor is used to carry a text message
+/// to be displayed by the receiver.
+///
+/// EN 300 468 - 6.4.7
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F08 {
+    /// Identifies the message
+    pub message_id: u8,
+    /// ISO 639 language code of the message
+    pub lang: StringDVB,
+    /// Message text
+    pub text: StringDVB,
+}
+
+
+impl Desc7F08 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            message_id: slice[3],
+            lang: StringDVB::from(&slice[4 .. 7]),
+            text: StringDVB::from(&slice[7 ..]),
+        }
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        MIN_SIZE + self.text.size()
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0x08);
+        buffer.push(self.message_id);
+
+        self.lang.assemble(buffer);
+        self.text.assemble(buffer);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc7F,
+            Desc7F08,
+        },
+    };
+
+    static DATA_7F08: &[u8] = &[
+        0x7f, 0x0a, 0x08, 0x01, 0x65, 0x6e, 0x67, 0x48, 0x65, 0x6c, 0x6c, 0x6f,
+    ];
+
+    #[test]
+    fn test_7f08_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F08);
+
+        let desc = match descriptors.find::<Desc7F>().unwrap() {
+            Desc7F::Message(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.message_id, 1);
+        assert_eq!(desc.lang, textcode::StringDVB::from_str("eng", textcode::ISO6937));
+        assert_eq!(desc.text, textcode::StringDVB::from_str("Hello", textcode::ISO6937));
+    }
+
+    #[test]
+    fn test_7f08_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F08 {
+            message_id: 1,
+            lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+            text: textcode::StringDVB::from_str("Hello", textcode::ISO6937),
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F08);
+    }
+}
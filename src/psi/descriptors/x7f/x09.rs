@@ -0,0 +1,223 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    textcode::StringDVB,
+};
+
+
+const MIN_SIZE: usize = 6;
+
+
+/// Target region
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F09i {
+    /// ISO 3166 country code if differs from the descriptor country code
+    pub country_code: Option<StringDVB>,
+    /// Number of the region codes: 0 - country, 1 - primary, 2 - secondary, 3 - tertiary
+    pub region_depth: u8,
+    /// Primary region code
+    pub primary_region_code: u8,
+    /// Secondary region code
+    pub secondary_region_code: u8,
+    /// Tertiary region code
+    pub tertiary_region_code: u16,
+}
+
+
+impl Desc7F09i {
+    fn size(&self) -> usize {
+        1 +
+            self.country_code.as_ref().map_or(0, |_| 3) +
+            match self.region_depth {
+                0 => 0,
+                1 => 1,
+                2 => 2,
+                _ => 4,
+            }
+    }
+}
+
+
+/// The target region descriptor identifies a set of target regions.
+///
+/// EN 300 468 - 6.4.12
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F09 {
+    /// ISO 3166 country code
+    pub country_code: StringDVB,
+    /// List of the target regions
+    pub items: Vec<Desc7F09i>,
+}
+
+
+impl Desc7F09 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let flags = slice[skip];
+            skip += 1;
+            if (flags & 0x04) != 0 {
+                skip += 3;
+            }
+            skip += match flags & 0x03 {
+                0 => 0,
+                1 => 1,
+                2 => 2,
+                _ => 4,
+            };
+        }
+
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            country_code: StringDVB::from(&slice[3 .. 6]),
+            items: Vec::new(),
+        };
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let mut item = Desc7F09i {
+                region_depth: slice[skip] & 0x03,
+                .. Default::default()
+            };
+            let country_code_flag = (slice[skip] & 0x04) != 0;
+            skip += 1;
+
+            if country_code_flag {
+                item.country_code = Some(StringDVB::from(&slice[skip .. skip + 3]));
+                skip += 3;
+            }
+            if item.region_depth >= 1 {
+                item.primary_region_code = slice[skip];
+                skip += 1;
+            }
+            if item.region_depth >= 2 {
+                item.secondary_region_code = slice[skip];
+                skip += 1;
+            }
+            if item.region_depth == 3 {
+                item.tertiary_region_code = slice[skip ..].get_u16();
+                skip += 2;
+            }
+
+            result.items.push(item);
+        }
+
+        result
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| acc + item.size())
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0x09);
+        self.country_code.assemble(buffer);
+
+        for item in &self.items {
+            buffer.push(set_bits!(8,
+                0x1F, 5,
+                u8::from(item.country_code.is_some()), 1,
+                item.region_depth, 2));
+
+            if let Some(country_code) = &item.country_code {
+                country_code.assemble(buffer);
+            }
+            if item.region_depth >= 1 {
+                buffer.push(item.primary_region_code);
+            }
+            if item.region_depth >= 2 {
+                buffer.push(item.secondary_region_code);
+            }
+            if item.region_depth >= 3 {
+                let skip = buffer.len();
+                buffer.resize(skip + 2, 0x00);
+                buffer[skip ..].set_u16(item.tertiary_region_code);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc7F,
+            Desc7F09,
+            Desc7F09i,
+        },
+    };
+
+    static DATA_7F09: &[u8] = &[
+        0x7f, 0x0b, 0x09, 0x47, 0x42, 0x52, 0xf9, 0x01, 0xfb, 0x03, 0x02, 0x00, 0x10,
+    ];
+
+    #[test]
+    fn test_7f09_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F09);
+
+        let desc = match descriptors.find::<Desc7F>().unwrap() {
+            Desc7F::TargetRegion(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.country_code, textcode::StringDVB::from_str("GBR", textcode::ISO6937));
+        assert_eq!(desc.items.len(), 2);
+
+        let item = &desc.items[0];
+        assert!(item.country_code.is_none());
+        assert_eq!(item.region_depth, 1);
+        assert_eq!(item.primary_region_code, 1);
+
+        let item = &desc.items[1];
+        assert_eq!(item.region_depth, 3);
+        assert_eq!(item.primary_region_code, 3);
+        assert_eq!(item.secondary_region_code, 2);
+        assert_eq!(item.tertiary_region_code, 0x0010);
+    }
+
+    #[test]
+    fn test_7f09_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F09 {
+            country_code: textcode::StringDVB::from_str("GBR", textcode::ISO6937),
+            items: vec![
+                Desc7F09i {
+                    region_depth: 1,
+                    primary_region_code: 1,
+                    .. Default::default()
+                },
+                Desc7F09i {
+                    region_depth: 3,
+                    primary_region_code: 3,
+                    secondary_region_code: 2,
+                    tertiary_region_code: 0x0010,
+                    .. Default::default()
+                },
+            ],
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F09);
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+
+
+const MIN_SIZE: usize = 10;
+
+
+/// The C2 delivery system descriptor is used to signal DVB-C2 transmission
+/// parameters.
+///
+/// EN 300 468 - 6.4.6.1
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F0D {
+    /// Identifies the data PLP
+    pub plp_id: u8,
+    /// Identifies the data slice
+    pub data_slice_id: u8,
+    /// Tuning frequency in Hz
+    pub frequency: u32,
+    /// Type of the tuning frequency: 0 - data slice, 1 - C2 system center, ...
+    pub frequency_type: u8,
+    /// Duration of the active OFDM symbol
+    pub active_ofdm_symbol_duration: u8,
+    /// Guard interval value
+    pub guard_interval: u8,
+}
+
+
+impl Desc7F0D {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() == MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            plp_id: slice[3],
+            data_slice_id: slice[4],
+            frequency: slice[5 ..].get_u32(),
+            frequency_type: slice[9] >> 6,
+            active_ofdm_symbol_duration: (slice[9] >> 3) & 0x07,
+            guard_interval: slice[9] & 0x07,
+        }
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        MIN_SIZE
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + MIN_SIZE, 0x00);
+        buffer[skip] = 0x7F;
+        buffer[skip + 1] = (MIN_SIZE - 2) as u8;
+        buffer[skip + 2] = 0x0D;
+        buffer[skip + 3] = self.plp_id;
+        buffer[skip + 4] = self.data_slice_id;
+        buffer[skip + 5 ..].set_u32(self.frequency);
+        buffer[skip + 9] = set_bits!(8,
+            self.frequency_type, 2,
+            self.active_ofdm_symbol_duration, 3,
+            self.guard_interval, 3);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc7F,
+        Desc7F0D,
+    };
+
+    static DATA_7F0D: &[u8] = &[0x7f, 0x08, 0x0d, 0x01, 0x02, 0x19, 0xb0, 0x89, 0xc0, 0x41];
+
+    #[test]
+    fn test_7f0d_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F0D);
+
+        let desc = match descriptors.find::<Desc7F>().unwrap() {
+            Desc7F::C2Delivery(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.plp_id, 1);
+        assert_eq!(desc.data_slice_id, 2);
+        assert_eq!(desc.frequency, 431000000);
+        assert_eq!(desc.frequency_type, 1);
+        assert_eq!(desc.active_ofdm_symbol_duration, 0);
+        assert_eq!(desc.guard_interval, 1);
+    }
+
+    #[test]
+    fn test_7f0d_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F0D {
+            plp_id: 1,
+            data_slice_id: 2,
+            frequency: 431000000,
+            frequency_type: 1,
+            active_ofdm_symbol_duration: 0,
+            guard_interval: 1,
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F0D);
+    }
+}
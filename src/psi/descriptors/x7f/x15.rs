@@ -0,0 +1,157 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+
+const MIN_SIZE: usize = 4;
+
+
+/// AC-4 configuration
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F15c {
+    /// Dialogue enhancement is enabled
+    pub dialog_enhancement_enabled: bool,
+    /// Channel mode: 0 - mono, 1 - stereo, 2 - multichannel
+    pub channel_mode: u8,
+}
+
+
+/// The AC-4 descriptor is used to identify streams which carry
+/// AC-4 audio.
+///
+/// EN 300 468 - D.7
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F15 {
+    /// AC-4 configuration
+    pub config: Option<Desc7F15c>,
+    /// AC-4 table of contents (ac4_dsi_byte)
+    pub toc: Option<Vec<u8>>,
+    /// Additional information
+    pub additional_info: Vec<u8>,
+}
+
+
+impl Desc7F15 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut skip = MIN_SIZE;
+        if (slice[3] & 0x80) != 0 {
+            skip += 1;
+        }
+        if (slice[3] & 0x40) != 0 {
+            if slice.len() <= skip {
+                return false;
+            }
+            skip += 1 + usize::from(slice[skip]);
+        }
+
+        skip <= slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        if (slice[3] & 0x80) != 0 {
+            result.config = Some(Desc7F15c {
+                dialog_enhancement_enabled: (slice[skip] & 0x80) != 0,
+                channel_mode: (slice[skip] >> 5) & 0x03,
+            });
+            skip += 1;
+        }
+        if (slice[3] & 0x40) != 0 {
+            let toc_len = usize::from(slice[skip]);
+            skip += 1;
+            result.toc = Some(Vec::from(&slice[skip .. skip + toc_len]));
+            skip += toc_len;
+        }
+
+        result.additional_info.extend_from_slice(&slice[skip ..]);
+        result
+    }
+
+    #[inline]
+    pub fn size(&self) -> usize {
+        MIN_SIZE +
+            self.config.as_ref().map_or(0, |_| 1) +
+            self.toc.as_ref().map_or(0, |v| 1 + v.len()) +
+            self.additional_info.len()
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0x15);
+        buffer.push(set_bits!(8,
+            u8::from(self.config.is_some()), 1,
+            u8::from(self.toc.is_some()), 1,
+            0x3F, 6));
+
+        if let Some(config) = &self.config {
+            buffer.push(set_bits!(8,
+                u8::from(config.dialog_enhancement_enabled), 1,
+                config.channel_mode, 2,
+                0x1F, 5));
+        }
+
+        if let Some(toc) = &self.toc {
+            buffer.push(toc.len() as u8);
+            buffer.extend_from_slice(toc);
+        }
+
+        buffer.extend_from_slice(&self.additional_info);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc7F,
+        Desc7F15,
+        Desc7F15c,
+    };
+
+    static DATA_7F15: &[u8] = &[0x7f, 0x06, 0x15, 0xff, 0xbf, 0x02, 0x20, 0x00];
+
+    #[test]
+    fn test_7f15_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F15);
+
+        let desc = match descriptors.find::<Desc7F>().unwrap() {
+            Desc7F::AC4(v) => v,
+            _ => unreachable!(),
+        };
+        let config = desc.config.as_ref().unwrap();
+        assert!(config.dialog_enhancement_enabled);
+        assert_eq!(config.channel_mode, 1);
+        assert_eq!(desc.toc.as_ref().unwrap().as_slice(), &[0x20, 0x00]);
+        assert!(desc.additional_info.is_empty());
+    }
+
+    #[test]
+    fn test_7f15_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F15 {
+            config: Some(Desc7F15c {
+                dialog_enhancement_enabled: true,
+                channel_mode: 1,
+            }),
+            toc: Some(vec![0x20, 0x00]),
+            additional_info: Vec::new(),
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F15);
+    }
+}
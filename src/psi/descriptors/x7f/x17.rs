@@ -0,0 +1,307 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    psi::BCD,
+};
+
+
+const MIN_SIZE: usize = 16;
+
+/// Size of the channel parameters
+const CHANNEL_SIZE: usize = 11;
+
+
+/// S2X mode: normal mode
+pub const S2X_MODE_NORMAL: u8 = 1;
+/// S2X mode: time-slicing
+pub const S2X_MODE_TIME_SLICING: u8 = 2;
+/// S2X mode: channel bonding
+pub const S2X_MODE_CHANNEL_BONDING: u8 = 3;
+
+
+/// Satellite channel parameters
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F17c {
+    /// Frequency in KHz
+    pub frequency: u32,
+    /// Position in minutes of angle
+    pub orbital_position: u16,
+    /// Satellite position in the western or eastern part of the orbit
+    pub west_east_flag: u8,
+    /// Polarization of the transmitted signal
+    pub polarization: u8,
+    /// Roll-off factor
+    pub rof: u8,
+    /// Symbol rate in Ksymbol/s
+    pub symbol_rate: u32,
+}
+
+
+impl Desc7F17c {
+    /// Parses channel parameters.
+    /// Returns channel and multiple_input_stream_flag
+    fn parse(slice: &[u8]) -> (Self, bool) {
+        let channel = Self {
+            frequency: slice.get_u32().from_bcd() * 10,
+            orbital_position: slice[4 ..].get_u16().from_bcd() * 6,
+            west_east_flag: slice[6] >> 7,
+            polarization: (slice[6] >> 5) & 0x03,
+            rof: slice[6] & 0x07,
+            symbol_rate: (slice[7 ..].get_u32() & 0x0FFF_FFFF).from_bcd() / 10,
+        };
+        (channel, (slice[6] & 0x10) != 0)
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>, multiple_input_stream_flag: bool) {
+        let skip = buffer.len();
+        buffer.resize(skip + CHANNEL_SIZE, 0x00);
+        buffer[skip ..].set_u32((self.frequency / 10).to_bcd());
+        buffer[skip + 4 ..].set_u16((self.orbital_position / 6).to_bcd());
+        buffer[skip + 6] = set_bits!(8,
+            self.west_east_flag, 1,
+            self.polarization, 2,
+            u8::from(multiple_input_stream_flag), 1,
+            0, 1,
+            self.rof, 3);
+        buffer[skip + 7 ..].set_u32(0xF000_0000 | (self.symbol_rate * 10).to_bcd());
+    }
+}
+
+
+/// The S2X satellite delivery system descriptor is used to signal
+/// DVB-S2X transmission parameters.
+///
+/// EN 300 468 - 6.4.6.5
+#[derive(Debug, Default, Clone)]
+pub struct Desc7F17 {
+    /// Supported receiver profiles
+    pub receiver_profiles: u8,
+    /// S2X mode. `S2X_MODE_NORMAL`, `S2X_MODE_TIME_SLICING`
+    /// or `S2X_MODE_CHANNEL_BONDING`
+    pub s2x_mode: u8,
+    /// Transport stream type: 3 - MPEG-2 TS, other - GS
+    pub ts_gs_s2x_mode: u8,
+    /// Physical layer scrambling sequence index
+    pub scrambling_sequence_index: Option<u32>,
+    /// Channel parameters
+    pub channel: Desc7F17c,
+    /// Input stream identifier for multiple input stream
+    pub input_stream_identifier: Option<u8>,
+    /// Time slice number for the `S2X_MODE_TIME_SLICING`
+    pub timeslice_number: u8,
+    /// Bonded channels for the `S2X_MODE_CHANNEL_BONDING`
+    pub bonds: Vec<Desc7F17c>,
+    /// Reserved for future use
+    pub reserved: Vec<u8>,
+}
+
+
+impl Desc7F17 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let s2x_mode = slice[4] >> 6;
+        let mut skip = 5;
+        if (slice[4] & 0x20) != 0 {
+            skip += 3;
+        }
+        if slice.len() < skip + CHANNEL_SIZE {
+            return false;
+        }
+        if (slice[skip + 6] & 0x10) != 0 {
+            skip += 1;
+        }
+        skip += CHANNEL_SIZE;
+
+        match s2x_mode {
+            S2X_MODE_TIME_SLICING => skip += 1,
+            S2X_MODE_CHANNEL_BONDING => {
+                if slice.len() <= skip {
+                    return false;
+                }
+                skip += 1 + (usize::from(slice[skip] & 0x01) + 1) * CHANNEL_SIZE;
+            }
+            _ => {}
+        }
+
+        skip <= slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            receiver_profiles: slice[3] >> 3,
+            s2x_mode: slice[4] >> 6,
+            ts_gs_s2x_mode: slice[4] & 0x03,
+            .. Default::default()
+        };
+
+        let mut skip = 5;
+        if (slice[4] & 0x20) != 0 {
+            result.scrambling_sequence_index = Some(slice[skip ..].get_u24() & 0x0003_FFFF);
+            skip += 3;
+        }
+
+        let (channel, multiple_input_stream_flag) = Desc7F17c::parse(&slice[skip ..]);
+        result.channel = channel;
+        skip += CHANNEL_SIZE;
+
+        if multiple_input_stream_flag {
+            result.input_stream_identifier = Some(slice[skip]);
+            skip += 1;
+        }
+
+        match result.s2x_mode {
+            S2X_MODE_TIME_SLICING => {
+                result.timeslice_number = slice[skip];
+                skip += 1;
+            }
+            S2X_MODE_CHANNEL_BONDING => {
+                let count = usize::from(slice[skip] & 0x01) + 1;
+                skip += 1;
+                for _ in 0 .. count {
+                    result.bonds.push(Desc7F17c::parse(&slice[skip ..]).0);
+                    skip += CHANNEL_SIZE;
+                }
+            }
+            _ => {}
+        }
+
+        result.reserved.extend_from_slice(&slice[skip ..]);
+        result
+    }
+
+    pub fn size(&self) -> usize {
+        let mut size = 5 + CHANNEL_SIZE;
+        if self.scrambling_sequence_index.is_some() {
+            size += 3;
+        }
+        if self.input_stream_identifier.is_some() {
+            size += 1;
+        }
+        match self.s2x_mode {
+            S2X_MODE_TIME_SLICING => size += 1,
+            S2X_MODE_CHANNEL_BONDING => size += 1 + self.bonds.len() * CHANNEL_SIZE,
+            _ => {}
+        }
+        size + self.reserved.len()
+    }
+
+    pub fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x7F);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0x17);
+        buffer.push(set_bits!(8,
+            self.receiver_profiles, 5,
+            0, 3));
+        buffer.push(set_bits!(8,
+            self.s2x_mode, 2,
+            u8::from(self.scrambling_sequence_index.is_some()), 1,
+            0, 3,
+            self.ts_gs_s2x_mode, 2));
+
+        if let Some(index) = self.scrambling_sequence_index {
+            let skip = buffer.len();
+            buffer.resize(skip + 3, 0x00);
+            buffer[skip ..].set_u24(index & 0x0003_FFFF);
+        }
+
+        self.channel.assemble(buffer, self.input_stream_identifier.is_some());
+
+        if let Some(isi) = self.input_stream_identifier {
+            buffer.push(isi);
+        }
+
+        match self.s2x_mode {
+            S2X_MODE_TIME_SLICING => buffer.push(self.timeslice_number),
+            S2X_MODE_CHANNEL_BONDING => {
+                buffer.push(set_bits!(8,
+                    0, 7,
+                    (self.bonds.len().max(1) - 1) as u8, 1));
+                for bond in &self.bonds {
+                    bond.assemble(buffer, false);
+                }
+            }
+            _ => {}
+        }
+
+        buffer.extend_from_slice(&self.reserved);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        constants,
+        psi::{
+            Desc,
+            Descriptors,
+            Desc7F,
+            Desc7F17,
+            Desc7F17c,
+            S2X_MODE_NORMAL,
+        },
+    };
+
+    static DATA_7F17: &[u8] = &[
+        0x7f, 0x0f, 0x17, 0x08, 0x43, 0x01, 0x23, 0x80, 0x00, 0x01, 0x30, 0xb3, 0xf0, 0x27,
+        0x50, 0x00, 0x05,
+    ];
+
+    #[test]
+    fn test_7f17_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_7F17);
+
+        let desc = descriptors.find::<Desc7F>().unwrap();
+        assert_eq!(desc.size(), DATA_7F17.len());
+        let desc = match desc {
+            Desc7F::S2XDelivery(v) => v,
+            _ => unreachable!(),
+        };
+        assert_eq!(desc.receiver_profiles, 1);
+        assert_eq!(desc.s2x_mode, S2X_MODE_NORMAL);
+        assert_eq!(desc.ts_gs_s2x_mode, 3);
+        assert!(desc.scrambling_sequence_index.is_none());
+        assert_eq!(desc.channel.frequency, 12380000);
+        assert_eq!(desc.channel.orbital_position, 780);
+        assert_eq!(desc.channel.west_east_flag, constants::POSITION_EAST);
+        assert_eq!(desc.channel.polarization, constants::POLARIZATION_V);
+        assert_eq!(desc.channel.rof, 3);
+        assert_eq!(desc.channel.symbol_rate, 27500);
+        assert_eq!(desc.input_stream_identifier, Some(5));
+    }
+
+    #[test]
+    fn test_7f17_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc7F::from(Desc7F17 {
+            receiver_profiles: 1,
+            s2x_mode: S2X_MODE_NORMAL,
+            ts_gs_s2x_mode: 3,
+            channel: Desc7F17c {
+                frequency: 12380000,
+                orbital_position: 780,
+                west_east_flag: constants::POSITION_EAST,
+                polarization: constants::POLARIZATION_V,
+                rof: 3,
+                symbol_rate: 27500,
+            },
+            input_stream_identifier: Some(5),
+            .. Default::default()
+        }));
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_7F17);
+    }
+}
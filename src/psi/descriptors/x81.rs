@@ -0,0 +1,334 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 5;
+
+
+/// The AC-3 audio descriptor is used to identify streams which carry
+/// AC-3 audio in ATSC and other systems.
+/// Optional fields are present only if all previous fields are defined.
+///
+/// ATSC A/52 - A.4.3
+#[derive(Debug, Default, Clone)]
+pub struct Desc81 {
+    /// Sampling frequency code
+    pub sample_rate_code: u8,
+    /// AC-3 coding version
+    pub bsid: u8,
+    /// Bit rate code. Bit 5 is set if value is an upper limit
+    pub bit_rate_code: u8,
+    /// Surround mode: 0 - not indicated, 1 - not Dolby Surround, 2 - Dolby Surround
+    pub surround_mode: u8,
+    /// Type of the audio service
+    pub bsmod: u8,
+    /// Number of channels. Value 0 is used for 1+1 mode
+    pub num_channels: u8,
+    /// Audio service is a full service
+    pub full_svc: bool,
+    /// Language code of the audio service
+    pub langcod: Option<u8>,
+    /// Language code of the second channel in 1+1 mode
+    pub langcod2: Option<u8>,
+    /// mainid and priority if bsmod < 2, otherwise asvcflags
+    pub service_info: Option<u8>,
+    /// Text encoding: `true` - ISO 8859-1, `false` - UTF-16
+    pub text_code: bool,
+    /// Text description of the audio service
+    pub text: Option<Vec<u8>>,
+    /// ISO 639 language code
+    pub language: Option<StringDVB>,
+    /// ISO 639 language code of the second channel in 1+1 mode
+    pub language_2: Option<StringDVB>,
+    /// Additional information
+    pub additional_info: Vec<u8>,
+}
+
+
+impl Desc81 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut skip = MIN_SIZE;
+        if skip == slice.len() {
+            return true;
+        }
+
+        // langcod, langcod2
+        skip += if (slice[4] >> 1) & 0x0F == 0 { 2 } else { 1 };
+        if skip >= slice.len() {
+            return skip == slice.len();
+        }
+
+        // mainid or asvcflags
+        skip += 1;
+        if skip == slice.len() {
+            return true;
+        }
+
+        // textlen, text
+        skip += 1 + usize::from(slice[skip] >> 1);
+        if skip >= slice.len() {
+            return skip == slice.len();
+        }
+
+        // language_flag, language_flag_2
+        let flags = slice[skip];
+        skip += 1 + usize::from(flags >> 7) * 3 + usize::from((flags >> 6) & 0x01) * 3;
+        skip <= slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            sample_rate_code: slice[2] >> 5,
+            bsid: slice[2] & 0x1F,
+            bit_rate_code: slice[3] >> 2,
+            surround_mode: slice[3] & 0x03,
+            bsmod: slice[4] >> 5,
+            num_channels: (slice[4] >> 1) & 0x0F,
+            full_svc: (slice[4] & 0x01) != 0,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        if skip == slice.len() {
+            return result;
+        }
+
+        result.langcod = Some(slice[skip]);
+        skip += 1;
+        if result.num_channels == 0 {
+            result.langcod2 = Some(slice[skip]);
+            skip += 1;
+        }
+        if skip == slice.len() {
+            return result;
+        }
+
+        result.service_info = Some(slice[skip]);
+        skip += 1;
+        if skip == slice.len() {
+            return result;
+        }
+
+        let text_len = usize::from(slice[skip] >> 1);
+        result.text_code = (slice[skip] & 0x01) != 0;
+        skip += 1;
+        result.text = Some(Vec::from(&slice[skip .. skip + text_len]));
+        skip += text_len;
+        if skip == slice.len() {
+            return result;
+        }
+
+        let flags = slice[skip];
+        skip += 1;
+        if (flags & 0x80) != 0 {
+            result.language = Some(StringDVB::from(&slice[skip .. skip + 3]));
+            skip += 3;
+        }
+        if (flags & 0x40) != 0 {
+            result.language_2 = Some(StringDVB::from(&slice[skip .. skip + 3]));
+            skip += 3;
+        }
+
+        result.additional_info.extend_from_slice(&slice[skip ..]);
+        result
+    }
+
+    /// Returns main audio service identifier if bsmod < 2
+    #[inline]
+    pub fn mainid(&self) -> Option<u8> {
+        self.service_info.filter(|_| self.bsmod < 2).map(|v| v >> 5)
+    }
+
+    /// Returns priority of the main audio service if bsmod < 2
+    #[inline]
+    pub fn priority(&self) -> Option<u8> {
+        self.service_info.filter(|_| self.bsmod < 2).map(|v| (v >> 3) & 0x03)
+    }
+
+    /// Returns main audio services the associated service is associated with
+    #[inline]
+    pub fn asvcflags(&self) -> Option<u8> {
+        self.service_info.filter(|_| self.bsmod >= 2)
+    }
+
+    /// Language flags byte is required if any of the following fields defined
+    #[inline]
+    fn has_language(&self) -> bool {
+        self.language.is_some() ||
+            self.language_2.is_some() ||
+            ! self.additional_info.is_empty()
+    }
+}
+
+
+impl Desc for Desc81 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x81
+    }
+
+    fn size(&self) -> usize {
+        let mut size = MIN_SIZE;
+
+        if self.langcod.is_none() {
+            return size;
+        }
+        size += if self.num_channels == 0 { 2 } else { 1 };
+
+        if self.service_info.is_none() {
+            return size;
+        }
+        size += 1;
+
+        let text = match &self.text {
+            Some(v) => v,
+            None => return size,
+        };
+        size += 1 + text.len();
+
+        if self.has_language() {
+            size += 1;
+            size += self.language.as_ref().map_or(0, |_| 3);
+            size += self.language_2.as_ref().map_or(0, |_| 3);
+            size += self.additional_info.len();
+        }
+
+        size
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x81);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(set_bits!(8,
+            self.sample_rate_code, 3,
+            self.bsid, 5));
+        buffer.push(set_bits!(8,
+            self.bit_rate_code, 6,
+            self.surround_mode, 2));
+        buffer.push(set_bits!(8,
+            self.bsmod, 3,
+            self.num_channels, 4,
+            u8::from(self.full_svc), 1));
+
+        let langcod = match self.langcod {
+            Some(v) => v,
+            None => return,
+        };
+        buffer.push(langcod);
+        if self.num_channels == 0 {
+            buffer.push(self.langcod2.unwrap_or(0xFF));
+        }
+
+        let service_info = match self.service_info {
+            Some(v) => v,
+            None => return,
+        };
+        buffer.push(service_info);
+
+        let text = match &self.text {
+            Some(v) => v,
+            None => return,
+        };
+        buffer.push(set_bits!(8,
+            text.len() as u8, 7,
+            u8::from(self.text_code), 1));
+        buffer.extend_from_slice(text);
+
+        if self.has_language() {
+            buffer.push(set_bits!(8,
+                u8::from(self.language.is_some()), 1,
+                u8::from(self.language_2.is_some()), 1,
+                0x3F, 6));
+            if let Some(language) = &self.language {
+                language.assemble(buffer);
+            }
+            if let Some(language_2) = &self.language_2 {
+                language_2.assemble(buffer);
+            }
+            buffer.extend_from_slice(&self.additional_info);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Desc,
+            Descriptors,
+            Desc81,
+        },
+    };
+
+    static DATA_81_SHORT: &[u8] = &[0x81, 0x03, 0x08, 0x38, 0x0f];
+    static DATA_81: &[u8] = &[
+        0x81, 0x0d, 0x08, 0x38, 0x0f, 0xff, 0x3f, 0x07, 0x65, 0x6e, 0x67, 0xbf, 0x65, 0x6e, 0x67,
+    ];
+
+    #[test]
+    fn test_81_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_81_SHORT);
+        descriptors.parse(DATA_81);
+
+        let desc = descriptors.get(0).unwrap().downcast_ref::<Desc81>().unwrap();
+        assert_eq!(desc.size(), DATA_81_SHORT.len());
+        assert_eq!(desc.sample_rate_code, 0);
+        assert_eq!(desc.bsid, 8);
+        assert_eq!(desc.bit_rate_code, 14);
+        assert_eq!(desc.surround_mode, 0);
+        assert_eq!(desc.bsmod, 0);
+        assert_eq!(desc.num_channels, 7);
+        assert!(desc.full_svc);
+        assert_eq!(desc.langcod, None);
+
+        let desc = descriptors.get(1).unwrap().downcast_ref::<Desc81>().unwrap();
+        assert_eq!(desc.size(), DATA_81.len());
+        assert_eq!(desc.langcod, Some(0xFF));
+        assert_eq!(desc.mainid(), Some(1));
+        assert_eq!(desc.priority(), Some(3));
+        assert_eq!(desc.asvcflags(), None);
+        assert!(desc.text_code);
+        assert_eq!(desc.text.as_ref().unwrap().as_slice(), b"eng");
+        assert_eq!(desc.language, Some(textcode::StringDVB::from_str("eng", textcode::ISO6937)));
+        assert!(desc.language_2.is_none());
+    }
+
+    #[test]
+    fn test_81_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc81 {
+            sample_rate_code: 0,
+            bsid: 8,
+            bit_rate_code: 14,
+            surround_mode: 0,
+            bsmod: 0,
+            num_channels: 7,
+            full_svc: true,
+            langcod: Some(0xFF),
+            service_info: Some(0x3F),
+            text_code: true,
+            text: Some(b"eng".to_vec()),
+            language: Some(textcode::StringDVB::from_str("eng", textcode::ISO6937)),
+            .. Default::default()
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_81);
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+use super::{
+    Desc,
+    Desc83i,
+};
+
+
+const MIN_SIZE: usize = 2;
+
+
+/// NorDig Logical Channel Descriptor version 1 - provides a default
+/// channel number label for service. Same tag as in the EACEM descriptor
+/// but with 14-bit channel number. Defined with private_data_specifier 0x29
+///
+/// NorDig Unified - 12.2.9.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc83NorDig {
+    /// List of pairs service_id (pnr), visible flag, and channel number
+    pub items: Vec<Desc83i>,
+}
+
+
+impl Desc83NorDig {
+    #[inline]
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        ((slice.len() - 2) % 4) == 0
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+        let mut skip = 2;
+        while slice.len() >= skip + 4 {
+            let service_id = slice[skip ..].get_u16();
+            let visible = slice[skip + 2] >> 7;
+            let lcn = slice[skip + 2 ..].get_u16() & 0x3FFF;
+            result.items.push(Desc83i {
+                service_id,
+                visible,
+                lcn,
+            });
+            skip += 4;
+        }
+        result
+    }
+}
+
+
+impl Desc for Desc83NorDig {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x83
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * 4
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let mut skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0x83;
+        buffer[skip + 1] = (size - 2) as u8;
+        skip += 2;
+
+        for item in &self.items {
+            buffer[skip ..].set_u16(item.service_id);
+            buffer[skip + 2 ..].set_u16(
+                set_bits!(16,
+                    u16::from(item.visible), 1,
+                    1, 1,
+                    item.lcn, 14));
+            skip += 4;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::psi::{
+        Descriptors,
+        Desc5F,
+        Desc83,
+        Desc83NorDig,
+        Desc83i,
+        PRIVATE_DATA_SPECIFIER_NORDIG,
+    };
+
+    static DATA_83: &[u8] = &[
+        0x5f, 0x04, 0x00, 0x00, 0x00, 0x29,
+        0x83, 0x08, 0x21, 0x85, 0xc4, 0x19, 0x21, 0x86, 0x40, 0x2b,
+    ];
+
+    #[test]
+    fn test_83_nordig_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_83);
+
+        assert!(descriptors.find::<Desc83>().is_none());
+        let desc = descriptors.find::<Desc83NorDig>().unwrap();
+        let mut items = desc.items.iter();
+        let item = items.next().unwrap();
+        assert_eq!(item.service_id, 8581);
+        assert_eq!(item.visible, 1);
+        assert_eq!(item.lcn, 1049);
+        let item = items.next().unwrap();
+        assert_eq!(item.service_id, 8582);
+        assert_eq!(item.visible, 0);
+        assert_eq!(item.lcn, 43);
+    }
+
+    #[test]
+    fn test_83_nordig_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc5F {
+            private_data_specifier: PRIVATE_DATA_SPECIFIER_NORDIG,
+        });
+        descriptors.push(Desc83NorDig {
+            items: vec![
+                Desc83i {
+                    service_id: 8581,
+                    visible: 1,
+                    lcn: 1049,
+                },
+                Desc83i {
+                    service_id: 8582,
+                    visible: 0,
+                    lcn: 43,
+                },
+            ]
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_83);
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::StringDVB;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+const ITEM_SIZE: usize = 6;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc86i {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// * `true` - CEA-708 digital TV closed captioning
+    /// * `false` - CEA-608 line-21 closed captioning
+    pub digital_cc: bool,
+    /// Line-21 field for CEA-608 captions:
+    /// * `0` - field 1
+    /// * `1` - field 2
+    pub line21_field: u8,
+    /// CEA-708 caption service number. Defined if `digital_cc` is `true`
+    pub caption_service_number: u8,
+    /// Service is an easy reader type
+    pub easy_reader: bool,
+    /// Service is formatted for 16:9 displays
+    pub wide_aspect_ratio: bool,
+}
+
+
+impl Desc86i {
+    /// Returns `true` if caption is CEA-608 line-21
+    #[inline]
+    pub fn is_608(&self) -> bool {
+        ! self.digital_cc
+    }
+}
+
+
+/// The caption service descriptor indicates closed captioning services
+/// carried in the video stream or event.
+///
+/// A/65 - 6.9.2
+#[derive(Debug, Default, Clone)]
+pub struct Desc86 {
+    pub items: Vec<Desc86i>,
+}
+
+
+impl Desc86 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        slice.len() >= MIN_SIZE + usize::from(slice[2] & 0x1F) * ITEM_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let number_of_services = usize::from(slice[2] & 0x1F);
+        let mut skip = MIN_SIZE;
+        for _ in 0 .. number_of_services {
+            let digital_cc = (slice[skip + 3] & 0x80) != 0;
+            result.items.push(Desc86i {
+                lang: StringDVB::from(&slice[skip .. skip + 3]),
+                digital_cc,
+                line21_field: if digital_cc { 0 } else { slice[skip + 3] & 0x01 },
+                caption_service_number: if digital_cc { slice[skip + 3] & 0x3F } else { 0 },
+                easy_reader: (slice[skip + 4] & 0x80) != 0,
+                wide_aspect_ratio: (slice[skip + 4] & 0x40) != 0,
+            });
+            skip += ITEM_SIZE;
+        }
+
+        result
+    }
+}
+
+
+impl Desc for Desc86 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x86
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * ITEM_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x86);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0xE0 | (self.items.len() as u8));
+
+        for item in &self.items {
+            item.lang.assemble(buffer);
+            if item.digital_cc {
+                buffer.push(0xC0 | item.caption_service_number);
+            } else {
+                buffer.push(0x7E | item.line21_field);
+            }
+            buffer.push(set_bits!(8,
+                item.easy_reader as u8, 1,
+                item.wide_aspect_ratio as u8, 1,
+                0x3F, 6));
+            buffer.push(0xFF);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc86,
+            Desc86i,
+        },
+    };
+
+    static DATA_86: &[u8] = &[
+        0x86, 0x0d, 0xe2,
+        0x65, 0x6e, 0x67, 0x7e, 0x3f, 0xff,
+        0x73, 0x70, 0x61, 0xc2, 0x7f, 0xff,
+    ];
+
+    #[test]
+    fn test_86_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_86);
+
+        let desc = descriptors.find::<Desc86>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+
+        let item = &desc.items[0];
+        assert_eq!(item.lang, textcode::StringDVB::from_str("eng", textcode::ISO6937));
+        assert!(item.is_608());
+        assert_eq!(item.line21_field, 0);
+        assert!(! item.easy_reader);
+        assert!(! item.wide_aspect_ratio);
+
+        let item = &desc.items[1];
+        assert_eq!(item.lang, textcode::StringDVB::from_str("spa", textcode::ISO6937));
+        assert!(item.digital_cc);
+        assert_eq!(item.caption_service_number, 2);
+        assert!(item.wide_aspect_ratio);
+    }
+
+    #[test]
+    fn test_86_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc86 {
+            items: vec![
+                Desc86i {
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                    digital_cc: false,
+                    line21_field: 0,
+                    caption_service_number: 0,
+                    easy_reader: false,
+                    wide_aspect_ratio: false,
+                },
+                Desc86i {
+                    lang: textcode::StringDVB::from_str("spa", textcode::ISO6937),
+                    digital_cc: true,
+                    line21_field: 0,
+                    caption_service_number: 2,
+                    easy_reader: false,
+                    wide_aspect_ratio: true,
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_86);
+    }
+}
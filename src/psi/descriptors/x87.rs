@@ -0,0 +1,197 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::MultipleString;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 3;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc87d {
+    /// Index of the dimension in the Rating Region Table
+    pub rating_dimension: u8,
+    /// Rating level for the dimension
+    pub rating_value: u8,
+}
+
+
+#[derive(Debug, Default, Clone)]
+pub struct Desc87i {
+    /// Rating region defined in the Rating Region Table:
+    /// * `0x01` - US (50 states + possessions)
+    /// * `0x02` - Canada
+    pub rating_region: u8,
+    /// List of rated dimensions
+    pub dimensions: Vec<Desc87d>,
+    /// Text describing the rating
+    pub description: MultipleString,
+}
+
+
+impl Desc87i {
+    #[inline]
+    fn size(&self) -> usize {
+        2 + self.dimensions.len() * 2 + 1 + self.description.size()
+    }
+}
+
+
+/// The content advisory descriptor defines the ratings for the event
+/// or program in one or more rating regions.
+///
+/// A/65 - 6.9.3
+#[derive(Debug, Default, Clone)]
+pub struct Desc87 {
+    pub items: Vec<Desc87i>,
+}
+
+
+impl Desc87 {
+    pub fn check(slice: &[u8]) -> bool {
+        if slice.len() < MIN_SIZE {
+            return false;
+        }
+
+        let mut skip = MIN_SIZE;
+        for _ in 0 .. (slice[2] & 0x3F) {
+            if slice.len() < skip + 2 {
+                return false;
+            }
+            let description_s = skip + 2 + usize::from(slice[skip + 1]) * 2;
+            if slice.len() < description_s + 1 {
+                return false;
+            }
+            skip = description_s + 1 + usize::from(slice[description_s]);
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        for _ in 0 .. (slice[2] & 0x3F) {
+            let mut item = Desc87i {
+                rating_region: slice[skip],
+                .. Default::default()
+            };
+
+            let rated_dimensions = usize::from(slice[skip + 1]);
+            skip += 2;
+            for _ in 0 .. rated_dimensions {
+                item.dimensions.push(Desc87d {
+                    rating_dimension: slice[skip],
+                    rating_value: slice[skip + 1] & 0x0F,
+                });
+                skip += 2;
+            }
+
+            let description_s = skip + 1;
+            let description_e = description_s + usize::from(slice[skip]);
+            item.description = MultipleString::from(&slice[description_s .. description_e]);
+            skip = description_e;
+
+            result.items.push(item);
+        }
+
+        result
+    }
+
+    /// Returns rating for the rating region
+    pub fn get_region(&self, rating_region: u8) -> Option<&Desc87i> {
+        self.items.iter().find(|item| item.rating_region == rating_region)
+    }
+}
+
+
+impl Desc for Desc87 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x87
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| acc + item.size())
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x87);
+        buffer.push((self.size() - 2) as u8);
+        buffer.push(0xC0 | (self.items.len() as u8));
+
+        for item in &self.items {
+            buffer.push(item.rating_region);
+            buffer.push(item.dimensions.len() as u8);
+            for dimension in &item.dimensions {
+                buffer.push(dimension.rating_dimension);
+                buffer.push(0xF0 | dimension.rating_value);
+            }
+            buffer.push(item.description.size() as u8);
+            item.description.assemble(buffer);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc87,
+            Desc87i,
+            Desc87d,
+        },
+    };
+
+    static DATA_87: &[u8] = &[
+        0x87, 0x13, 0xc1,
+        0x01, 0x01, 0x00, 0xf4,
+        0x0d, 0x01, 0x65, 0x6e, 0x67, 0x01, 0x00, 0x00, 0x05, 0x54, 0x56, 0x2d, 0x31, 0x34,
+    ];
+
+    #[test]
+    fn test_87_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_87);
+
+        let desc = descriptors.find::<Desc87>().unwrap();
+        let item = desc.get_region(1).unwrap();
+        assert_eq!(item.dimensions.len(), 1);
+        assert_eq!(item.dimensions[0].rating_dimension, 0);
+        assert_eq!(item.dimensions[0].rating_value, 4);
+        assert_eq!(item.description.get_text("eng"), Some("TV-14"));
+    }
+
+    #[test]
+    fn test_87_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc87 {
+            items: vec![
+                Desc87i {
+                    rating_region: 1,
+                    dimensions: vec![
+                        Desc87d {
+                            rating_dimension: 0,
+                            rating_value: 4,
+                        },
+                    ],
+                    description: textcode::MultipleString::from_str("TV-14", "eng"),
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_87);
+    }
+}
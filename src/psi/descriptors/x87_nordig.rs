@@ -0,0 +1,234 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    textcode::{
+        country,
+        StringDVB,
+    },
+};
+use super::{
+    Desc,
+    Desc83i,
+};
+
+
+const MIN_SIZE: usize = 2;
+
+
+/// Channel list for the country
+#[derive(Debug, Default, Clone)]
+pub struct Desc87NorDigList {
+    /// Unique identifier of the channel list
+    pub channel_list_id: u8,
+    /// Name of the channel list
+    pub name: StringDVB,
+    /// ISO 3166 alpha-3 country code
+    pub country_code: StringDVB,
+    /// List of pairs service_id (pnr), visible flag, and channel number
+    pub items: Vec<Desc83i>,
+}
+
+
+impl Desc87NorDigList {
+    #[inline]
+    fn size(&self) -> usize {
+        2 + self.name.size() + 3 + 1 + self.items.len() * 4
+    }
+}
+
+
+/// NorDig Logical Channel Descriptor version 2 - provides a channel number
+/// label for service in the several channel lists, e.g. one list per
+/// country. Defined with private_data_specifier 0x29
+///
+/// NorDig Unified - 12.2.9.3
+#[derive(Debug, Default, Clone)]
+pub struct Desc87NorDig {
+    pub items: Vec<Desc87NorDigList>,
+}
+
+
+impl Desc87NorDig {
+    pub fn check(slice: &[u8]) -> bool {
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            if slice.len() < skip + 2 {
+                return false;
+            }
+            let country_s = skip + 2 + usize::from(slice[skip + 1]);
+            if slice.len() < country_s + 4 {
+                return false;
+            }
+            let items_len = usize::from(slice[country_s + 3]);
+            if (items_len % 4) != 0 {
+                return false;
+            }
+            skip = country_s + 4 + items_len;
+        }
+        skip == slice.len()
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self::default();
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            let name_s = skip + 2;
+            let name_e = name_s + usize::from(slice[skip + 1]);
+            let items_e = name_e + 4 + usize::from(slice[name_e + 3]);
+
+            let mut list = Desc87NorDigList {
+                channel_list_id: slice[skip],
+                name: StringDVB::from(&slice[name_s .. name_e]),
+                country_code: StringDVB::from(&slice[name_e .. name_e + 3]),
+                items: Vec::new(),
+            };
+
+            let mut item_skip = name_e + 4;
+            while items_e > item_skip {
+                list.items.push(Desc83i {
+                    service_id: slice[item_skip ..].get_u16(),
+                    visible: slice[item_skip + 2] >> 7,
+                    lcn: slice[item_skip + 2 ..].get_u16() & 0x03FF,
+                });
+                item_skip += 4;
+            }
+
+            result.items.push(list);
+            skip = items_e;
+        }
+
+        result
+    }
+
+    /// Returns channel list for the country defined by ISO 3166 code
+    pub fn get_list(&self, code: &str) -> Option<&Desc87NorDigList> {
+        self.items.iter().find(|item| country::is_same(&item.country_code.to_string(), code))
+    }
+}
+
+
+impl Desc for Desc87NorDig {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0x87
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.items.iter().fold(MIN_SIZE, |acc, item| acc + item.size())
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0x87);
+        buffer.push((self.size() - 2) as u8);
+
+        for list in &self.items {
+            buffer.push(list.channel_list_id);
+            list.name.assemble_sized(buffer);
+            list.country_code.assemble(buffer);
+            buffer.push((list.items.len() * 4) as u8);
+
+            for item in &list.items {
+                let skip = buffer.len();
+                buffer.resize(skip + 4, 0x00);
+                buffer[skip ..].set_u16(item.service_id);
+                buffer[skip + 2 ..].set_u16(
+                    set_bits!(16,
+                        u16::from(item.visible), 1,
+                        0x1F, 5,
+                        item.lcn, 10));
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            Desc5F,
+            Desc83i,
+            Desc87NorDig,
+            Desc87NorDigList,
+            PRIVATE_DATA_SPECIFIER_NORDIG,
+        },
+    };
+
+    static DATA_87: &[u8] = &[
+        0x5f, 0x04, 0x00, 0x00, 0x00, 0x29,
+        0x87, 0x18,
+        0x01, 0x02, 0x46, 0x49, 0x46, 0x49, 0x4e, 0x04, 0x21, 0x85, 0xfc, 0x01,
+        0x02, 0x02, 0x53, 0x45, 0x53, 0x57, 0x45, 0x04, 0x21, 0x85, 0x7c, 0x05,
+    ];
+
+    #[test]
+    fn test_87_nordig_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_87);
+
+        let desc = descriptors.find::<Desc87NorDig>().unwrap();
+        assert_eq!(desc.items.len(), 2);
+
+        let list = desc.get_list("SE").unwrap();
+        assert_eq!(list.channel_list_id, 2);
+        assert_eq!(list.name.to_string(), "SE");
+        let item = &list.items[0];
+        assert_eq!(item.service_id, 8581);
+        assert_eq!(item.visible, 0);
+        assert_eq!(item.lcn, 5);
+
+        assert_eq!(desc.get_list("FIN").unwrap().items[0].lcn, 1);
+        assert!(desc.get_list("NOR").is_none());
+    }
+
+    #[test]
+    fn test_87_nordig_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc5F {
+            private_data_specifier: PRIVATE_DATA_SPECIFIER_NORDIG,
+        });
+        descriptors.push(Desc87NorDig {
+            items: vec![
+                Desc87NorDigList {
+                    channel_list_id: 1,
+                    name: textcode::StringDVB::from_str("FI", textcode::ISO6937),
+                    country_code: textcode::StringDVB::from_str("FIN", textcode::ISO6937),
+                    items: vec![
+                        Desc83i {
+                            service_id: 8581,
+                            visible: 1,
+                            lcn: 1,
+                        },
+                    ],
+                },
+                Desc87NorDigList {
+                    channel_list_id: 2,
+                    name: textcode::StringDVB::from_str("SE", textcode::ISO6937),
+                    country_code: textcode::StringDVB::from_str("SWE", textcode::ISO6937),
+                    items: vec![
+                        Desc83i {
+                            service_id: 8581,
+                            visible: 0,
+                            lcn: 5,
+                        },
+                    ],
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_87);
+    }
+}
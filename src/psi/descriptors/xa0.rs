@@ -0,0 +1,94 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::textcode::MultipleString;
+use super::Desc;
+
+
+const MIN_SIZE: usize = 2;
+
+
+/// The extended channel name descriptor provides the long channel name
+/// for the virtual channel.
+///
+/// A/65 - 6.9.4
+#[derive(Debug, Default, Clone)]
+pub struct DescA0 {
+    /// Long channel name
+    pub name: MultipleString,
+}
+
+
+impl DescA0 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        Self {
+            name: MultipleString::from(&slice[MIN_SIZE ..]),
+        }
+    }
+}
+
+
+impl Desc for DescA0 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0xA0
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.name.size()
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        buffer.push(0xA0);
+        buffer.push((self.size() - 2) as u8);
+        self.name.assemble(buffer);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            DescA0,
+        },
+    };
+
+    static DATA_A0: &[u8] = &[
+        0xa0, 0x0f, 0x01, 0x65, 0x6e, 0x67, 0x01, 0x00, 0x00, 0x07, 0x4b, 0x51, 0x45, 0x44,
+        0x2d, 0x48, 0x44,
+    ];
+
+    #[test]
+    fn test_a0_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_A0);
+
+        let desc = descriptors.find::<DescA0>().unwrap();
+        assert_eq!(desc.name.to_string(), "KQED-HD");
+    }
+
+    #[test]
+    fn test_a0_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(DescA0 {
+            name: textcode::MultipleString::from_str("KQED-HD", "eng"),
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_A0);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    textcode::StringDVB,
+};
+use super::Desc;
+
+
+const MIN_SIZE: usize = 5;
+const ITEM_SIZE: usize = 6;
+
+
+#[derive(Debug, Default, Clone)]
+pub struct DescA1i {
+    /// Type of the elementary stream
+    pub stream_type: u8,
+    /// PID of the elementary stream
+    pub pid: u16,
+    /// ISO 639 language code. Filled with zeroes if not defined
+    pub lang: StringDVB,
+}
+
+
+/// The service location descriptor specifies the stream type, PID,
+/// and language code for each elementary stream of the virtual channel.
+///
+/// A/65 - 6.9.5
+#[derive(Debug, Default, Clone)]
+pub struct DescA1 {
+    /// PID of the stream with PCR
+    pub pcr_pid: u16,
+    pub items: Vec<DescA1i>,
+}
+
+
+impl DescA1 {
+    pub fn check(slice: &[u8]) -> bool {
+        slice.len() >= MIN_SIZE &&
+        slice.len() == MIN_SIZE + usize::from(slice[4]) * ITEM_SIZE
+    }
+
+    pub fn parse(slice: &[u8]) -> Self {
+        let mut result = Self {
+            pcr_pid: slice[2 ..].get_u16() & 0x1FFF,
+            .. Default::default()
+        };
+
+        let mut skip = MIN_SIZE;
+        while slice.len() > skip {
+            result.items.push(DescA1i {
+                stream_type: slice[skip],
+                pid: slice[skip + 1 ..].get_u16() & 0x1FFF,
+                lang: StringDVB::from(&slice[skip + 3 .. skip + 6]),
+            });
+            skip += ITEM_SIZE;
+        }
+
+        result
+    }
+}
+
+
+impl Desc for DescA1 {
+    #[inline]
+    fn tag(&self) -> u8 {
+        0xA1
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        MIN_SIZE + self.items.len() * ITEM_SIZE
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let size = self.size();
+        let mut skip = buffer.len();
+        buffer.resize(skip + size, 0x00);
+
+        buffer[skip] = 0xA1;
+        buffer[skip + 1] = (size - 2) as u8;
+        buffer[skip + 2 ..].set_u16(0xE000 | self.pcr_pid);
+        buffer[skip + 4] = self.items.len() as u8;
+        skip += MIN_SIZE;
+
+        for item in &self.items {
+            buffer[skip] = item.stream_type;
+            buffer[skip + 1 ..].set_u16(0xE000 | item.pid);
+            let lang = item.lang.as_bytes();
+            let len = lang.len().min(3);
+            buffer[skip + 3 .. skip + 3 + len].copy_from_slice(&lang[.. len]);
+            skip += ITEM_SIZE;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        textcode,
+        psi::{
+            Descriptors,
+            DescA1,
+            DescA1i,
+        },
+    };
+
+    static DATA_A1: &[u8] = &[
+        0xa1, 0x0f, 0xe0, 0x31, 0x02,
+        0x02, 0xe0, 0x31, 0x00, 0x00, 0x00,
+        0x81, 0xe0, 0x34, 0x65, 0x6e, 0x67,
+    ];
+
+    #[test]
+    fn test_a1_parse() {
+        let mut descriptors = Descriptors::default();
+        descriptors.parse(DATA_A1);
+
+        let desc = descriptors.find::<DescA1>().unwrap();
+        assert_eq!(desc.pcr_pid, 0x31);
+        assert_eq!(desc.items.len(), 2);
+        assert_eq!(desc.items[0].stream_type, 0x02);
+        assert_eq!(desc.items[0].pid, 0x31);
+        assert_eq!(desc.items[1].stream_type, 0x81);
+        assert_eq!(desc.items[1].pid, 0x34);
+        assert_eq!(desc.items[1].lang, textcode::StringDVB::from_str("eng", textcode::ISO6937));
+    }
+
+    #[test]
+    fn test_a1_assemble() {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(DescA1 {
+            pcr_pid: 0x31,
+            items: vec![
+                DescA1i {
+                    stream_type: 0x02,
+                    pid: 0x31,
+                    lang: textcode::StringDVB::default(),
+                },
+                DescA1i {
+                    stream_type: 0x81,
+                    pid: 0x34,
+                    lang: textcode::StringDVB::from_str("eng", textcode::ISO6937),
+                },
+            ],
+        });
+
+        let mut assembled = Vec::new();
+        descriptors.assemble(&mut assembled);
+
+        assert_eq!(assembled.as_slice(), DATA_A1);
+    }
+}
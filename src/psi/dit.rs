@@ -0,0 +1,79 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::psi::{
+    Psi,
+    PsiDemux,
+    PsiError,
+};
+
+
+/// TS Packet Identifier for DIT
+pub const DIT_PID: u16 = 0x001E;
+
+
+/// Discontinuity Information Table is inserted at transition points
+/// where SI information may be discontinuous in the partial TS.
+///
+/// EN 300 468 - 7.1.1
+#[derive(Default, Debug)]
+pub struct Dit {
+    /// Indicates that the transition is due to a change of the
+    /// originating source. `0` - change only in the selection
+    pub transition_flag: u8,
+}
+
+
+impl Dit {
+    #[inline]
+    fn check(&self, psi: &Psi) -> bool {
+        psi.size == 4 &&
+        psi.buffer.len() >= psi.size &&
+        psi.buffer[0] == 0x7E
+    }
+
+    pub fn parse(&mut self, psi: &Psi) {
+        if ! self.check(psi) {
+            return;
+        }
+
+        self.transition_flag = psi.buffer[3] >> 7;
+    }
+}
+
+
+impl PsiDemux for Dit {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        let mut psi = Psi::new(0x7E, 3, 0);
+        psi.buffer[1] = 0x70; /* reserved bits */
+        psi.buffer[2] = 1;
+        psi.buffer.push((self.transition_flag << 7) | 0x7F);
+
+        vec![psi]
+    }
+
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        let mut psi_list = self.psi_list_assemble();
+        let psi = psi_list.first_mut().unwrap();
+        psi.pid = pid;
+        psi.cc = *cc;
+        psi.size = psi.buffer.len();
+        psi.demux(dst);
+        *cc = psi.cc;
+
+        Ok(())
+    }
+}
+
+
+impl From<&Psi> for Dit {
+    fn from(psi: &Psi) -> Self {
+        let mut dit = Dit::default();
+        dit.parse(psi);
+        dit
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::bytes::*;
+
+
+/// BIOP Profile Body tag
+const TAG_BIOP: u32 = 0x4953_4F06;
+/// BIOP::ObjectLocation component tag
+const TAG_OBJECT_LOCATION: u32 = 0x4953_4F50;
+/// DSM::ConnBinder component tag
+const TAG_CONN_BINDER: u32 = 0x4953_4F40;
+
+/// Tap use for the DII message of the module delivery parameters
+pub const BIOP_DELIVERY_PARA_USE: u16 = 0x0016;
+/// Tap use for the elementary stream with module data
+pub const BIOP_OBJECT_USE: u16 = 0x0017;
+
+/// Size of the object key in bytes
+const OBJECT_KEY_SIZE: u8 = 4;
+
+
+/// Kind of the BIOP object
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectKind {
+    /// Service Gateway - root directory of the carousel
+    ServiceGateway,
+    /// Directory
+    Directory,
+    /// File
+    File,
+}
+
+
+impl ObjectKind {
+    #[inline]
+    fn as_bytes(self) -> &'static [u8] {
+        match self {
+            ObjectKind::ServiceGateway => b"srg\0",
+            ObjectKind::Directory => b"dir\0",
+            ObjectKind::File => b"fil\0",
+        }
+    }
+}
+
+
+/// Location of the object in the carousel
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ObjectLocation {
+    /// Carousel identifier
+    pub carousel_id: u32,
+    /// Module where the object is placed
+    pub module_id: u16,
+    /// Object key in the module
+    pub key: u32,
+    /// Association tag of the elementary stream with DII
+    pub association_tag: u16,
+    /// Transaction identifier of the DII
+    pub transaction_id: u32,
+}
+
+
+/// Writes Interoperable Object Reference with BIOP Profile Body
+pub fn assemble_ior(buffer: &mut Vec<u8>, kind: ObjectKind, location: &ObjectLocation) {
+    let skip = buffer.len();
+    buffer.resize(skip + 8, 0x00);
+    buffer[skip ..].set_u32(4);
+    buffer[skip + 4 ..].copy_from_slice(kind.as_bytes());
+
+    let skip = buffer.len();
+    buffer.resize(skip + 4 + 4 + 4 + 2, 0x00);
+    // taggedProfiles_count
+    buffer[skip ..].set_u32(1);
+    buffer[skip + 4 ..].set_u32(TAG_BIOP);
+    // profile_data_length: byte_order, lite_component_count and two components
+    buffer[skip + 8 ..].set_u32(2 + (5 + 13) + (5 + 18));
+    // profile_data_byte_order - big endian
+    buffer[skip + 12] = 0x00;
+    // lite_component_count
+    buffer[skip + 13] = 2;
+
+    // BIOP::ObjectLocation
+    let skip = buffer.len();
+    buffer.resize(skip + 5 + 13, 0x00);
+    buffer[skip ..].set_u32(TAG_OBJECT_LOCATION);
+    buffer[skip + 4] = 13;
+    buffer[skip + 5 ..].set_u32(location.carousel_id);
+    buffer[skip + 9 ..].set_u16(location.module_id);
+    // version.major, version.minor
+    buffer[skip + 11] = 0x01;
+    buffer[skip + 12] = 0x00;
+    buffer[skip + 13] = OBJECT_KEY_SIZE;
+    buffer[skip + 14 ..].set_u32(location.key);
+
+    // DSM::ConnBinder
+    let skip = buffer.len();
+    buffer.resize(skip + 5 + 18, 0x00);
+    buffer[skip ..].set_u32(TAG_CONN_BINDER);
+    buffer[skip + 4] = 18;
+    // taps_count
+    buffer[skip + 5] = 1;
+    // id
+    buffer[skip + 6 ..].set_u16(0);
+    buffer[skip + 8 ..].set_u16(BIOP_DELIVERY_PARA_USE);
+    buffer[skip + 10 ..].set_u16(location.association_tag);
+    // selector_length, selector_type
+    buffer[skip + 12] = 10;
+    buffer[skip + 13 ..].set_u16(0x0001);
+    buffer[skip + 15 ..].set_u32(location.transaction_id);
+    // timeout
+    buffer[skip + 19 ..].set_u32(0xFFFF_FFFF);
+}
+
+
+/// Directory entry
+#[derive(Debug)]
+pub struct Binding<'a> {
+    /// Name of the entry
+    pub name: &'a str,
+    /// Kind of the bound object
+    pub kind: ObjectKind,
+    /// Location of the bound object
+    pub location: ObjectLocation,
+    /// File size for `ObjectKind::File`
+    pub content_size: u64,
+}
+
+
+impl<'a> Binding<'a> {
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        // nameComponents_count
+        buffer.push(1);
+        buffer.push((self.name.len() + 1) as u8);
+        buffer.extend_from_slice(self.name.as_bytes());
+        buffer.push(0x00);
+        buffer.push(4);
+        match self.kind {
+            ObjectKind::File => buffer.extend_from_slice(ObjectKind::File.as_bytes()),
+            _ => buffer.extend_from_slice(ObjectKind::Directory.as_bytes()),
+        };
+        // bindingType - nobject
+        buffer.push(0x01);
+
+        assemble_ior(buffer, self.kind, &self.location);
+
+        let skip = buffer.len();
+        if self.kind == ObjectKind::File {
+            buffer.resize(skip + 10, 0x00);
+            buffer[skip ..].set_u16(8);
+            buffer[skip + 2 ..].set_u32((self.content_size >> 32) as u32);
+            buffer[skip + 6 ..].set_u32(self.content_size as u32);
+        } else {
+            buffer.resize(skip + 2, 0x00);
+        }
+    }
+}
+
+
+/// Writes BIOP message header. Returns offset of the message_size field
+fn assemble_header(buffer: &mut Vec<u8>, kind: ObjectKind, key: u32) -> usize {
+    buffer.extend_from_slice(b"BIOP");
+    // biop_version.major, biop_version.minor, byte_order, message_type
+    buffer.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+
+    let size_skip = buffer.len();
+    buffer.resize(size_skip + 4 + 1 + 4, 0x00);
+    buffer[size_skip + 4] = OBJECT_KEY_SIZE;
+    buffer[size_skip + 5 ..].set_u32(key);
+
+    let skip = buffer.len();
+    buffer.resize(skip + 8, 0x00);
+    buffer[skip ..].set_u32(4);
+    buffer[skip + 4 ..].copy_from_slice(kind.as_bytes());
+
+    size_skip
+}
+
+
+#[inline]
+fn finalize_header(buffer: &mut [u8], size_skip: usize) {
+    let message_size = (buffer.len() - size_skip - 4) as u32;
+    buffer[size_skip ..].set_u32(message_size);
+}
+
+
+/// Writes BIOP::FileMessage
+pub fn assemble_file(buffer: &mut Vec<u8>, key: u32, content: &[u8]) {
+    let size_skip = assemble_header(buffer, ObjectKind::File, key);
+
+    let skip = buffer.len();
+    buffer.resize(skip + 2 + 8 + 1 + 4 + 4, 0x00);
+    // objectInfo_length: DSM::File::ContentSize
+    buffer[skip ..].set_u16(8);
+    buffer[skip + 2 ..].set_u32((content.len() as u64 >> 32) as u32);
+    buffer[skip + 6 ..].set_u32(content.len() as u32);
+    // serviceContextList_count
+    buffer[skip + 10] = 0;
+    buffer[skip + 11 ..].set_u32(4 + content.len() as u32);
+    buffer[skip + 15 ..].set_u32(content.len() as u32);
+    buffer.extend_from_slice(content);
+
+    finalize_header(buffer, size_skip);
+}
+
+
+/// Writes BIOP::DirectoryMessage or BIOP::ServiceGatewayMessage
+pub fn assemble_directory(buffer: &mut Vec<u8>, kind: ObjectKind, key: u32, bindings: &[Binding]) {
+    let size_skip = assemble_header(buffer, kind, key);
+
+    let skip = buffer.len();
+    buffer.resize(skip + 2 + 1 + 4 + 2, 0x00);
+    // objectInfo_length, serviceContextList_count
+    buffer[skip ..].set_u16(0);
+    buffer[skip + 2] = 0;
+    buffer[skip + 7 ..].set_u16(bindings.len() as u16);
+
+    for binding in bindings {
+        binding.assemble(buffer);
+    }
+
+    let body_size = (buffer.len() - skip - 7) as u32;
+    buffer[skip + 3 ..].set_u32(body_size);
+
+    finalize_header(buffer, size_skip);
+}
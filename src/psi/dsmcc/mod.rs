@@ -0,0 +1,582 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io,
+    path::Path,
+};
+
+use crate::{
+    bytes::*,
+    ts,
+    psi::{
+        Psi,
+        PsiDemux,
+        PsiError,
+        Descriptors,
+        Desc13,
+        Desc52,
+        CAROUSEL_FORMAT_STANDARD,
+    },
+};
+
+mod biop;
+use biop::*;
+
+
+/// Maximum block size in the DownloadDataBlock section
+pub const DSMCC_BLOCK_SIZE: u16 = 4066;
+
+
+/// Maximum size of the module
+const MODULE_SIZE: usize = 64 * 1024;
+
+
+/// Maximum number of blocks in the module. Limited by 16-bit blockNumber
+const MODULE_MAX_BLOCKS: usize = 0x1_0000;
+
+
+/// Maximum length of the file or directory name.
+/// Limited by 8-bit id_length with the terminating null byte
+const NAME_MAX_SIZE: usize = 0xFF - 1;
+
+
+/// Maximum section length without CRC
+const DSMCC_SECTION_SIZE: usize = 4096 - 4;
+
+
+/// Section header + dsmccMessageHeader
+const MESSAGE_HEADER_SIZE: usize = 8 + 12;
+
+
+/// DSM-CC Object Carousel generator. Converts a tree of files into
+/// the BIOP objects and broadcasts them with the DownloadServerInitiate,
+/// DownloadInfoIndication and DownloadDataBlock sections.
+///
+/// Files are packed into modules up to 64 KiB, each module is split into
+/// blocks of `block_size` bytes.
+///
+/// ISO 13818-6, EN 301 192 - 8, TR 101 202 - 4.7
+///
+/// # Examples
+///
+/// ``` ignore
+/// use mpegts::psi::*;
+///
+/// let mut carousel = ObjectCarousel::default();
+/// carousel.carousel_id = 1;
+/// carousel.association_tag = 0x000B;
+/// carousel.bitrate = 500_000;
+/// carousel.load_dir("/opt/hbbtv/app").unwrap();
+///
+/// let mut cc = 0;
+/// let mut ts = Vec::new();
+/// // called every 10ms
+/// carousel.fill(0x0800, &mut cc, 10, &mut ts).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ObjectCarousel {
+    /// Carousel version. Should be changed on each update of the files
+    pub version: u8,
+    /// Carousel identifier. Same value defined in the carousel_identifier_descriptor
+    pub carousel_id: u32,
+    /// Association tag of the carousel stream.
+    /// Same value defined in the stream_identifier_descriptor
+    pub association_tag: u16,
+    /// Size of the block data in the DownloadDataBlock.
+    /// Value is limited to `1 ..= DSMCC_BLOCK_SIZE`
+    pub block_size: u16,
+    /// Cycle bitrate in bits per second
+    pub bitrate: u32,
+
+    files: BTreeMap<String, Vec<u8>>,
+
+    /// TS packets for one cycle of the carousel
+    cycle: Vec<u8>,
+    /// Version, carousel_id, association_tag, and block_size used for the cycle
+    cycle_params: (u8, u32, u16, u16),
+    /// Position in the cycle
+    skip: usize,
+    /// Fractional part of the packets not sent in the previous call
+    remain: u64,
+}
+
+
+impl Default for ObjectCarousel {
+    fn default() -> Self {
+        ObjectCarousel {
+            version: 0,
+            carousel_id: 0,
+            association_tag: 0,
+            block_size: DSMCC_BLOCK_SIZE,
+            bitrate: 0,
+
+            files: BTreeMap::new(),
+
+            cycle: Vec::new(),
+            cycle_params: (0, 0, 0, 0),
+            skip: 0,
+            remain: 0,
+        }
+    }
+}
+
+
+/// BIOP object in the carousel
+#[derive(Debug)]
+struct Object {
+    path: String,
+    kind: ObjectKind,
+    key: u32,
+    module_id: u16,
+}
+
+
+#[derive(Debug, Default)]
+struct Module {
+    id: u16,
+    data: Vec<u8>,
+}
+
+
+#[inline]
+fn get_parent(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(x) => &path[.. x],
+        None => "",
+    }
+}
+
+
+#[inline]
+fn get_name(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(x) => &path[x + 1 ..],
+        None => path,
+    }
+}
+
+
+impl ObjectCarousel {
+    /// Appends file into the carousel.
+    /// `path` - path to the file relative to the carousel root
+    ///
+    /// Returns error if name of the file or directory in the path
+    /// is longer than 254 bytes, or if file does not fit into the module
+    /// with the current `block_size`
+    pub fn add_file<S: AsRef<str>>(&mut self, path: S, data: Vec<u8>) -> io::Result<()> {
+        let path = path.as_ref().trim_matches('/');
+        if path.is_empty() {
+            return Ok(());
+        }
+
+        if path.split('/').any(|name| name.len() > NAME_MAX_SIZE) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                format!("name is longer than {} bytes: {}", NAME_MAX_SIZE, path)));
+        }
+
+        // BIOP::FileMessage header
+        let mut header = Vec::new();
+        assemble_file(&mut header, 0, &[]);
+
+        if header.len() + data.len() > self.module_max_size() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                format!("file is larger than {} bytes: {}", self.module_max_size(), path)));
+        }
+
+        self.files.insert(path.to_owned(), data);
+        self.cycle.clear();
+        Ok(())
+    }
+
+    /// Removes all files from the carousel
+    pub fn clear(&mut self) {
+        self.files.clear();
+        self.cycle.clear();
+    }
+
+    /// Returns descriptors for the PMT elementary stream of the carousel:
+    /// stream identifier and carousel identifier descriptors
+    pub fn descriptors(&self) -> Descriptors {
+        let mut descriptors = Descriptors::default();
+        descriptors.push(Desc52 {
+            tag: self.association_tag as u8,
+        });
+        descriptors.push(Desc13 {
+            carousel_id: self.carousel_id,
+            format_id: CAROUSEL_FORMAT_STANDARD,
+            .. Default::default()
+        });
+        descriptors
+    }
+
+    /// Reads all files from the directory tree
+    pub fn load_dir<P: AsRef<Path>>(&mut self, root: P) -> io::Result<()> {
+        self.load_dir_with_prefix(root.as_ref(), "")
+    }
+
+    fn load_dir_with_prefix(&mut self, dir: &Path, prefix: &str) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            let path = if prefix.is_empty() {
+                name.into_owned()
+            } else {
+                format!("{}/{}", prefix, name)
+            };
+
+            if entry.file_type()?.is_dir() {
+                self.load_dir_with_prefix(&entry.path(), &path)?;
+            } else {
+                let data = fs::read(entry.path())?;
+                self.add_file(path, data)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns size of the block limited to `1 ..= DSMCC_BLOCK_SIZE`
+    #[inline]
+    fn block_size(&self) -> u16 {
+        self.block_size.clamp(1, DSMCC_BLOCK_SIZE)
+    }
+
+    /// Returns maximum size of the module with the current block size
+    #[inline]
+    fn module_max_size(&self) -> usize {
+        MODULE_MAX_BLOCKS * usize::from(self.block_size())
+    }
+
+    /// transactionId for DSI and DII: originator - network, version, identification
+    #[inline]
+    fn transaction_id(&self, identification: u16) -> u32 {
+        0x8000_0000 | (u32::from(self.version) << 16) | (u32::from(identification) << 1)
+    }
+
+    /// Builds list of the objects. First object is a Service Gateway
+    fn object_list(&self) -> Vec<Object> {
+        let mut dirs: Vec<&str> = Vec::new();
+        for path in self.files.keys() {
+            let mut parent = get_parent(path);
+            while ! parent.is_empty() && ! dirs.contains(&parent) {
+                dirs.push(parent);
+                parent = get_parent(parent);
+            }
+        }
+        dirs.sort();
+
+        let mut list = vec![Object {
+            path: String::new(),
+            kind: ObjectKind::ServiceGateway,
+            key: 0,
+            module_id: 0,
+        }];
+
+        for dir in dirs {
+            list.push(Object {
+                path: dir.to_owned(),
+                kind: ObjectKind::Directory,
+                key: list.len() as u32,
+                module_id: 0,
+            });
+        }
+
+        for path in self.files.keys() {
+            list.push(Object {
+                path: path.clone(),
+                kind: ObjectKind::File,
+                key: list.len() as u32,
+                module_id: 0,
+            });
+        }
+
+        list
+    }
+
+    fn assemble_object(&self, list: &[Object], object: &Object, buffer: &mut Vec<u8>) {
+        if object.kind == ObjectKind::File {
+            assemble_file(buffer, object.key, &self.files[&object.path]);
+            return;
+        }
+
+        let transaction_id = self.transaction_id(1);
+        let bindings: Vec<Binding> = list.iter()
+            .filter(|x| x.kind != ObjectKind::ServiceGateway && get_parent(&x.path) == object.path)
+            .map(|x| Binding {
+                name: get_name(&x.path),
+                kind: x.kind,
+                location: ObjectLocation {
+                    carousel_id: self.carousel_id,
+                    module_id: x.module_id,
+                    key: x.key,
+                    association_tag: self.association_tag,
+                    transaction_id,
+                },
+                content_size: match x.kind {
+                    ObjectKind::File => self.files[&x.path].len() as u64,
+                    _ => 0,
+                },
+            })
+            .collect();
+
+        assemble_directory(buffer, object.kind, object.key, &bindings);
+    }
+
+    /// Packs objects into modules
+    fn module_list(&self) -> (Vec<Object>, Vec<Module>) {
+        let mut list = self.object_list();
+
+        // Object size not depends on the module_id so assign modules with sizes
+        let mut buffer = Vec::new();
+        let size_list: Vec<usize> = list.iter().map(|object| {
+            buffer.clear();
+            self.assemble_object(&list, object, &mut buffer);
+            buffer.len()
+        }).collect();
+
+        let mut module_id: u16 = 1;
+        let mut module_size = 0;
+        for (object, size) in list.iter_mut().zip(size_list) {
+            if module_size != 0 && module_size + size > MODULE_SIZE {
+                module_id += 1;
+                module_size = 0;
+            }
+            module_size += size;
+            object.module_id = module_id;
+        }
+
+        let mut modules: Vec<Module> = Vec::new();
+        for object in &list {
+            if modules.last().map(|x| x.id) != Some(object.module_id) {
+                modules.push(Module {
+                    id: object.module_id,
+                    data: Vec::new(),
+                });
+            }
+            let module = modules.last_mut().unwrap();
+            self.assemble_object(&list, object, &mut module.data);
+        }
+
+        (list, modules)
+    }
+
+    fn psi_init(&self, table_id: u8, message_id: u16, transaction_id: u32) -> Psi {
+        let mut psi = Psi::new(table_id, MESSAGE_HEADER_SIZE, 0);
+        psi.buffer[3 ..].set_u16(transaction_id as u16);
+        // protocolDiscriminator, dsmccType
+        psi.buffer[8] = 0x11;
+        psi.buffer[9] = 0x03;
+        psi.buffer[10 ..].set_u16(message_id);
+        psi.buffer[12 ..].set_u32(transaction_id);
+        // reserved, adaptationLength
+        psi.buffer[16] = 0xFF;
+        psi.buffer[17] = 0x00;
+        psi
+    }
+
+    #[inline]
+    fn psi_finalize(psi: &mut Psi) {
+        let message_length = (psi.buffer.len() - MESSAGE_HEADER_SIZE) as u16;
+        psi.buffer[18 ..].set_u16(message_length);
+        psi.finalize();
+    }
+
+    /// DownloadServerInitiate with Service Gateway reference
+    fn assemble_dsi(&self, list: &[Object]) -> Psi {
+        let mut psi = self.psi_init(0x3B, 0x1006, self.transaction_id(0));
+
+        // serverId
+        psi.buffer.resize(MESSAGE_HEADER_SIZE + 20, 0xFF);
+        // compatibilityDescriptorLength, privateDataLength
+        let skip = psi.buffer.len();
+        psi.buffer.resize(skip + 4, 0x00);
+
+        let srg = &list[0];
+        assemble_ior(&mut psi.buffer, ObjectKind::ServiceGateway, &ObjectLocation {
+            carousel_id: self.carousel_id,
+            module_id: srg.module_id,
+            key: srg.key,
+            association_tag: self.association_tag,
+            transaction_id: self.transaction_id(1),
+        });
+        // downloadTaps_count, serviceContextList_count, userInfoLength
+        psi.buffer.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+
+        let private_data_length = (psi.buffer.len() - skip - 4) as u16;
+        psi.buffer[skip + 2 ..].set_u16(private_data_length);
+
+        Self::psi_finalize(&mut psi);
+        psi
+    }
+
+    /// DownloadInfoIndication with list of modules
+    fn assemble_dii(&self, modules: &[Module]) -> Psi {
+        let mut psi = self.psi_init(0x3B, 0x1002, self.transaction_id(1));
+
+        let skip = psi.buffer.len();
+        psi.buffer.resize(skip + 20, 0x00);
+        // downloadId
+        psi.buffer[skip ..].set_u32(self.carousel_id);
+        psi.buffer[skip + 4 ..].set_u16(self.block_size());
+        // windowSize, ackPeriod, tCDownloadWindow, tCDownloadScenario,
+        // compatibilityDescriptor length - zero
+        psi.buffer[skip + 18 ..].set_u16(modules.len() as u16);
+
+        for module in modules {
+            let skip = psi.buffer.len();
+            psi.buffer.resize(skip + 8 + 21, 0x00);
+            psi.buffer[skip ..].set_u16(module.id);
+            psi.buffer[skip + 2 ..].set_u32(module.data.len() as u32);
+            psi.buffer[skip + 6] = self.version;
+            psi.buffer[skip + 7] = 21;
+
+            // BIOP::ModuleInfo
+            let skip = skip + 8;
+            // moduleTimeOut, blockTimeOut
+            psi.buffer[skip ..].set_u32(0xFFFF_FFFF);
+            psi.buffer[skip + 4 ..].set_u32(0xFFFF_FFFF);
+            // minBlockTime
+            psi.buffer[skip + 8 ..].set_u32(0);
+            // taps_count
+            psi.buffer[skip + 12] = 1;
+            // id
+            psi.buffer[skip + 13 ..].set_u16(0);
+            psi.buffer[skip + 15 ..].set_u16(BIOP_OBJECT_USE);
+            psi.buffer[skip + 17 ..].set_u16(self.association_tag);
+            // selector_length, userInfoLength
+            psi.buffer[skip + 19] = 0;
+            psi.buffer[skip + 20] = 0;
+        }
+
+        // privateDataLength
+        psi.buffer.extend_from_slice(&[0x00, 0x00]);
+
+        Self::psi_finalize(&mut psi);
+        psi
+    }
+
+    /// DownloadDataBlock sections for the module
+    fn assemble_ddb(&self, module: &Module, psi_list: &mut Vec<Psi>) {
+        let block_size = usize::from(self.block_size());
+        let blocks = module.data.len().div_ceil(block_size);
+        let last_section_number = std::cmp::min(blocks - 1, 0xFF) as u8;
+
+        for (block_number, block) in module.data.chunks(block_size).take(MODULE_MAX_BLOCKS).enumerate() {
+            let mut psi = self.psi_init(0x3C, 0x1003, self.carousel_id);
+            psi.buffer[3 ..].set_u16(module.id);
+            psi.buffer[5] = 0xC0 | ((self.version << 1) & 0x3E) | 0x01;
+            psi.buffer[6] = block_number as u8;
+            psi.buffer[7] = last_section_number;
+
+            let skip = psi.buffer.len();
+            psi.buffer.resize(skip + 6, 0x00);
+            psi.buffer[skip ..].set_u16(module.id);
+            psi.buffer[skip + 2] = self.version;
+            psi.buffer[skip + 3] = 0xFF;
+            psi.buffer[skip + 4 ..].set_u16(block_number as u16);
+            psi.buffer.extend_from_slice(block);
+
+            Self::psi_finalize(&mut psi);
+            psi_list.push(psi);
+        }
+    }
+
+    /// Sends TS packets of the carousel cycle in amount required
+    /// to keep configured `bitrate` for the `duration` in milliseconds.
+    /// Carousel is repeated continuously. Cycle is rebuilt if files
+    /// or carousel parameters are changed.
+    /// Returns error if carousel could not be assembled
+    pub fn fill(&mut self, pid: u16, cc: &mut u8, duration: u64, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        let params = (self.version, self.carousel_id, self.association_tag, self.block_size);
+        if self.cycle.is_empty() || self.cycle_params != params {
+            let mut cycle_cc = 0;
+            let mut cycle = Vec::new();
+            self.demux(pid, &mut cycle_cc, &mut cycle)?;
+            self.cycle = cycle;
+            self.cycle_params = params;
+            self.skip = 0;
+        }
+
+        if self.cycle.is_empty() {
+            return Ok(());
+        }
+
+        let bits = u64::from(self.bitrate) * duration + self.remain;
+        let packet_bits = (ts::PACKET_SIZE * 8 * 1000) as u64;
+        let count = bits / packet_bits;
+        self.remain = bits % packet_bits;
+
+        for _ in 0 .. count {
+            let skip = dst.len();
+            dst.extend_from_slice(&self.cycle[self.skip .. self.skip + ts::PACKET_SIZE]);
+            ts::set_pid(&mut dst[skip ..], pid);
+            ts::set_cc(&mut dst[skip ..], *cc);
+            *cc = (*cc + 1) & 0x0F;
+
+            self.skip += ts::PACKET_SIZE;
+            if self.skip >= self.cycle.len() {
+                self.skip = 0;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+impl PsiDemux for ObjectCarousel {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        let (list, modules) = self.module_list();
+
+        let mut psi_list = vec![
+            self.assemble_dsi(&list),
+            self.assemble_dii(&modules),
+        ];
+
+        for module in &modules {
+            self.assemble_ddb(module, &mut psi_list);
+        }
+
+        psi_list
+    }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        let (_, modules) = self.module_list();
+
+        let size = self.assemble_dii(&modules).size - 4;
+        if size > DSMCC_SECTION_SIZE {
+            return Err(PsiError::ItemOverflow { size, limit: DSMCC_SECTION_SIZE });
+        }
+
+        for module in &modules {
+            let size = module.data.len();
+            if size > self.module_max_size() {
+                return Err(PsiError::ItemOverflow { size, limit: self.module_max_size() });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates carousel and converts one cycle into TS packets
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        self.validate()?;
+
+        for psi in self.psi_list_assemble().iter_mut() {
+            psi.pid = pid;
+            psi.cc = *cc;
+            psi.demux(dst);
+            *cc = psi.cc;
+        }
+
+        Ok(())
+    }
+}
@@ -13,8 +13,15 @@ use crate::{
         MJDTo,
         Psi,
         PsiDemux,
+        PsiError,
+        PSI_MAX_SECTIONS,
         Descriptors,
+        DescriptorContext,
+        Desc4D,
+        Desc4E,
+        Desc4F,
     },
+    textcode::lang,
 };
 
 
@@ -25,6 +32,14 @@ pub const EIT_PID: u16 = 0x0012;
 const EIT_SECTION_SIZE: usize = 4096 - 4;
 
 
+/// Section header size
+const EIT_HEADER_SIZE: usize = 14;
+
+
+/// Default repetition interval for the EIT present/following in milliseconds
+pub const EIT_PF_INTERVAL: u64 = 2000;
+
+
 /// EIT Item
 #[derive(Debug, Default, Clone)]
 pub struct EitItem {
@@ -60,7 +75,7 @@ impl EitItem {
         item.status = (slice[10] >> 5) & 0x07;
         item.ca_mode = (slice[10] >> 4) & 0x01;
 
-        item.descriptors.parse(&slice[12 ..]);
+        item.descriptors.parse_context(&slice[12 ..], DescriptorContext::Eit);
 
         item
     }
@@ -85,12 +100,55 @@ impl EitItem {
     fn size(&self) -> usize {
         12 + self.descriptors.size()
     }
+
+    /// Returns reference to the NVOD reference event
+    /// for the time-shifted event
+    #[inline]
+    pub fn reference_event(&self) -> Option<&Desc4F> {
+        self.descriptors.find::<Desc4F>()
+    }
+
+    fn find_short_event(&self, lang: &str) -> Option<&Desc4D> {
+        self.descriptors.find_all::<Desc4D>()
+            .find(|desc| lang::is_same(&desc.lang.to_string(), lang))
+            .or_else(|| self.descriptors.find::<Desc4D>())
+    }
+
+    /// Returns event name for the preferred language defined by ISO 639 code.
+    /// Falls back to the first short event descriptor
+    pub fn get_title(&self, lang: &str) -> Option<String> {
+        self.find_short_event(lang).map(|desc| desc.name.to_string())
+    }
+
+    /// Returns event short description for the preferred language
+    /// defined by ISO 639 code.
+    /// Falls back to the first short event descriptor
+    pub fn get_short_text(&self, lang: &str) -> Option<String> {
+        self.find_short_event(lang).map(|desc| desc.text.to_string())
+    }
+
+    /// Returns event description concatenated from the extended event
+    /// descriptors for the preferred language defined by ISO 639 code.
+    /// Falls back to the language of the first extended event descriptor
+    pub fn get_description(&self, lang: &str) -> Option<String> {
+        let lang = self.descriptors.find_all::<Desc4E>()
+            .map(|desc| desc.lang.to_string())
+            .find(|v| lang::is_same(v, lang))
+            .or_else(|| self.descriptors.find::<Desc4E>().map(|desc| desc.lang.to_string()))?;
+
+        let mut list: Vec<&Desc4E> = self.descriptors.find_all::<Desc4E>()
+            .filter(|desc| desc.lang.to_string() == lang)
+            .collect();
+        list.sort_by_key(|desc| desc.number);
+
+        Some(list.into_iter().map(|desc| desc.text.to_string()).collect())
+    }
 }
 
 
 /// Event Information Table provides information in chronological order
 /// regarding the events contained within each service.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Eit {
     /// identifies to which table the section belongs:
     /// * `0x4E` - actual TS, present/following event information
@@ -151,8 +209,23 @@ impl Eit {
         }
     }
 
+    /// Returns event by the event id
+    pub fn get_event(&self, event_id: u16) -> Option<&EitItem> {
+        self.items.iter().find(|item| item.event_id == event_id)
+    }
+
+    /// Returns reference event for the time-shifted event.
+    /// `self` should contain events of the NVOD reference service
+    pub fn resolve_reference_event(&self, item: &EitItem) -> Option<&EitItem> {
+        let reference = item.reference_event()?;
+        if reference.reference_service_id != self.pnr {
+            return None;
+        }
+        self.get_event(reference.reference_event_id)
+    }
+
     fn psi_init(&self) -> Psi {
-        let mut psi = Psi::new(self.table_id, 14, self.version);
+        let mut psi = Psi::new(self.table_id, EIT_HEADER_SIZE, self.version);
         psi.buffer[1] = 0xF0; // set reserved_future_use bit
         psi.buffer[3 ..].set_u16(self.pnr);
         psi.buffer[8 ..].set_u16(self.tsid);
@@ -305,19 +378,261 @@ impl PsiDemux for Eit {
         psi_list
     }
 
-    /// Converts PSI into TS packets
-    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) {
-        let mut psi_list = self.psi_list_assemble();
-        if psi_list.is_empty() {
-            return;
+    fn validate(&self) -> Result<(), PsiError> {
+        for item in &self.items {
+            item.descriptors.validate()?;
+            let size = EIT_HEADER_SIZE + item.size();
+            if size > EIT_SECTION_SIZE {
+                return Err(PsiError::ItemOverflow { size, limit: EIT_SECTION_SIZE });
+            }
         }
 
+        if (self.table_id == 0x4E || self.table_id == 0x4F) &&
+            self.items.len() > PSI_MAX_SECTIONS
+        {
+            return Err(PsiError::SectionOverflow);
+        }
+
+        Ok(())
+    }
+
+    /// Validates table and converts PSI into TS packets
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        self.validate()?;
+
+        let mut psi_list = self.psi_list_assemble();
         for psi in psi_list.iter_mut() {
             psi.pid = pid;
             psi.cc = *cc;
             psi.demux(dst);
             *cc = psi.cc;
         }
+
+        Ok(())
+    }
+}
+
+
+/// Generator of the EIT present/following for the actual TS (0x4E).
+/// Selects present and following events from the service schedule
+/// as the wall clock moves on.
+///
+/// Version is incremented on switching to the next event
+/// or on the schedule change.
+/// Present event has running_status `4` - running,
+/// following event has running_status `1` - not running.
+/// Section without event is emitted if present or following
+/// event is not defined in the schedule.
+#[derive(Debug)]
+pub struct EitPf {
+    /// EIT version
+    pub version: u8,
+    /// program number
+    pub pnr: u16,
+    /// transport stream identifier
+    pub tsid: u16,
+    /// identifying the network of the originating delivery system
+    pub onid: u16,
+    /// Repetition interval in milliseconds
+    pub interval: u64,
+
+    schedule: Vec<EitItem>,
+    present: Option<EitItem>,
+    following: Option<EitItem>,
+    /// Schedule changed and version should be incremented
+    changed: bool,
+    /// Time of the next repetition in milliseconds
+    next_time: u64,
+}
+
+
+impl EitPf {
+    pub fn new(pnr: u16, tsid: u16, onid: u16) -> Self {
+        EitPf {
+            version: 0,
+            pnr,
+            tsid,
+            onid,
+            interval: EIT_PF_INTERVAL,
+
+            schedule: Vec::new(),
+            present: None,
+            following: None,
+            changed: false,
+            next_time: 0,
+        }
+    }
+
+    /// Replaces service schedule
+    pub fn set_schedule(&mut self, mut schedule: Vec<EitItem>) {
+        schedule.sort_by_key(|item| item.start);
+        self.schedule = schedule;
+        self.changed = true;
+    }
+
+    #[inline]
+    pub fn get_schedule(&self) -> &[EitItem] { &self.schedule }
+
+    /// Returns current present event
+    #[inline]
+    pub fn present(&self) -> Option<&EitItem> { self.present.as_ref() }
+
+    /// Returns current following event
+    #[inline]
+    pub fn following(&self) -> Option<&EitItem> { self.following.as_ref() }
+
+    /// Selects present and following events for the `now` time in UTC.
+    /// Returns `true` if events or schedule has been changed
+    pub fn update(&mut self, now: u64) -> bool {
+        let present = self.schedule.iter()
+            .find(|item| item.start <= now && now < item.start + u64::from(item.duration))
+            .map(|item| EitItem { status: 4, .. item.clone() });
+        let following = self.schedule.iter()
+            .find(|item| item.start > now)
+            .map(|item| EitItem { status: 1, .. item.clone() });
+
+        let key = |item: &Option<EitItem>| item.as_ref().map(|v| (v.event_id, v.start, v.duration));
+        let changed = self.changed ||
+            key(&present) != key(&self.present) ||
+            key(&following) != key(&self.following);
+
+        self.present = present;
+        self.following = following;
+
+        if changed {
+            self.changed = false;
+            self.version = (self.version + 1) & 0x1F;
+        }
+
+        changed
+    }
+
+    /// Updates events for the time `now` in milliseconds and converts
+    /// EIT present/following into TS packets if repetition interval is
+    /// passed or events has been changed.
+    /// Returns `true` if packets are appended into `dst`,
+    /// or error if present or following event could not be assembled
+    pub fn process(&mut self, now: u64, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<bool, PsiError> {
+        let changed = self.update(now / 1000);
+        if ! changed && now < self.next_time {
+            return Ok(false);
+        }
+
+        self.next_time = now + self.interval;
+        self.demux(pid, cc, dst)?;
+        Ok(true)
+    }
+
+    fn psi_init(&self) -> Psi {
+        let mut psi = Psi::new(0x4E, EIT_HEADER_SIZE, self.version);
+        psi.buffer[1] = 0xF0; // set reserved_future_use bit
+        psi.buffer[3 ..].set_u16(self.pnr);
+        psi.buffer[8 ..].set_u16(self.tsid);
+        psi.buffer[10 ..].set_u16(self.onid);
+        // Segment_last_Section_number
+        psi.buffer[12] = 1;
+        // Last_table_id
+        psi.buffer[13] = 0x4E;
+        psi
+    }
+}
+
+
+impl PsiDemux for EitPf {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        pf_assemble(&self.psi_init(), [&self.present, &self.following])
+    }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        pf_validate([&self.present, &self.following])
+    }
+}
+
+
+/// Assembles present and following sections.
+/// Section without event is assembled if event is not defined
+fn pf_assemble(psi: &Psi, items: [&Option<EitItem>; 2]) -> Vec<Psi> {
+    let mut psi_list = Vec::new();
+
+    for item in items.iter() {
+        let mut psi = psi.clone();
+        if let Some(item) = item {
+            item.assemble(&mut psi.buffer);
+        }
+        psi_list.push(psi);
+    }
+
+    psi_list
+}
+
+
+fn pf_validate(items: [&Option<EitItem>; 2]) -> Result<(), PsiError> {
+    for item in items.iter().filter_map(|v| v.as_ref()) {
+        item.descriptors.validate()?;
+        let size = EIT_HEADER_SIZE + item.size();
+        if size > EIT_SECTION_SIZE {
+            return Err(PsiError::ItemOverflow { size, limit: EIT_SECTION_SIZE });
+        }
+    }
+
+    Ok(())
+}
+
+
+/// EIT present/following with events defined by the section number:
+/// section `0` - present event, section `1` - following event.
+/// Both sections are assembled, section without event
+/// is assembled if event is not defined
+#[derive(Debug, Default, Clone)]
+pub struct EitPfTable {
+    /// identifies to which table the section belongs:
+    /// * `0x4E` - actual TS, present/following event information
+    /// * `0x4F` - other TS, present/following event information
+    pub table_id: u8,
+    /// EIT version
+    pub version: u8,
+    /// program number
+    pub pnr: u16,
+    /// transport stream identifier
+    pub tsid: u16,
+    /// identifying the network of the originating delivery system
+    pub onid: u16,
+    /// present and following events
+    pub items: [Option<EitItem>; 2],
+}
+
+
+impl EitPfTable {
+    /// Returns present event
+    #[inline]
+    pub fn present(&self) -> Option<&EitItem> { self.items[0].as_ref() }
+
+    /// Returns following event
+    #[inline]
+    pub fn following(&self) -> Option<&EitItem> { self.items[1].as_ref() }
+
+    fn psi_init(&self) -> Psi {
+        let mut psi = Psi::new(self.table_id, EIT_HEADER_SIZE, self.version);
+        psi.buffer[1] = 0xF0; // set reserved_future_use bit
+        psi.buffer[3 ..].set_u16(self.pnr);
+        psi.buffer[8 ..].set_u16(self.tsid);
+        psi.buffer[10 ..].set_u16(self.onid);
+        // Segment_last_Section_number
+        psi.buffer[12] = 1;
+        // Last_table_id
+        psi.buffer[13] = self.table_id;
+        psi
+    }
+}
+
+
+impl PsiDemux for EitPfTable {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        pf_assemble(&self.psi_init(), [&self.items[0], &self.items[1]])
+    }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        pf_validate([&self.items[0], &self.items[1]])
     }
 }
 
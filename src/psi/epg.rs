@@ -0,0 +1,184 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::psi::{
+    Eit,
+    EitItem,
+};
+
+
+/// Returns sub-table identifier for EIT actual and other:
+/// `0x4E` for present/following, `0x50 ..= 0x5F` for schedule
+#[inline]
+fn sub_table_id(table_id: u8) -> u8 {
+    if (table_id & 0xFE) == 0x4E {
+        0x4E
+    } else {
+        0x50 | (table_id & 0x0F)
+    }
+}
+
+
+/// Returns bit of the sub-table in the `EpgEvent::tables`
+#[inline]
+fn sub_table_bit(table_id: u8) -> u32 {
+    if table_id == 0x4E {
+        1 << 16
+    } else {
+        1 << (table_id & 0x0F)
+    }
+}
+
+
+/// Event stored in the EPG service
+#[derive(Debug, Clone)]
+struct EpgEvent {
+    /// Bit mask of the sub-tables where event has been received
+    tables: u32,
+    item: EitItem,
+}
+
+
+/// Events of the single service
+#[derive(Debug, Default, Clone)]
+pub struct EpgService {
+    /// Original network identifier
+    pub onid: u16,
+    /// Transport stream identifier
+    pub tsid: u16,
+    /// Service identifier (pnr)
+    pub service_id: u16,
+    /// Sub-table identifier and version
+    versions: Vec<(u8, u8)>,
+    /// Events sorted by start time
+    events: Vec<EpgEvent>,
+}
+
+
+impl EpgService {
+    fn ingest(&mut self, eit: &Eit) {
+        let table_id = sub_table_id(eit.table_id);
+        let bit = sub_table_bit(table_id);
+
+        match self.versions.iter_mut().find(|(id, _)| *id == table_id) {
+            Some((_, version)) if *version == eit.version => {}
+            Some((_, version)) => {
+                *version = eit.version;
+                for event in &mut self.events {
+                    event.tables &= ! bit;
+                }
+                self.events.retain(|event| event.tables != 0);
+            }
+            None => self.versions.push((table_id, eit.version)),
+        }
+
+        for item in &eit.items {
+            match self.events.iter_mut().find(|v| v.item.event_id == item.event_id) {
+                Some(v) => {
+                    v.tables |= bit;
+                    v.item = item.clone();
+                }
+                None => self.events.push(EpgEvent {
+                    tables: bit,
+                    item: item.clone(),
+                }),
+            }
+        }
+
+        self.events.sort_by_key(|event| event.item.start);
+    }
+
+    /// Returns iterator over all events sorted by start time
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &EitItem> {
+        self.events.iter().map(|event| &event.item)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.events.is_empty() }
+
+    #[inline]
+    pub fn len(&self) -> usize { self.events.len() }
+
+    /// Returns event by the event id
+    pub fn get_event(&self, event_id: u16) -> Option<&EitItem> {
+        self.iter().find(|item| item.event_id == event_id)
+    }
+
+    /// Returns event running at the `now` time in UTC
+    pub fn present(&self, now: u64) -> Option<&EitItem> {
+        self.iter().find(|item| item.start <= now && now < item.start + u64::from(item.duration))
+    }
+
+    /// Returns first event that starts after the `now` time in UTC
+    pub fn following(&self, now: u64) -> Option<&EitItem> {
+        self.iter().find(|item| item.start > now)
+    }
+
+    /// Returns events overlapping time range from `start` to `end` in UTC
+    pub fn range(&self, start: u64, end: u64) -> impl Iterator<Item = &EitItem> {
+        self.iter().filter(move |item| item.start < end && item.start + u64::from(item.duration) > start)
+    }
+
+    /// Removes events that ended before the `now` time in UTC
+    pub fn expire(&mut self, now: u64) {
+        self.events.retain(|event| event.item.start + u64::from(event.item.duration) > now)
+    }
+}
+
+
+/// EPG database collects events from the EIT present/following and
+/// schedule sections, actual and other.
+/// Services are identified by the onid, tsid and service id.
+///
+/// Events with the same event id are replaced.
+/// Events are dropped if version of all sub-tables where event has been
+/// received is changed.
+#[derive(Debug, Default)]
+pub struct Epg {
+    services: Vec<EpgService>,
+}
+
+
+impl Epg {
+    /// Merges EIT section into the database
+    pub fn ingest(&mut self, eit: &Eit) {
+        let position = self.services.iter()
+            .position(|v| v.onid == eit.onid && v.tsid == eit.tsid && v.service_id == eit.pnr);
+        let service = match position {
+            Some(v) => &mut self.services[v],
+            None => {
+                self.services.push(EpgService {
+                    onid: eit.onid,
+                    tsid: eit.tsid,
+                    service_id: eit.pnr,
+                    .. Default::default()
+                });
+                self.services.last_mut().unwrap()
+            }
+        };
+
+        service.ingest(eit);
+    }
+
+    /// Returns service by the onid, tsid and service id
+    pub fn get_service(&self, onid: u16, tsid: u16, service_id: u16) -> Option<&EpgService> {
+        self.services.iter()
+            .find(|v| v.onid == onid && v.tsid == tsid && v.service_id == service_id)
+    }
+
+    /// Returns iterator over all services
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &EpgService> { self.services.iter() }
+
+    /// Removes events that ended before the `now` time in UTC
+    pub fn expire(&mut self, now: u64) {
+        for service in &mut self.services {
+            service.expire(now);
+        }
+    }
+}
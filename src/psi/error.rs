@@ -0,0 +1,42 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use std::{
+    fmt,
+    error,
+};
+
+
+/// Errors detected while validating tables before assembly
+#[derive(Debug, Clone, PartialEq)]
+pub enum PsiError {
+    /// Descriptor payload does not fit into the 8-bit descriptor_length.
+    /// `size` is a complete descriptor size with tag and length
+    DescriptorOverflow { tag: u8, size: usize },
+    /// Table item or table header does not fit into the single section
+    ItemOverflow { size: usize, limit: usize },
+    /// Table requires more sections than allowed:
+    /// 256, or one for the single section tables
+    SectionOverflow,
+}
+
+
+impl fmt::Display for PsiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PsiError::DescriptorOverflow { tag, size } => write!(f,
+                "descriptor 0x{:02X} size {} exceeds 257 bytes", tag, size),
+            PsiError::ItemOverflow { size, limit } => write!(f,
+                "item size {} exceeds section limit {}", size, limit),
+            PsiError::SectionOverflow => write!(f,
+                "table exceeds section limit"),
+        }
+    }
+}
+
+
+impl error::Error for PsiError {}
@@ -0,0 +1,89 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::psi::{
+    Descriptors,
+    Desc83,
+    Desc83i,
+    Desc83NorDig,
+    Desc87NorDig,
+    Desc88,
+};
+
+
+/// Effective logical channel number of the service
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Lcn {
+    /// Transport stream identifier
+    pub tsid: u16,
+    /// Original network identifier
+    pub onid: u16,
+    /// Service identifier (pnr)
+    pub service_id: u16,
+    /// Logical channel number
+    pub lcn: u16,
+    /// Service should be visible in the channel list
+    pub visible: bool,
+}
+
+
+impl Lcn {
+    /// Collects channel numbers from the NIT transport stream loop descriptors
+    /// for the country defined by ISO 3166 code.
+    ///
+    /// Channel numbers are overridden in the following order:
+    ///
+    /// 1. Logical channel descriptor - EACEM or NorDig version 1
+    /// 2. NorDig logical channel descriptor version 2 - list for the country.
+    ///    First list is used if country not found
+    /// 3. HD simulcast logical channel descriptor - only if `hd_simulcast`
+    ///    is `true`, for receivers capable of HD reception
+    pub fn from_descriptors(
+        tsid: u16,
+        onid: u16,
+        descriptors: &Descriptors,
+        country: &str,
+        hd_simulcast: bool) -> Vec<Lcn>
+    {
+        let mut result: Vec<Lcn> = Vec::new();
+
+        let mut set = |items: &[Desc83i]| {
+            for item in items {
+                let lcn = Lcn {
+                    tsid,
+                    onid,
+                    service_id: item.service_id,
+                    lcn: item.lcn,
+                    visible: item.visible != 0,
+                };
+                match result.iter_mut().find(|v| v.service_id == item.service_id) {
+                    Some(v) => *v = lcn,
+                    None => result.push(lcn),
+                }
+            }
+        };
+
+        for desc in descriptors.find_all::<Desc83>() {
+            set(&desc.items);
+        }
+        for desc in descriptors.find_all::<Desc83NorDig>() {
+            set(&desc.items);
+        }
+        for desc in descriptors.find_all::<Desc87NorDig>() {
+            if let Some(list) = desc.get_list(country).or_else(|| desc.items.first()) {
+                set(&list.items);
+            }
+        }
+        if hd_simulcast {
+            for desc in descriptors.find_all::<Desc88>() {
+                set(&desc.items);
+            }
+        }
+
+        result
+    }
+}
@@ -11,6 +11,7 @@ use crate::{
 };
 
 mod utils; pub use utils::*;
+mod error; pub use error::*;
 mod descriptors; pub use descriptors::*;
 
 mod pat; pub use pat::*;
@@ -20,6 +21,20 @@ mod nit; pub use nit::*;
 mod sdt; pub use sdt::*;
 mod tdt; pub use tdt::*;
 mod tot; pub use tot::*;
+mod rst; pub use rst::*;
+mod st; pub use st::*;
+mod sit; pub use sit::*;
+mod dit; pub use dit::*;
+mod dsmcc; pub use dsmcc::*;
+mod tuning; pub use tuning::*;
+mod crid; pub use crid::*;
+mod lcn; pub use lcn::*;
+mod network; pub use network::*;
+mod epg; pub use epg::*;
+
+
+/// Maximum number of sections in the table
+pub const PSI_MAX_SECTIONS: usize = 256;
 
 
 /// Program Specific Information includes normative data which is necessary for
@@ -189,7 +204,8 @@ impl Psi {
             self.size = self.buffer.len() + 4;
             self.buffer.resize(self.size, 0x00);
 
-            let x = (u16::from(self.buffer[1] & 0xF0) << 8) | ((self.size - 3) as u16);
+            let section_length = std::cmp::min(self.size - 3, 0x0FFF) as u16;
+            let x = (u16::from(self.buffer[1] & 0xF0) << 8) | section_length;
             self.buffer[1..].set_u16(x);
         }
 
@@ -255,17 +271,28 @@ impl Psi {
 
 /// Trait for PSI to demux into TS packets
 pub trait PsiDemux {
-    /// Build list of PSI tables
+    /// Build list of PSI tables.
+    /// Table should be checked with `validate` before
     fn psi_list_assemble(&self) -> Vec<Psi>;
 
     /// Finalize
     fn finalize(&self, _psi: &mut Psi) {}
 
-    /// Converts PSI into TS packets
-    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) {
+    /// Checks that table could be assembled without losing data
+    fn validate(&self) -> Result<(), PsiError> { Ok(()) }
+
+    /// Validates table and converts PSI into TS packets.
+    /// Nothing is appended into `dst` if table is not valid
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        self.validate()?;
+
         let mut psi_list = self.psi_list_assemble();
         if psi_list.is_empty() {
-            return;
+            return Ok(());
+        }
+
+        if psi_list.len() > PSI_MAX_SECTIONS {
+            return Err(PsiError::SectionOverflow);
         }
 
         let last_section_number = (psi_list.len() - 1) as u8;
@@ -279,5 +306,7 @@ pub trait PsiDemux {
             psi.demux(dst);
             *cc = psi.cc;
         }
+
+        Ok(())
     }
 }
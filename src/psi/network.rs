@@ -0,0 +1,323 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::psi::{
+    Descriptors,
+    Eit,
+    EitPfTable,
+    Nit,
+    NitItem,
+    Sdt,
+    SdtItem,
+};
+
+
+/// Network described by the NIT actual or other
+#[derive(Debug, Default, Clone)]
+pub struct Network {
+    /// Network identifier
+    pub network_id: u16,
+    /// NIT version
+    pub version: u8,
+    /// Network descriptors
+    pub descriptors: Descriptors,
+}
+
+
+/// Transport stream in the network database
+#[derive(Debug, Default, Clone)]
+pub struct NetworkTs {
+    /// Original network identifier
+    pub onid: u16,
+    /// Transport stream identifier
+    pub tsid: u16,
+    /// Identifier of the network that delivers transport stream
+    pub network_id: Option<u16>,
+    /// Transport stream descriptors from the NIT
+    pub descriptors: Descriptors,
+    /// SDT version
+    pub sdt_version: u8,
+    /// Services from the SDT actual or other
+    pub services: Vec<SdtItem>,
+    /// Present/following events of the services
+    pub eit_pf: Vec<EitPfTable>,
+    /// Schedule events of the services
+    pub eit: Vec<Eit>,
+}
+
+
+impl NetworkTs {
+    /// Returns service by the service id (pnr)
+    pub fn get_service(&self, pnr: u16) -> Option<&SdtItem> {
+        self.services.iter().find(|item| item.pnr == pnr)
+    }
+}
+
+
+/// Returns true for EIT actual and other present/following
+#[inline]
+fn is_eit_pf(table_id: u8) -> bool {
+    (table_id & 0xFE) == 0x4E
+}
+
+
+/// Network-wide service information collected from the several
+/// transport streams. Stores SDT actual and other (0x42, 0x46),
+/// NIT actual and other (0x40, 0x41) and EIT actual and other.
+/// Transport streams are identified by the onid and tsid.
+///
+/// Regenerates "other" tables for the target transport stream
+/// from the information of all other transport streams.
+#[derive(Debug, Default)]
+pub struct NetworkDb {
+    /// List of the networks
+    pub networks: Vec<Network>,
+    /// List of the transport streams
+    pub ts: Vec<NetworkTs>,
+}
+
+
+impl NetworkDb {
+    /// Returns transport stream by the onid and tsid
+    pub fn get_ts(&self, onid: u16, tsid: u16) -> Option<&NetworkTs> {
+        self.ts.iter().find(|ts| ts.onid == onid && ts.tsid == tsid)
+    }
+
+    /// Returns network by the network id
+    pub fn get_network(&self, network_id: u16) -> Option<&Network> {
+        self.networks.iter().find(|network| network.network_id == network_id)
+    }
+
+    fn ts_mut(&mut self, onid: u16, tsid: u16) -> &mut NetworkTs {
+        let position = self.ts.iter().position(|ts| ts.onid == onid && ts.tsid == tsid);
+        match position {
+            Some(v) => &mut self.ts[v],
+            None => {
+                self.ts.push(NetworkTs {
+                    onid,
+                    tsid,
+                    .. Default::default()
+                });
+                self.ts.last_mut().unwrap()
+            }
+        }
+    }
+
+    /// Merges SDT actual or other into the database.
+    /// Services with the same service id are replaced.
+    /// Previous services are dropped if SDT version is changed
+    pub fn ingest_sdt(&mut self, sdt: &Sdt) {
+        let ts = self.ts_mut(sdt.onid, sdt.tsid);
+        if ts.sdt_version != sdt.version {
+            ts.services.clear();
+            ts.sdt_version = sdt.version;
+        }
+
+        for item in &sdt.items {
+            match ts.services.iter_mut().find(|v| v.pnr == item.pnr) {
+                Some(v) => *v = item.clone(),
+                None => ts.services.push(item.clone()),
+            }
+        }
+    }
+
+    /// Merges NIT actual or other into the database.
+    /// Network descriptors are replaced. Transport streams
+    /// from the NIT are attached to the network.
+    /// Previous transport streams are detached from the network
+    /// if NIT version is changed. Detached transport streams without
+    /// services and events are removed
+    pub fn ingest_nit(&mut self, nit: &Nit) {
+        let network = Network {
+            network_id: nit.network_id,
+            version: nit.version,
+            descriptors: nit.descriptors.clone(),
+        };
+
+        let is_changed = match self.networks.iter_mut().find(|v| v.network_id == nit.network_id) {
+            Some(v) => {
+                let is_changed = v.version != nit.version;
+                // descriptors defined in the first section only
+                if is_changed || ! nit.descriptors.is_empty() {
+                    *v = network;
+                }
+                is_changed
+            }
+            None => {
+                self.networks.push(network);
+                false
+            }
+        };
+
+        if is_changed {
+            let network_id = Some(nit.network_id);
+            self.ts.retain(|ts| {
+                ts.network_id != network_id ||
+                ! ts.services.is_empty() ||
+                ! ts.eit_pf.is_empty() ||
+                ! ts.eit.is_empty()
+            });
+
+            for ts in self.ts.iter_mut().filter(|ts| ts.network_id == network_id) {
+                ts.network_id = None;
+                ts.descriptors = Descriptors::default();
+            }
+        }
+
+        for item in &nit.items {
+            let ts = self.ts_mut(item.onid, item.tsid);
+            ts.network_id = Some(nit.network_id);
+            ts.descriptors = item.descriptors.clone();
+        }
+    }
+
+    /// Merges EIT actual or other section into the database.
+    /// `section_number` - number of the section. For present/following
+    /// event of the section 0 stored as present and event of the
+    /// section 1 stored as following, section without event
+    /// clears event in this position.
+    /// Schedule events are stored for each table_id,
+    /// events with the same event id are replaced.
+    /// Previous events are dropped if version of the sub-table is changed
+    pub fn ingest_eit(&mut self, eit: &Eit, section_number: u8) {
+        let ts = self.ts_mut(eit.onid, eit.tsid);
+
+        if is_eit_pf(eit.table_id) {
+            let position = ts.eit_pf.iter().position(|v| v.pnr == eit.pnr);
+            let stored = match position {
+                Some(v) => &mut ts.eit_pf[v],
+                None => {
+                    ts.eit_pf.push(EitPfTable {
+                        table_id: 0x4E,
+                        version: eit.version,
+                        pnr: eit.pnr,
+                        tsid: eit.tsid,
+                        onid: eit.onid,
+                        items: [None, None],
+                    });
+                    ts.eit_pf.last_mut().unwrap()
+                }
+            };
+
+            if stored.version != eit.version {
+                stored.items = [None, None];
+                stored.version = eit.version;
+            }
+
+            if let Some(v) = stored.items.get_mut(usize::from(section_number)) {
+                *v = eit.items.first().cloned();
+            }
+
+            return;
+        }
+
+        let table_id = 0x50 | (eit.table_id & 0x0F);
+
+        let position = ts.eit.iter()
+            .position(|v| v.pnr == eit.pnr && v.table_id == table_id);
+        let stored = match position {
+            Some(v) => &mut ts.eit[v],
+            None => {
+                ts.eit.push(Eit {
+                    table_id,
+                    version: eit.version,
+                    pnr: eit.pnr,
+                    tsid: eit.tsid,
+                    onid: eit.onid,
+                    items: Vec::new(),
+                });
+                ts.eit.last_mut().unwrap()
+            }
+        };
+
+        if stored.version != eit.version {
+            stored.items.clear();
+            stored.version = eit.version;
+        }
+
+        for item in &eit.items {
+            match stored.items.iter_mut().find(|v| v.event_id == item.event_id) {
+                Some(v) => *v = item.clone(),
+                None => stored.items.push(item.clone()),
+            }
+        }
+
+        stored.items.sort_by_key(|v| v.start);
+    }
+
+    /// Returns SDT other (0x46) for all transport streams
+    /// except the target transport stream
+    pub fn sdt_other(&self, onid: u16, tsid: u16) -> Vec<Sdt> {
+        self.ts.iter()
+            .filter(|ts| ! (ts.onid == onid && ts.tsid == tsid))
+            .filter(|ts| ! ts.services.is_empty())
+            .map(|ts| Sdt {
+                table_id: 0x46,
+                version: ts.sdt_version,
+                onid: ts.onid,
+                tsid: ts.tsid,
+                items: ts.services.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns NIT other (0x41) for all networks
+    /// except the network of the target transport stream
+    pub fn nit_other(&self, network_id: u16) -> Vec<Nit> {
+        self.networks.iter()
+            .filter(|network| network.network_id != network_id)
+            .map(|network| Nit {
+                table_id: 0x41,
+                version: network.version,
+                network_id: network.network_id,
+                descriptors: network.descriptors.clone(),
+                items: self.ts.iter()
+                    .filter(|ts| ts.network_id == Some(network.network_id))
+                    .map(|ts| NitItem {
+                        tsid: ts.tsid,
+                        onid: ts.onid,
+                        descriptors: ts.descriptors.clone(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Returns EIT other present/following (0x4F) for all
+    /// transport streams except the target transport stream.
+    /// Both sections are regenerated, section without event
+    /// is kept in its position
+    pub fn eit_pf_other(&self, onid: u16, tsid: u16) -> Vec<EitPfTable> {
+        self.ts.iter()
+            .filter(|ts| ! (ts.onid == onid && ts.tsid == tsid))
+            .flat_map(|ts| ts.eit_pf.iter())
+            .filter(|eit| eit.items.iter().any(Option::is_some))
+            .map(|eit| EitPfTable {
+                table_id: 0x4F,
+                .. eit.clone()
+            })
+            .collect()
+    }
+
+    /// Returns EIT other schedule (0x60 ..= 0x6F) for all
+    /// transport streams except the target transport stream
+    pub fn eit_other(&self, onid: u16, tsid: u16) -> Vec<Eit> {
+        self.ts.iter()
+            .filter(|ts| ! (ts.onid == onid && ts.tsid == tsid))
+            .flat_map(|ts| ts.eit.iter())
+            .filter(|eit| ! eit.items.is_empty())
+            .map(|eit| Eit {
+                table_id: 0x60 | (eit.table_id & 0x0F),
+                version: eit.version,
+                pnr: eit.pnr,
+                tsid: eit.tsid,
+                onid: eit.onid,
+                items: eit.items.clone(),
+            })
+            .collect()
+    }
+}
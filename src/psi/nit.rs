@@ -10,8 +10,16 @@ use crate::{
     psi::{
         Psi,
         PsiDemux,
+        PsiError,
+        PSI_MAX_SECTIONS,
         Descriptors,
+        DescriptorContext,
+        Tuning,
+        Lcn,
+        Desc40,
+        Desc5B,
     },
+    textcode::StringDVB,
 };
 
 
@@ -22,8 +30,13 @@ pub const NIT_PID: u16 = 0x0010;
 const NIT_SECTION_SIZE: usize = 1024 - 4;
 
 
+/// Section header size with network_descriptors_length
+/// and transport_stream_loop_length
+const NIT_HEADER_SIZE: usize = 12;
+
+
 /// NIT Item.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct NitItem {
     /// Identifier which serves as a label for identification of this
     /// TS from any other multiplex within the delivery system.
@@ -42,7 +55,7 @@ impl NitItem {
         item.tsid = slice[0 ..].get_u16();
         item.onid = slice[2 ..].get_u16();
 
-        item.descriptors.parse(&slice[6 ..]);
+        item.descriptors.parse_context(&slice[6 ..], DescriptorContext::Nit);
 
         item
     }
@@ -62,6 +75,20 @@ impl NitItem {
     fn size(&self) -> usize {
         6 + self.descriptors.size()
     }
+
+    /// Returns tuning parameters of the transport stream
+    #[inline]
+    pub fn tuning(&self) -> Tuning {
+        Tuning::from_descriptors(self.tsid, self.onid, &self.descriptors)
+    }
+
+    /// Returns effective channel numbers of the services in the transport
+    /// stream for the country defined by ISO 3166 code.
+    /// HD simulcast channel numbers applied if `hd_simulcast` is `true`
+    #[inline]
+    pub fn lcn(&self, country: &str, hd_simulcast: bool) -> Vec<Lcn> {
+        Lcn::from_descriptors(self.tsid, self.onid, &self.descriptors, country, hd_simulcast)
+    }
 }
 
 
@@ -106,7 +133,7 @@ impl Nit {
         self.version = (psi.buffer[5] & 0x3E) >> 1;
 
         let descriptors_len = (psi.buffer[8 ..].get_u16() & 0x0FFF) as usize;
-        self.descriptors.parse(&psi.buffer[10 .. 10 + descriptors_len]);
+        self.descriptors.parse_context(&psi.buffer[10 .. 10 + descriptors_len], DescriptorContext::Nit);
 
         let ptr = &psi.buffer[12 + descriptors_len .. psi.size - 4];
         let mut skip = 0;
@@ -120,16 +147,49 @@ impl Nit {
         }
     }
 
-    fn psi_init(&self, first: bool) -> Psi {
+    /// Returns tuning parameters of all transport streams in the network
+    pub fn tuning(&self) -> Vec<Tuning> {
+        self.items.iter().map(NitItem::tuning).collect()
+    }
+
+    /// Returns effective channel numbers of all services in the network
+    /// for the country defined by ISO 3166 code.
+    /// HD simulcast channel numbers applied if `hd_simulcast` is `true`
+    pub fn lcn(&self, country: &str, hd_simulcast: bool) -> Vec<Lcn> {
+        self.items.iter().flat_map(|item| item.lcn(country, hd_simulcast)).collect()
+    }
+
+    /// Returns effective channel number of the service
+    /// for the country defined by ISO 3166 code.
+    /// HD simulcast channel number applied if `hd_simulcast` is `true`
+    pub fn get_lcn(
+        &self,
+        onid: u16,
+        tsid: u16,
+        service_id: u16,
+        country: &str,
+        hd_simulcast: bool) -> Option<Lcn>
+    {
+        self.items.iter()
+            .filter(|item| item.onid == onid && item.tsid == tsid)
+            .flat_map(|item| item.lcn(country, hd_simulcast))
+            .find(|item| item.service_id == service_id)
+    }
+
+    /// Returns network name for the preferred language defined by ISO 639 code.
+    /// Falls back to the name from the network name descriptor
+    pub fn get_name(&self, lang: &str) -> Option<&StringDVB> {
+        self.descriptors.find_all::<Desc5B>()
+            .find_map(|desc| desc.get_name(lang))
+            .or_else(|| self.descriptors.find::<Desc40>().map(|desc| &desc.name))
+    }
+
+    fn psi_init(&self, descriptors: &Descriptors) -> Psi {
         let mut psi = Psi::new(self.table_id, 10, self.version);
         psi.buffer[1] = 0xF0;  // set section_syntax_indicator and reserved bits
         psi.buffer[3 ..].set_u16(self.network_id);
-        if first {
-            let descriptors_len = self.descriptors.assemble(&mut psi.buffer) as u16;
-            psi.buffer[8 ..].set_u16(0xF000 | descriptors_len);
-        } else {
-            psi.buffer[8] = 0xF0;  //reserved
-        }
+        let descriptors_len = descriptors.assemble(&mut psi.buffer) as u16;
+        psi.buffer[8 ..].set_u16(0xF000 | descriptors_len);
         // transport_stream_loop_lengt
         psi.buffer.push(0x00);
         psi.buffer.push(0x00);
@@ -139,8 +199,14 @@ impl Nit {
 
 
 impl PsiDemux for Nit {
+    /// Network descriptors that do not fit into the first section
+    /// are placed into the following sections
     fn psi_list_assemble(&self) -> Vec<Psi> {
-        let mut psi_list = vec![self.psi_init(true)];
+        let mut psi_list: Vec<Psi> = self.descriptors
+            .split(NIT_SECTION_SIZE - NIT_HEADER_SIZE)
+            .iter()
+            .map(|descriptors| self.psi_init(descriptors))
+            .collect();
 
         for item in &self.items {
             {
@@ -151,7 +217,7 @@ impl PsiDemux for Nit {
                 }
             }
 
-            let mut psi = self.psi_init(false);
+            let mut psi = self.psi_init(&Descriptors::default());
             item.assemble(&mut psi.buffer);
             psi_list.push(psi);
         }
@@ -165,6 +231,24 @@ impl PsiDemux for Nit {
 
         psi_list
     }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        self.descriptors.validate()?;
+
+        for item in &self.items {
+            item.descriptors.validate()?;
+            let size = NIT_HEADER_SIZE + item.size();
+            if size > NIT_SECTION_SIZE {
+                return Err(PsiError::ItemOverflow { size, limit: NIT_SECTION_SIZE });
+            }
+        }
+
+        if self.psi_list_assemble().len() > PSI_MAX_SECTIONS {
+            return Err(PsiError::SectionOverflow);
+        }
+
+        Ok(())
+    }
 }
 
 
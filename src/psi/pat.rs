@@ -10,6 +10,8 @@ use crate::{
     psi::{
         Psi,
         PsiDemux,
+        PsiError,
+        PSI_MAX_SECTIONS,
     },
 };
 
@@ -22,6 +24,10 @@ pub const PAT_PID: u16 = 0x0000;
 const PAT_SECTION_SIZE: usize = 1024 - 4;
 
 
+/// Section header size
+const PAT_HEADER_SIZE: usize = 8;
+
+
 /// PAT Item
 #[derive(Debug, Default)]
 pub struct PatItem {
@@ -79,38 +85,70 @@ impl Pat {
         // TODO: check if PSI already parsed
     }
 
-    /// Reads PSI packet and append data into the `Pat`
+    /// Reads PSI packet and merges data into the `Pat`.
+    /// Items with the same program number are replaced.
+    /// Previous items are dropped if tsid or version is changed
     pub fn parse(&mut self, psi: &Psi) {
         if ! self.check(&psi) {
             return;
         }
 
-        self.tsid = psi.buffer[3 ..].get_u16();
-        self.version = (psi.buffer[5] & 0x3E) >> 1;
+        let tsid = psi.buffer[3 ..].get_u16();
+        let version = (psi.buffer[5] & 0x3E) >> 1;
+        if tsid != self.tsid || version != self.version {
+            self.items.clear();
+        }
+
+        self.tsid = tsid;
+        self.version = version;
 
-        let ptr = &psi.buffer[8 .. psi.size - 4];
+        let ptr = &psi.buffer[PAT_HEADER_SIZE .. psi.size - 4];
         let mut skip = 0;
         while ptr.len() >= skip + 4 {
-            self.items.push(PatItem::parse(&ptr[skip .. skip + 4]));
+            let item = PatItem::parse(&ptr[skip .. skip + 4]);
+            match self.items.iter_mut().find(|x| x.pnr == item.pnr) {
+                Some(v) => v.pid = item.pid,
+                None => self.items.push(item),
+            }
             skip += 4;
         }
     }
+
+    fn psi_init(&self) -> Psi {
+        let mut psi = Psi::new(0x00, PAT_HEADER_SIZE, self.version);
+        psi.buffer[3 ..].set_u16(self.tsid);
+        psi
+    }
 }
 
 
 impl PsiDemux for Pat {
     fn psi_list_assemble(&self) -> Vec<Psi> {
-        let mut psi = Psi::new(0x00, 8, self.version);
-        psi.buffer[3 ..].set_u16(self.tsid);
+        let mut psi_list = vec![self.psi_init()];
 
         for item in &self.items {
-            if psi.buffer.len() + item.size() > PAT_SECTION_SIZE {
-                break;
+            {
+                let psi = psi_list.last_mut().unwrap();
+                if PAT_SECTION_SIZE >= psi.buffer.len() + item.size() {
+                    item.assemble(&mut psi.buffer);
+                    continue;
+                }
             }
+
+            let mut psi = self.psi_init();
             item.assemble(&mut psi.buffer);
+            psi_list.push(psi);
+        }
+
+        psi_list
+    }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        if self.psi_list_assemble().len() > PSI_MAX_SECTIONS {
+            return Err(PsiError::SectionOverflow);
         }
 
-        vec![psi]
+        Ok(())
     }
 }
 
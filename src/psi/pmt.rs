@@ -10,7 +10,19 @@ use crate::{
     psi::{
         Psi,
         PsiDemux,
+        PsiError,
+        PSI_MAX_SECTIONS,
         Descriptors,
+        DescriptorContext,
+        Desc05,
+        Desc86,
+        Desc86i,
+        FORMAT_HEVC,
+        FORMAT_OPUS,
+        FORMAT_KLVA,
+        FORMAT_BSSD,
+        FORMAT_AC3,
+        FORMAT_EAC3,
     },
     es::StreamType,
 };
@@ -20,6 +32,10 @@ use crate::{
 const PMT_SECTION_SIZE: usize = 1024 - 4;
 
 
+/// Section header size with program_info_length
+const PMT_HEADER_SIZE: usize = 12;
+
+
 /// PMT Item.
 #[derive(Debug, Default)]
 pub struct PmtItem {
@@ -41,7 +57,7 @@ impl PmtItem {
         item.stream_type = slice[0];
         item.pid = slice[1 ..].get_u16() & 0x1FFF;
 
-        item.descriptors.parse(&slice[5 ..]);
+        item.descriptors.parse_context(&slice[5 ..], DescriptorContext::Pmt);
 
         item
     }
@@ -62,6 +78,26 @@ impl PmtItem {
         5 + self.descriptors.size()
     }
 
+    /// Returns closed caption services carried in the video stream
+    /// defined with ATSC caption service descriptor
+    pub fn captions(&self) -> impl Iterator<Item = &Desc86i> {
+        self.descriptors.find_all::<Desc86>().flat_map(|desc| desc.items.iter())
+    }
+
+    /// Returns stream type defined with registration descriptor
+    fn get_registered_type(&self) -> Option<StreamType> {
+        let desc = self.descriptors.find::<Desc05>()?;
+        match desc.format_identifier {
+            FORMAT_HEVC => Some(StreamType::VIDEO),
+            FORMAT_OPUS => Some(StreamType::AUDIO),
+            FORMAT_BSSD => Some(StreamType::AUDIO),
+            FORMAT_AC3 => Some(StreamType::AUDIO),
+            FORMAT_EAC3 => Some(StreamType::AUDIO),
+            FORMAT_KLVA => Some(StreamType::DATA),
+            _ => None,
+        }
+    }
+
     pub fn get_stream_type(&self) -> StreamType {
         match self.stream_type {
             // Video
@@ -85,18 +121,25 @@ impl PmtItem {
                 StreamType::DATA
             }
             0x06 => {
+                if let Some(stream_type) = self.get_registered_type() {
+                    return stream_type;
+                }
                 for desc in self.descriptors.iter() {
                     match desc.tag() {
                         0x56 => return StreamType::TTX,     // teletext_descriptor
                         0x59 => return StreamType::SUB,     // subtitling_descriptor
                         0x6A => return StreamType::AUDIO,   // AC-3_descriptor
                         0x7A => return StreamType::AUDIO,   // enhanced_AC-3_descriptor
+                        0x7B => return StreamType::AUDIO,   // DTS_descriptor
+                        0x7C => return StreamType::AUDIO,   // AAC_descriptor
                         0x81 => return StreamType::AUDIO,   // AC-3 Audio
                         _ => {}
                     }
                 }
                 StreamType::DATA
             }
+            // User Private
+            0x80 ..= 0xFF => self.get_registered_type().unwrap_or(StreamType::DATA),
             _ => StreamType::DATA,
         }
     }
@@ -138,7 +181,7 @@ impl Pmt {
         self.pcr = psi.buffer[8 ..].get_u16() & 0x1FFF;
 
         let descriptors_len = (psi.buffer[10 ..].get_u16() & 0x0FFF) as usize;
-        self.descriptors.parse(&psi.buffer[11 .. 11 + descriptors_len]);
+        self.descriptors.parse_context(&psi.buffer[11 .. 11 + descriptors_len], DescriptorContext::Pmt);
 
         let ptr = &psi.buffer[12 + descriptors_len .. psi.size - 4];
         let mut skip = 0;
@@ -153,7 +196,7 @@ impl Pmt {
     }
 
     fn psi_init(&self, first: bool) -> Psi {
-        let mut psi = Psi::new(0x02, 12, self.version);
+        let mut psi = Psi::new(0x02, PMT_HEADER_SIZE, self.version);
         psi.buffer[3 ..].set_u16(self.pnr);
         psi.buffer[8 ..].set_u16(0xE000 | self.pcr);
         if first {
@@ -187,6 +230,28 @@ impl PsiDemux for Pmt {
 
         psi_list
     }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        self.descriptors.validate()?;
+        let size = PMT_HEADER_SIZE + self.descriptors.size();
+        if size > PMT_SECTION_SIZE {
+            return Err(PsiError::ItemOverflow { size, limit: PMT_SECTION_SIZE });
+        }
+
+        for item in &self.items {
+            item.descriptors.validate()?;
+            let size = PMT_HEADER_SIZE + item.size();
+            if size > PMT_SECTION_SIZE {
+                return Err(PsiError::ItemOverflow { size, limit: PMT_SECTION_SIZE });
+            }
+        }
+
+        if self.psi_list_assemble().len() > PSI_MAX_SECTIONS {
+            return Err(PsiError::SectionOverflow);
+        }
+
+        Ok(())
+    }
 }
 
 
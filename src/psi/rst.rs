@@ -0,0 +1,166 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    psi::{
+        Psi,
+        PsiDemux,
+        PsiError,
+    },
+};
+
+
+/// TS Packet Identifier for RST
+pub const RST_PID: u16 = 0x0013;
+
+
+/// Maximum section length
+const RST_SECTION_SIZE: usize = 1024;
+
+
+/// Section header size
+const RST_HEADER_SIZE: usize = 3;
+
+
+/// RST Item
+#[derive(Debug, Default, Clone)]
+pub struct RstItem {
+    /// Transport stream identifier
+    pub tsid: u16,
+    /// Identifying the network of the originating delivery system
+    pub onid: u16,
+    /// Program number
+    pub pnr: u16,
+    /// Event identification number
+    pub event_id: u16,
+    /// Indicating the status of the event
+    /// * `0` - undefined
+    /// * `1` - not running
+    /// * `2` - starts in a few seconds (e.g. for video recording)
+    /// * `3` - pausing
+    /// * `4` - running
+    /// * `5` - service off-air
+    pub running_status: u8,
+}
+
+
+impl RstItem {
+    fn parse(slice: &[u8]) -> Self {
+        RstItem {
+            tsid: slice[0 ..].get_u16(),
+            onid: slice[2 ..].get_u16(),
+            pnr: slice[4 ..].get_u16(),
+            event_id: slice[6 ..].get_u16(),
+            running_status: slice[8] & 0x07,
+        }
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + 9, 0x00);
+
+        buffer[skip ..].set_u16(self.tsid);
+        buffer[skip + 2 ..].set_u16(self.onid);
+        buffer[skip + 4 ..].set_u16(self.pnr);
+        buffer[skip + 6 ..].set_u16(self.event_id);
+        buffer[skip + 8] = 0xF8 | (self.running_status & 0x07);
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        9
+    }
+}
+
+
+/// Running Status Table allows rapid updating of the timing status
+/// of one or more events.
+///
+/// EN 300 468 - 5.2.7
+#[derive(Default, Debug)]
+pub struct Rst {
+    /// List of RST items
+    pub items: Vec<RstItem>,
+}
+
+
+impl Rst {
+    #[inline]
+    fn check(&self, psi: &Psi) -> bool {
+        psi.size >= 3 &&
+        psi.buffer.len() >= psi.size &&
+        psi.buffer[0] == 0x71
+    }
+
+    /// Reads PSI packet and append data into the `Rst`
+    pub fn parse(&mut self, psi: &Psi) {
+        if ! self.check(psi) {
+            return;
+        }
+
+        let ptr = &psi.buffer[3 .. psi.size];
+        let mut skip = 0;
+        while ptr.len() >= skip + 9 {
+            self.items.push(RstItem::parse(&ptr[skip .. skip + 9]));
+            skip += 9;
+        }
+    }
+}
+
+
+impl PsiDemux for Rst {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        let mut psi_list = vec![Psi::new(0x71, RST_HEADER_SIZE, 0)];
+
+        for item in &self.items {
+            if psi_list.last().unwrap().buffer.len() + item.size() > RST_SECTION_SIZE {
+                psi_list.push(Psi::new(0x71, RST_HEADER_SIZE, 0));
+            }
+            item.assemble(&mut psi_list.last_mut().unwrap().buffer);
+        }
+
+        psi_list
+    }
+
+    /// Items are split into several sections,
+    /// so each item should fit into the single section
+    fn validate(&self) -> Result<(), PsiError> {
+        for item in &self.items {
+            let size = RST_HEADER_SIZE + item.size();
+            if size > RST_SECTION_SIZE {
+                return Err(PsiError::ItemOverflow { size, limit: RST_SECTION_SIZE });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        self.validate()?;
+
+        for psi in self.psi_list_assemble().iter_mut() {
+            psi.size = psi.buffer.len();
+            psi.buffer[1 ..].set_u16(0x7000 | (psi.size - 3) as u16);
+            psi.pid = pid;
+            psi.cc = *cc;
+            psi.demux(dst);
+            *cc = psi.cc;
+        }
+
+        Ok(())
+    }
+}
+
+
+impl From<&Psi> for Rst {
+    fn from(psi: &Psi) -> Self {
+        let mut rst = Rst::default();
+        rst.parse(psi);
+        rst
+    }
+}
@@ -10,8 +10,18 @@ use crate::{
     psi::{
         Psi,
         PsiDemux,
+        PsiError,
+        PSI_MAX_SECTIONS,
         Descriptors,
+        DescriptorContext,
+        Desc48,
+        Desc49,
+        Desc4B,
+        Desc4Bi,
+        Desc4C,
+        Desc5D,
     },
+    textcode::StringDVB,
 };
 
 
@@ -22,8 +32,12 @@ pub const SDT_PID: u16 = 0x0011;
 const SDT_SECTION_SIZE: usize = 1024 - 4;
 
 
+/// Section header size
+const SDT_HEADER_SIZE: usize = 11;
+
+
 /// SDT item.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SdtItem {
     /// Program number.
     pub pnr: u16,
@@ -50,7 +64,7 @@ impl SdtItem {
         item.running_status = (slice[3] >> 5) & 0x07;
         item.free_ca_mode = (slice[3] >> 4) & 0x01;
 
-        item.descriptors.parse(&slice[5 ..]);
+        item.descriptors.parse_context(&slice[5 ..], DescriptorContext::Sdt);
 
         item
     }
@@ -71,6 +85,50 @@ impl SdtItem {
     fn size(&self) -> usize {
         5 + self.descriptors.size()
     }
+
+    /// Returns service name for the preferred language defined by ISO 639 code.
+    /// Falls back to the name from the service descriptor
+    pub fn get_name(&self, lang: &str) -> Option<&StringDVB> {
+        self.descriptors.find_all::<Desc5D>()
+            .find_map(|desc| desc.get_name(lang))
+            .or_else(|| self.descriptors.find::<Desc48>().map(|desc| &desc.name))
+    }
+
+    /// Returns service provider name for the preferred language defined by
+    /// ISO 639 code. Falls back to the provider from the service descriptor
+    pub fn get_provider(&self, lang: &str) -> Option<&StringDVB> {
+        self.descriptors.find_all::<Desc5D>()
+            .find_map(|desc| desc.get_provider(lang))
+            .or_else(|| self.descriptors.find::<Desc48>().map(|desc| &desc.provider))
+    }
+
+    /// Returns `true` if service is intended for reception in the country
+    /// defined by ISO 3166 code. Service without country availability
+    /// descriptors is available everywhere
+    pub fn is_available(&self, country: &str) -> bool {
+        let mut result = true;
+        for desc in self.descriptors.find_all::<Desc49>() {
+            if desc.contains(country) {
+                return desc.country_availability_flag;
+            }
+            if desc.country_availability_flag {
+                result = false;
+            }
+        }
+        result
+    }
+
+    /// Returns list of the time-shifted services for the NVOD reference service
+    pub fn nvod_services(&self) -> impl Iterator<Item = &Desc4Bi> {
+        self.descriptors.find_all::<Desc4B>().flat_map(|desc| desc.items.iter())
+    }
+
+    /// Returns service id of the NVOD reference service
+    /// for the time-shifted service
+    #[inline]
+    pub fn reference_service_id(&self) -> Option<u16> {
+        self.descriptors.find::<Desc4C>().map(|desc| desc.reference_service_id)
+    }
 }
 
 
@@ -125,8 +183,18 @@ impl Sdt {
         }
     }
 
+    /// Returns service by the service id (pnr)
+    pub fn get_service(&self, pnr: u16) -> Option<&SdtItem> {
+        self.items.iter().find(|item| item.pnr == pnr)
+    }
+
+    /// Returns NVOD reference service for the time-shifted service
+    pub fn resolve_reference_service(&self, item: &SdtItem) -> Option<&SdtItem> {
+        item.reference_service_id().and_then(|pnr| self.get_service(pnr))
+    }
+
     fn psi_init(&self) -> Psi {
-        let mut psi = Psi::new(self.table_id, 11, self.version);
+        let mut psi = Psi::new(self.table_id, SDT_HEADER_SIZE, self.version);
         psi.buffer[1] = 0xF0;  // set section_syntax_indicator and reserved bits
         psi.buffer[3 ..].set_u16(self.tsid);
         psi.buffer[8 ..].set_u16(self.onid);
@@ -156,6 +224,22 @@ impl PsiDemux for Sdt {
 
         psi_list
     }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        for item in &self.items {
+            item.descriptors.validate()?;
+            let size = SDT_HEADER_SIZE + item.size();
+            if size > SDT_SECTION_SIZE {
+                return Err(PsiError::ItemOverflow { size, limit: SDT_SECTION_SIZE });
+            }
+        }
+
+        if self.psi_list_assemble().len() > PSI_MAX_SECTIONS {
+            return Err(PsiError::SectionOverflow);
+        }
+
+        Ok(())
+    }
 }
 
 
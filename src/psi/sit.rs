@@ -0,0 +1,207 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    psi::{
+        Psi,
+        PsiDemux,
+        PsiError,
+        Descriptors,
+        DescriptorContext,
+        Desc63,
+        Pat,
+        Sdt,
+    },
+};
+
+
+/// TS Packet Identifier for SIT
+pub const SIT_PID: u16 = 0x001F;
+
+
+/// Maximum section length without CRC
+const SIT_SECTION_SIZE: usize = 1024 - 4;
+
+
+/// Section header size with transmission_info_loop_length
+const SIT_HEADER_SIZE: usize = 10;
+
+
+/// SIT Item
+#[derive(Debug, Default)]
+pub struct SitItem {
+    /// Program number
+    pub pnr: u16,
+    /// Indicating the status of the service
+    pub running_status: u8,
+    /// List of descriptors
+    pub descriptors: Descriptors,
+}
+
+
+impl SitItem {
+    fn parse(slice: &[u8]) -> Self {
+        let mut item = SitItem {
+            pnr: slice[0 ..].get_u16(),
+            running_status: (slice[2] >> 4) & 0x07,
+            descriptors: Descriptors::default(),
+        };
+
+        item.descriptors.parse_context(&slice[4 ..], DescriptorContext::Sit);
+
+        item
+    }
+
+    fn assemble(&self, buffer: &mut Vec<u8>) {
+        let skip = buffer.len();
+        buffer.resize(skip + 4, 0x00);
+
+        buffer[skip ..].set_u16(self.pnr);
+
+        let descriptors_len = self.descriptors.assemble(buffer) as u16;
+        buffer[skip + 2 ..].set_u16(
+            0x8000 |
+            (u16::from(self.running_status & 0x07) << 12) |
+            (descriptors_len & 0x0FFF));
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        4 + self.descriptors.size()
+    }
+}
+
+
+/// Selection Information Table describes the service(s) and event(s)
+/// carried by the partial transport stream.
+///
+/// EN 300 468 - 7.1.2
+#[derive(Debug, Default)]
+pub struct Sit {
+    /// SIT version
+    pub version: u8,
+    /// Transmission info descriptors. Should contain
+    /// partial_transport_stream_descriptor
+    pub descriptors: Descriptors,
+    /// List of SIT items
+    pub items: Vec<SitItem>,
+}
+
+
+impl Sit {
+    #[inline]
+    fn check(&self, psi: &Psi) -> bool {
+        psi.size >= 10 + 4 &&
+        psi.buffer[0] == 0x7F &&
+        psi.check()
+    }
+
+    pub fn parse(&mut self, psi: &Psi) {
+        if ! self.check(psi) {
+            return;
+        }
+
+        self.version = (psi.buffer[5] & 0x3E) >> 1;
+
+        let descriptors_len = (psi.buffer[8 ..].get_u16() & 0x0FFF) as usize;
+        if 10 + descriptors_len > psi.size - 4 {
+            return;
+        }
+
+        self.descriptors.parse_context(&psi.buffer[10 .. 10 + descriptors_len], DescriptorContext::Sit);
+
+        let ptr = &psi.buffer[10 + descriptors_len .. psi.size - 4];
+        let mut skip = 0;
+        while ptr.len() >= skip + 4 {
+            let item_len = 4 + (ptr[skip + 2 ..].get_u16() & 0x0FFF) as usize;
+            if skip + item_len > ptr.len() {
+                break;
+            }
+            self.items.push(SitItem::parse(&ptr[skip .. skip + item_len]));
+            skip += item_len;
+        }
+    }
+
+    /// Builds SIT for the partial TS with selected programs.
+    /// Programs not defined in the `pat` are skipped.
+    /// Service running status and descriptors copied from the `sdt`.
+    /// `peak_rate` - maximum bitrate of the partial TS in units of 400 bit/s
+    pub fn from_source(pat: &Pat, sdt: &Sdt, pnr_list: &[u16], peak_rate: u32) -> Self {
+        let mut sit = Sit::default();
+
+        sit.descriptors.push(Desc63 {
+            peak_rate,
+            minimum_overall_smoothing_rate: 0x003F_FFFF,
+            maximum_overall_smoothing_buffer: 0x3FFF,
+        });
+
+        for &pnr in pnr_list {
+            if pnr == 0 || ! pat.items.iter().any(|x| x.pnr == pnr) {
+                continue;
+            }
+
+            let mut item = SitItem {
+                pnr,
+                .. Default::default()
+            };
+
+            if let Some(sdt_item) = sdt.items.iter().find(|x| x.pnr == pnr) {
+                item.running_status = sdt_item.running_status;
+                item.descriptors = sdt_item.descriptors.clone();
+            }
+
+            sit.items.push(item);
+        }
+
+        sit
+    }
+}
+
+
+impl PsiDemux for Sit {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        let mut psi = Psi::new(0x7F, 10, self.version);
+        psi.buffer[1] = 0xF0;  // set section_syntax_indicator and reserved bits
+        psi.buffer[3 ..].set_u16(0xFFFF);  // reserved_future_use and reserved
+
+        let descriptors_len = self.descriptors.assemble(&mut psi.buffer) as u16;
+        psi.buffer[8 ..].set_u16(0xF000 | (descriptors_len & 0x0FFF));
+
+        for item in &self.items {
+            item.assemble(&mut psi.buffer);
+        }
+
+        vec![psi]
+    }
+
+    /// SIT is limited to the single section
+    fn validate(&self) -> Result<(), PsiError> {
+        self.descriptors.validate()?;
+        for item in &self.items {
+            item.descriptors.validate()?;
+        }
+
+        let size = SIT_HEADER_SIZE +
+            self.descriptors.size() +
+            self.items.iter().map(SitItem::size).sum::<usize>();
+        if size > SIT_SECTION_SIZE {
+            return Err(PsiError::SectionOverflow);
+        }
+
+        Ok(())
+    }
+}
+
+
+impl From<&Psi> for Sit {
+    fn from(psi: &Psi) -> Self {
+        let mut sit = Sit::default();
+        sit.parse(psi);
+        sit
+    }
+}
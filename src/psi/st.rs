@@ -0,0 +1,92 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::{
+    bytes::*,
+    psi::{
+        Psi,
+        PsiDemux,
+        PsiError,
+    },
+};
+
+
+/// Maximum number of the stuffing bytes in the section
+const ST_DATA_SIZE: usize = 4096 - 3;
+
+
+/// Stuffing Table may be used to replace or invalidate either
+/// sub-tables or complete SI tables.
+///
+/// EN 300 468 - 5.2.8
+#[derive(Default, Debug)]
+pub struct St {
+    /// Number of the stuffing bytes
+    pub size: usize,
+}
+
+
+impl St {
+    #[inline]
+    fn check(&self, psi: &Psi) -> bool {
+        psi.size >= 3 &&
+        psi.buffer.len() >= psi.size &&
+        psi.buffer[0] == 0x72
+    }
+
+    pub fn parse(&mut self, psi: &Psi) {
+        if ! self.check(psi) {
+            return;
+        }
+
+        self.size = psi.size - 3;
+    }
+}
+
+
+impl PsiDemux for St {
+    fn psi_list_assemble(&self) -> Vec<Psi> {
+        let size = std::cmp::min(self.size, ST_DATA_SIZE);
+
+        let mut psi = Psi::new(0x72, 3, 0);
+        psi.buffer[1 ..].set_u16(0x7000 | size as u16);
+        psi.buffer.resize(3 + size, 0xFF);
+
+        vec![psi]
+    }
+
+    fn validate(&self) -> Result<(), PsiError> {
+        if self.size > ST_DATA_SIZE {
+            return Err(PsiError::ItemOverflow { size: self.size, limit: ST_DATA_SIZE });
+        }
+
+        Ok(())
+    }
+
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        self.validate()?;
+
+        let mut psi_list = self.psi_list_assemble();
+        let psi = psi_list.first_mut().unwrap();
+        psi.pid = pid;
+        psi.cc = *cc;
+        psi.size = psi.buffer.len();
+        psi.demux(dst);
+        *cc = psi.cc;
+
+        Ok(())
+    }
+}
+
+
+impl From<&Psi> for St {
+    fn from(psi: &Psi) -> Self {
+        let mut st = St::default();
+        st.parse(psi);
+        st
+    }
+}
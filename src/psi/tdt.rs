@@ -13,6 +13,7 @@ use crate::{
         MJDTo,
         Psi,
         PsiDemux,
+        PsiError,
     },
 };
 
@@ -60,7 +61,7 @@ impl PsiDemux for Tdt {
         vec![psi]
     }
 
-    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) {
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
         let mut psi_list = self.psi_list_assemble();
         let mut psi = psi_list.first_mut().unwrap();
         psi.pid = pid;
@@ -68,6 +69,8 @@ impl PsiDemux for Tdt {
         psi.size = psi.buffer.len();
         psi.demux(dst);
         *cc = psi.cc;
+
+        Ok(())
     }
 }
 
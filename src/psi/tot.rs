@@ -13,7 +13,9 @@ use crate::{
         MJDTo,
         Psi,
         PsiDemux,
+        PsiError,
         Descriptors,
+        DescriptorContext,
     },
 };
 
@@ -22,6 +24,14 @@ use crate::{
 pub const TOT_PID: u16 = 0x0014;
 
 
+/// Maximum section length without CRC
+const TOT_SECTION_SIZE: usize = 1024 - 4;
+
+
+/// Section header size with descriptors_loop_length
+const TOT_HEADER_SIZE: usize = 10;
+
+
 /// Time Offset Table carries the UTC-time and date information and local time offset
 #[derive(Default, Debug)]
 pub struct Tot {
@@ -49,17 +59,17 @@ impl Tot {
             u64::from(psi.buffer[5 ..].get_u24().from_bcd_time());
 
         let descriptors_len = (psi.buffer[8 ..].get_u16() & 0x0FFF) as usize;
-        self.descriptors.parse(&psi.buffer[10 .. 10 + descriptors_len]);
+        self.descriptors.parse_context(&psi.buffer[10 .. 10 + descriptors_len], DescriptorContext::Tot);
     }
 }
 
 
 impl PsiDemux for Tot {
     fn psi_list_assemble(&self) -> Vec<Psi> {
-        let mut psi = Psi::new(0x73, 10, 0);
+        let mut psi = Psi::new(0x73, TOT_HEADER_SIZE, 0);
         psi.buffer[1] = 0x70; /* reserved bits */
 
-        psi.buffer.resize(10, 0x00);
+        psi.buffer.resize(TOT_HEADER_SIZE, 0x00);
         psi.buffer[3 ..].set_u16(self.time.to_mjd());
         psi.buffer[5 ..].set_u24((self.time as u32).to_bcd_time());
 
@@ -69,7 +79,19 @@ impl PsiDemux for Tot {
         vec![psi]
     }
 
-    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) {
+    fn validate(&self) -> Result<(), PsiError> {
+        self.descriptors.validate()?;
+        let size = TOT_HEADER_SIZE + self.descriptors.size();
+        if size > TOT_SECTION_SIZE {
+            return Err(PsiError::ItemOverflow { size, limit: TOT_SECTION_SIZE });
+        }
+
+        Ok(())
+    }
+
+    fn demux(&self, pid: u16, cc: &mut u8, dst: &mut Vec<u8>) -> Result<(), PsiError> {
+        self.validate()?;
+
         let mut psi_list = self.psi_list_assemble();
         let mut psi = psi_list.first_mut().unwrap();
         psi.finalize();
@@ -78,6 +100,8 @@ impl PsiDemux for Tot {
         psi.size = psi.buffer.len();
         psi.demux(dst);
         *cc = psi.cc;
+
+        Ok(())
     }
 }
 
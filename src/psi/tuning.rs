@@ -0,0 +1,126 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use crate::psi::{
+    Descriptors,
+    Desc43,
+    Desc44,
+    Desc5A,
+    Desc62,
+    Desc6C,
+    Desc6Ci,
+    Desc6D,
+    Desc6Di,
+    Desc79,
+    Desc7F,
+    Desc7F04,
+    Desc7F0D,
+    Desc7F17,
+};
+
+
+/// Delivery system of the transport stream
+#[derive(Debug, Clone)]
+pub enum DeliverySystem {
+    /// DVB-S/S2. Frequency in KHz
+    Satellite(Desc43),
+    /// DVB-C. Frequency in Hz
+    Cable(Desc44),
+    /// DVB-T. Frequency in Hz
+    Terrestrial(Desc5A),
+    /// DVB-T2. Frequencies in Hz defined in the cells
+    T2(Desc7F04),
+    /// DVB-C2. Frequency in Hz
+    C2(Desc7F0D),
+    /// DVB-S2X. Frequency in KHz
+    S2X(Desc7F17),
+}
+
+
+/// Tuning parameters of the transport stream collected
+/// from the NIT transport stream loop descriptors
+#[derive(Debug, Default, Clone)]
+pub struct Tuning {
+    /// Transport stream identifier
+    pub tsid: u16,
+    /// Original network identifier
+    pub onid: u16,
+    /// Delivery system descriptor
+    pub delivery: Option<DeliverySystem>,
+    /// DVB-S2 multistream and scrambling parameters
+    pub s2: Option<Desc79>,
+    /// Alternative frequencies. Units are same as in the delivery system
+    pub frequencies: Vec<u32>,
+    /// Cells coverage areas
+    pub cells: Vec<Desc6Ci>,
+    /// Cells frequencies in Hz
+    pub cell_frequencies: Vec<Desc6Di>,
+}
+
+
+impl Tuning {
+    /// Collects tuning parameters from the descriptors
+    pub fn from_descriptors(tsid: u16, onid: u16, descriptors: &Descriptors) -> Self {
+        let mut result = Self {
+            tsid,
+            onid,
+            .. Default::default()
+        };
+
+        for desc in descriptors.iter() {
+            if let Some(desc) = desc.downcast_ref::<Desc43>() {
+                result.delivery = Some(DeliverySystem::Satellite(desc.clone()));
+            } else if let Some(desc) = desc.downcast_ref::<Desc44>() {
+                result.delivery = Some(DeliverySystem::Cable(desc.clone()));
+            } else if let Some(desc) = desc.downcast_ref::<Desc5A>() {
+                result.delivery = Some(DeliverySystem::Terrestrial(desc.clone()));
+            } else if let Some(desc) = desc.downcast_ref::<Desc79>() {
+                result.s2 = Some(desc.clone());
+            } else if let Some(desc) = desc.downcast_ref::<Desc62>() {
+                result.frequencies.extend_from_slice(&desc.frequencies);
+            } else if let Some(desc) = desc.downcast_ref::<Desc6C>() {
+                result.cells.extend_from_slice(&desc.items);
+            } else if let Some(desc) = desc.downcast_ref::<Desc6D>() {
+                result.cell_frequencies.extend_from_slice(&desc.items);
+            } else if let Some(desc) = desc.downcast_ref::<Desc7F>() {
+                match desc {
+                    Desc7F::T2Delivery(v) => result.delivery = Some(DeliverySystem::T2(v.clone())),
+                    Desc7F::C2Delivery(v) => result.delivery = Some(DeliverySystem::C2(v.clone())),
+                    Desc7F::S2XDelivery(v) => result.delivery = Some(DeliverySystem::S2X(v.clone())),
+                    _ => {}
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns primary frequency of the transport stream.
+    /// KHz for satellite and Hz for other delivery systems
+    pub fn frequency(&self) -> Option<u32> {
+        match self.delivery.as_ref()? {
+            DeliverySystem::Satellite(v) => Some(v.frequency),
+            DeliverySystem::Cable(v) => Some(v.frequency),
+            DeliverySystem::Terrestrial(v) => Some(v.frequency),
+            DeliverySystem::C2(v) => Some(v.frequency),
+            DeliverySystem::S2X(v) => Some(v.channel.frequency),
+            DeliverySystem::T2(v) => v.params.as_ref()
+                .and_then(|p| p.cells.first())
+                .and_then(|c| c.frequencies.first())
+                .copied()
+                .or_else(|| self.cell_frequencies.first().map(|c| c.frequency)),
+        }
+    }
+
+    /// Returns input stream identifier for the multistream transport stream
+    pub fn input_stream_identifier(&self) -> Option<u8> {
+        match &self.delivery {
+            Some(DeliverySystem::S2X(v)) => v.input_stream_identifier,
+            _ => self.s2.as_ref().and_then(|v| v.input_stream_identifier),
+        }
+    }
+}
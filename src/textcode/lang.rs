@@ -187,3 +187,22 @@ pub fn convert<'a>(code: &str) -> Option<&'a str> {
 
     None
 }
+
+/// Returns `true` if both codes define same language.
+/// Codes could be in the ISO 639-1 or ISO 639-2 (T or B) formats
+pub fn is_same(a: &str, b: &str) -> bool {
+    if a.eq_ignore_ascii_case(b) {
+        return true;
+    }
+
+    let a = a.to_ascii_lowercase();
+    let b = b.to_ascii_lowercase();
+
+    let a = if a.len() == 3 { convert(&a) } else { Some(a.as_str()) };
+    let b = if b.len() == 3 { convert(&b) } else { Some(b.as_str()) };
+
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
@@ -9,6 +9,9 @@
 
 mod data;
 pub mod lang;
+pub mod country;
+mod mss;
+pub use mss::*;
 
 use std::{char, cmp};
 use std::fmt::{self, Write};
@@ -283,6 +286,46 @@ impl StringDVB {
 
         out
     }
+
+    /// Splits text into two parts. Size of the first part with
+    /// codepage identifier is less or equal than `size`.
+    /// Text breaks after the last space that fits into the first part,
+    /// or on the character boundary if the text has no spaces.
+    /// ISO6937 diacritical mark is not separated from the base letter.
+    /// Concatenation of both parts gives the original text.
+    pub fn split_at_word(&self, size: usize) -> (Self, Self) {
+        let limit = size.saturating_sub(self.size() - self.data.len());
+        if self.data.len() <= limit {
+            return (self.clone(), StringDVB::default());
+        }
+
+        let mut next = match self.data[.. limit].iter().rposition(|&c| c == b' ') {
+            Some(v) => v + 1,
+            None => limit,
+        };
+
+        if self.codepage == UTF8 {
+            while next > 0 && self.data[next] & 0xC0 == 0x80 {
+                next -= 1;
+            }
+        } else if self.codepage == ISO6937 {
+            // non-spacing diacritical mark precedes the base letter
+            while next > 0 && (0xC1 ..= 0xCF).contains(&self.data[next - 1]) {
+                next -= 1;
+            }
+        }
+
+        (
+            StringDVB {
+                codepage: self.codepage,
+                data: self.data[.. next].to_vec(),
+            },
+            StringDVB {
+                codepage: self.codepage,
+                data: self.data[next ..].to_vec(),
+            },
+        )
+    }
 }
 
 impl<'a> From<&'a [u8]> for StringDVB {
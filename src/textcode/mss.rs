@@ -0,0 +1,243 @@
+// Copyright (C) 2018-2019 Cesbo OU <info@cesbo.com>
+//
+// This file is part of ASC/libmpegts
+//
+// ASC/libmpegts can not be copied and/or distributed without the express
+// permission of Cesbo OU
+
+use std::fmt;
+
+use super::{
+    lang,
+    StringDVB,
+    ISO6937,
+};
+
+
+/// Segment is not compressed
+const COMPRESSION_NONE: u8 = 0x00;
+/// Segment encoded with UTF-16
+const MODE_UTF16: u8 = 0x3F;
+/// Modes below this value select the Unicode page of 256 characters
+const MODE_PAGE_MAX: u8 = 0x34;
+/// Maximum size of the segment data
+const SEGMENT_SIZE: usize = 255;
+
+
+/// Returns true if mode selects the Unicode page.
+/// Modes 0x07-0x08, 0x11-0x1F, 0x28-0x2F are reserved
+fn is_page_mode(mode: u32) -> bool {
+    match mode {
+        0x07 ..= 0x08 => false,
+        0x11 ..= 0x1F => false,
+        0x28 ..= 0x2F => false,
+        _ => mode < u32::from(MODE_PAGE_MAX),
+    }
+}
+
+
+/// String of the ATSC multiple string structure in one language
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MultipleStringItem {
+    /// ISO 639 language code
+    pub lang: StringDVB,
+    /// Decoded text. Compressed segments are not supported and skipped
+    pub text: String,
+}
+
+
+impl MultipleStringItem {
+    /// Returns mode for the single segment: Unicode page if all chars
+    /// in the same page, otherwise UTF-16
+    fn get_mode(&self) -> u8 {
+        let mut mode = None;
+        for c in self.text.chars() {
+            let page = u32::from(c) >> 8;
+            if ! is_page_mode(page) {
+                return MODE_UTF16;
+            }
+            match mode {
+                None => mode = Some(page as u8),
+                Some(v) if u32::from(v) == page => {}
+                Some(_) => return MODE_UTF16,
+            }
+        }
+        mode.unwrap_or(0x00)
+    }
+
+    /// Returns uncompressed segments with mode and data.
+    /// Text is split into segments of 255 bytes,
+    /// characters are not split between segments
+    fn encode(&self) -> (u8, Vec<Vec<u8>>) {
+        let mode = self.get_mode();
+        let mut segments: Vec<Vec<u8>> = Vec::new();
+        let mut buffer = [0u16; 2];
+
+        for c in self.text.chars() {
+            let mut data = Vec::with_capacity(4);
+            if mode == MODE_UTF16 {
+                for v in c.encode_utf16(&mut buffer) {
+                    data.extend_from_slice(&v.to_be_bytes());
+                }
+            } else {
+                data.push(u32::from(c) as u8);
+            }
+
+            match segments.last_mut() {
+                Some(segment) if segment.len() + data.len() <= SEGMENT_SIZE => {
+                    segment.extend_from_slice(&data);
+                }
+                _ => {
+                    if segments.len() == usize::from(u8::MAX) {
+                        break;
+                    }
+                    segments.push(data);
+                }
+            }
+        }
+
+        (mode, segments)
+    }
+}
+
+
+/// ATSC multiple string structure - text in one or more languages.
+/// Used in the ATSC descriptors and tables.
+///
+/// A/65 - 6.10
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MultipleString {
+    pub items: Vec<MultipleStringItem>,
+}
+
+
+impl fmt::Display for MultipleString {
+    /// Writes text of the first string
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.items.first() {
+            Some(item) => f.write_str(&item.text),
+            None => Ok(()),
+        }
+    }
+}
+
+
+fn decode_segment(mode: u8, data: &[u8], text: &mut String) {
+    if mode == MODE_UTF16 {
+        let iter = data.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]]));
+        text.extend(std::char::decode_utf16(iter)
+            .map(|c| c.unwrap_or(std::char::REPLACEMENT_CHARACTER)));
+    } else if is_page_mode(u32::from(mode)) {
+        let page = u32::from(mode) << 8;
+        text.extend(data.iter()
+            .filter_map(|&c| std::char::from_u32(page | u32::from(c))));
+    }
+}
+
+
+impl From<&[u8]> for MultipleString {
+    /// Parses multiple string structure. Invalid data is ignored
+    fn from(data: &[u8]) -> Self {
+        let mut result = MultipleString::default();
+        if data.is_empty() {
+            return result;
+        }
+
+        let number_strings = data[0];
+        let mut skip = 1;
+        for _ in 0 .. number_strings {
+            if data.len() < skip + 4 {
+                break;
+            }
+
+            let mut item = MultipleStringItem {
+                lang: StringDVB::from(&data[skip .. skip + 3]),
+                text: String::new(),
+            };
+            let number_segments = data[skip + 3];
+            skip += 4;
+
+            for _ in 0 .. number_segments {
+                if data.len() < skip + 3 {
+                    break;
+                }
+                let compression_type = data[skip];
+                let mode = data[skip + 1];
+                let segment_s = skip + 3;
+                let segment_e = segment_s + usize::from(data[skip + 2]);
+                if segment_e > data.len() {
+                    break;
+                }
+                if compression_type == COMPRESSION_NONE {
+                    decode_segment(mode, &data[segment_s .. segment_e], &mut item.text);
+                }
+                skip = segment_e;
+            }
+
+            result.items.push(item);
+        }
+
+        result
+    }
+}
+
+
+impl MultipleString {
+    /// Creates multiple string with one string
+    pub fn from_str(s: &str, lang: &str) -> Self {
+        MultipleString {
+            items: vec![
+                MultipleStringItem {
+                    lang: StringDVB::from_str(lang, ISO6937),
+                    text: s.to_owned(),
+                },
+            ],
+        }
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns text for the language defined by ISO 639 code
+    pub fn get_text(&self, code: &str) -> Option<&str> {
+        self.items.iter()
+            .find(|item| lang::is_same(&item.lang.to_string(), code))
+            .map(|item| item.text.as_str())
+    }
+
+    /// Returns size in bytes that needed for assembled structure.
+    /// Each string assembled without compression
+    pub fn size(&self) -> usize {
+        if self.items.is_empty() {
+            return 0;
+        }
+
+        self.items.iter().fold(1, |acc, item| {
+            acc + 4 + item.encode().1.iter().fold(0, |acc, data| acc + 3 + data.len())
+        })
+    }
+
+    /// Writes multiple string structure into buffer.
+    /// Nothing is written if structure is empty
+    pub fn assemble(&self, dst: &mut Vec<u8>) {
+        if self.items.is_empty() {
+            return;
+        }
+
+        dst.push(self.items.len() as u8);
+        for item in &self.items {
+            item.lang.assemble(dst);
+
+            let (mode, segments) = item.encode();
+            dst.push(segments.len() as u8);
+            for data in &segments {
+                dst.push(COMPRESSION_NONE);
+                dst.push(mode);
+                dst.push(data.len() as u8);
+                dst.extend_from_slice(data);
+            }
+        }
+    }
+}
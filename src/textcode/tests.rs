@@ -66,3 +66,124 @@ fn test_truncate() {
     x.truncate(5 + 3);
     assert_eq!(&x.to_string(), "Hello...");
 }
+
+
+#[test]
+fn test_split_at_word() {
+    let x = StringDVB::from_str("Hello, world!!!", ISO6937);
+    let (head, tail) = x.split_at_word(10);
+    assert_eq!(&head.to_string(), "Hello, ");
+    assert_eq!(&tail.to_string(), "world!!!");
+
+    let (head, tail) = x.split_at_word(100);
+    assert_eq!(head, x);
+    assert!(tail.is_empty());
+
+    // diacritical mark with base letter: "Größe"
+    let x = StringDVB::from(&[b'G', b'r', 0xC8, b'o', 0xFB, b'e'][..]);
+    let (head, tail) = x.split_at_word(3);
+    assert_eq!(head.data, b"Gr");
+    assert_eq!(tail.data, &[0xC8, b'o', 0xFB, b'e']);
+
+    let x = StringDVB::from_str("Привет", UTF8);
+    let (head, tail) = x.split_at_word(6);
+    assert_eq!(head.size(), 5);
+    assert_eq!(&head.to_string(), "Пр");
+    assert_eq!(&tail.to_string(), "ивет");
+
+    let x = StringDVB::from_str("Привет мир", ISO8859_5);
+    let (head, tail) = x.split_at_word(10);
+    assert_eq!(&head.to_string(), "Привет ");
+    assert_eq!(&tail.to_string(), "мир");
+}
+
+
+#[test]
+fn test_lang_is_same() {
+    assert!(lang::is_same("eng", "en"));
+    assert!(lang::is_same("ger", "deu"));
+    assert!(lang::is_same("DEU", "de"));
+    assert!(lang::is_same("xyz", "xyz"));
+    assert!(! lang::is_same("eng", "fra"));
+    assert!(! lang::is_same("xyz", "en"));
+}
+
+
+#[test]
+fn test_country() {
+    assert_eq!(country::convert("DE"), Some("DEU"));
+    assert_eq!(country::convert("gbr"), Some("GB"));
+    assert_eq!(country::convert("XX"), None);
+    assert_eq!(country::get_name("FIN"), Some("Finland"));
+    assert!(country::is_same("deu", "DE"));
+    assert!(country::is_same("XYZ", "xyz"));
+    assert!(! country::is_same("DEU", "AT"));
+}
+
+
+#[test]
+fn test_multiple_string() {
+    let data: &[u8] = &[
+        0x02,
+        0x65, 0x6e, 0x67, 0x01, 0x00, 0x00, 0x04, 0x4e, 0x65, 0x77, 0x73,
+        0x72, 0x75, 0x73, 0x01, 0x00, 0x3f, 0x04, 0x04, 0x1d, 0x04, 0x3e,
+    ];
+
+    let mss = MultipleString::from(data);
+    assert_eq!(mss.items.len(), 2);
+    assert_eq!(mss.to_string(), "News");
+    assert_eq!(mss.get_text("en"), Some("News"));
+    assert_eq!(mss.get_text("rus"), Some("Но"));
+
+    let mut assembled = Vec::new();
+    mss.assemble(&mut assembled);
+    assert_eq!(assembled.len(), mss.size());
+    assert_eq!(assembled.as_slice(), &[
+        0x02,
+        0x65, 0x6e, 0x67, 0x01, 0x00, 0x00, 0x04, 0x4e, 0x65, 0x77, 0x73,
+        0x72, 0x75, 0x73, 0x01, 0x00, 0x04, 0x02, 0x1d, 0x3e,
+    ]);
+
+    let mss = MultipleString::from_str("Caf\u{e9}", "fra");
+    let mut assembled = Vec::new();
+    mss.assemble(&mut assembled);
+    assert_eq!(MultipleString::from(assembled.as_slice()), mss);
+}
+
+
+#[test]
+fn test_multiple_string_reserved_page() {
+    // page 0x11 is reserved, text encoded with UTF-16
+    let mss = MultipleString::from_str("\u{1100}\u{1101}", "kor");
+    let mut assembled = Vec::new();
+    mss.assemble(&mut assembled);
+    assert_eq!(assembled.len(), mss.size());
+    assert_eq!(assembled.as_slice(), &[
+        0x01,
+        0x6b, 0x6f, 0x72, 0x01, 0x00, 0x3f, 0x04, 0x11, 0x00, 0x11, 0x01,
+    ]);
+    assert_eq!(MultipleString::from(assembled.as_slice()), mss);
+}
+
+
+#[test]
+fn test_multiple_string_segments() {
+    let text = "a".repeat(300);
+    let mss = MultipleString::from_str(&text, "eng");
+    let mut assembled = Vec::new();
+    mss.assemble(&mut assembled);
+    assert_eq!(assembled.len(), mss.size());
+    assert_eq!(&assembled[1 .. 5], &[0x65, 0x6e, 0x67, 0x02]);
+    assert_eq!(&assembled[5 .. 8], &[0x00, 0x00, 0xff]);
+    assert_eq!(&assembled[263 .. 266], &[0x00, 0x00, 45]);
+    assert_eq!(MultipleString::from(assembled.as_slice()), mss);
+
+    // surrogate pairs are not split between segments
+    let text = "\u{1F600}".repeat(100);
+    let mss = MultipleString::from_str(&text, "eng");
+    let mut assembled = Vec::new();
+    mss.assemble(&mut assembled);
+    assert_eq!(assembled.len(), mss.size());
+    assert_eq!(&assembled[5 .. 8], &[0x00, 0x3f, 252]);
+    assert_eq!(MultipleString::from(assembled.as_slice()), mss);
+}
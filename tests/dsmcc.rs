@@ -0,0 +1,162 @@
+use mpegts::{
+    ts,
+    bytes::*,
+    psi::*,
+};
+
+const INDEX_HTML: &[u8] = b"<html><body>Hello</body></html>";
+const APP_JS: &[u8] = b"console.log('hello');";
+
+fn get_carousel() -> ObjectCarousel {
+    let mut carousel = ObjectCarousel::default();
+    carousel.version = 1;
+    carousel.carousel_id = 1;
+    carousel.association_tag = 0x000B;
+    carousel.add_file("index.html", INDEX_HTML.to_vec()).unwrap();
+    carousel.add_file("js/app.js", APP_JS.to_vec()).unwrap();
+    carousel
+}
+
+fn find(data: &[u8], needle: &[u8]) -> bool {
+    data.windows(needle.len()).any(|x| x == needle)
+}
+
+#[test]
+fn test_assemble_dsmcc() {
+    let carousel = get_carousel();
+    let psi_list = carousel.psi_list_assemble();
+    assert_eq!(psi_list.len(), 3);
+
+    for psi in &psi_list {
+        assert!(psi.check());
+        assert_eq!(psi.buffer[8], 0x11);
+        assert_eq!(psi.buffer[9], 0x03);
+    }
+
+    // DownloadServerInitiate
+    let dsi = &psi_list[0];
+    assert_eq!(dsi.buffer[0], 0x3B);
+    assert_eq!(dsi.buffer[10 ..].get_u16(), 0x1006);
+    assert!(find(&dsi.buffer, b"srg\0"));
+
+    // DownloadInfoIndication
+    let dii = &psi_list[1];
+    assert_eq!(dii.buffer[0], 0x3B);
+    assert_eq!(dii.buffer[10 ..].get_u16(), 0x1002);
+    assert_eq!(dii.buffer[20 ..].get_u32(), 1);
+    assert_eq!(dii.buffer[24 ..].get_u16(), DSMCC_BLOCK_SIZE);
+    assert_eq!(dii.buffer[38 ..].get_u16(), 1);
+
+    // DownloadDataBlock
+    let ddb = &psi_list[2];
+    assert_eq!(ddb.buffer[0], 0x3C);
+    assert_eq!(ddb.buffer[3 ..].get_u16(), 1);
+    assert_eq!(ddb.buffer[10 ..].get_u16(), 0x1003);
+    let block = &ddb.buffer[26 .. ddb.size - 4];
+    assert_eq!(&block[.. 4], b"BIOP");
+    assert!(find(block, b"index.html\0"));
+    assert!(find(block, b"js\0"));
+    assert!(find(block, b"app.js\0"));
+    assert!(find(block, INDEX_HTML));
+    assert!(find(block, APP_JS));
+}
+
+#[test]
+fn test_assemble_dsmcc_blocks() {
+    let mut carousel = get_carousel();
+    carousel.block_size = 64;
+    let psi_list = carousel.psi_list_assemble();
+    assert!(psi_list.len() > 3);
+
+    let mut module = Vec::new();
+    let last_section_number = (psi_list.len() - 3) as u8;
+    for (n, ddb) in psi_list[2 ..].iter().enumerate() {
+        assert_eq!(ddb.buffer[6], n as u8);
+        assert_eq!(ddb.buffer[7], last_section_number);
+        assert_eq!(ddb.buffer[24 ..].get_u16(), n as u16);
+        module.extend_from_slice(&ddb.buffer[26 .. ddb.size - 4]);
+    }
+
+    let dii = &psi_list[1];
+    assert_eq!(dii.buffer[42 ..].get_u32() as usize, module.len());
+}
+
+#[test]
+fn test_fill_dsmcc() {
+    let mut carousel = get_carousel();
+    carousel.bitrate = 188 * 8 * 100;
+
+    let mut cc = 0;
+    let mut dst = Vec::new();
+    carousel.fill(0x0800, &mut cc, 50, &mut dst).unwrap();
+    assert_eq!(dst.len(), 5 * 188);
+    carousel.fill(0x0800, &mut cc, 5, &mut dst).unwrap();
+    assert_eq!(dst.len(), 5 * 188);
+    carousel.fill(0x0800, &mut cc, 5, &mut dst).unwrap();
+    assert_eq!(dst.len(), 6 * 188);
+
+    for (n, packet) in dst.chunks(188).enumerate() {
+        assert_eq!(ts::get_pid(packet), 0x0800);
+        assert_eq!(ts::get_cc(packet), n as u8);
+    }
+
+    // cycle is rebuilt after change of the carousel parameters
+    for version in &[2, 3] {
+        carousel.version = *version;
+        carousel.block_size = 64 * u16::from(*version);
+
+        let mut expected = Vec::new();
+        carousel.demux(0x0800, &mut 0, &mut expected).unwrap();
+
+        let mut dst = Vec::new();
+        carousel.fill(0x0800, &mut cc, 10, &mut dst).unwrap();
+        assert_eq!(dst.len(), 188);
+        assert_eq!(&dst[4 ..], &expected[4 .. 188]);
+    }
+}
+
+#[test]
+fn test_dsmcc_descriptors() {
+    let carousel = get_carousel();
+    let descriptors = carousel.descriptors();
+
+    assert_eq!(descriptors.find::<Desc52>().unwrap().tag, 0x0B);
+    let desc = descriptors.find::<Desc13>().unwrap();
+    assert_eq!(desc.carousel_id, 1);
+    assert_eq!(desc.format_id, CAROUSEL_FORMAT_STANDARD);
+}
+
+#[test]
+fn test_dsmcc_limits() {
+    let mut carousel = get_carousel();
+
+    let name = "a".repeat(255);
+    assert!(carousel.add_file(&name, Vec::new()).is_err());
+    assert!(carousel.add_file(format!("{}/index.html", name), Vec::new()).is_err());
+    assert!(carousel.add_file(&name[1 ..], Vec::new()).is_ok());
+
+    carousel.block_size = 1;
+    assert!(carousel.add_file("large.bin", vec![0; 0x1_0000]).is_err());
+    assert!(carousel.add_file("large.bin", vec![0; 0x1_0000 - 64]).is_ok());
+    carousel.add_file("large.bin", Vec::new()).unwrap();
+
+    // block size is limited to the section size
+    for block_size in &[0, 5000] {
+        carousel.block_size = *block_size;
+        let psi_list = carousel.psi_list_assemble();
+        for psi in &psi_list {
+            assert!(psi.check());
+            assert!(psi.size <= 4096);
+        }
+        let dii = &psi_list[1];
+        assert_eq!(dii.buffer[24 ..].get_u16(), if *block_size == 0 { 1 } else { DSMCC_BLOCK_SIZE });
+    }
+    assert!(carousel.validate().is_ok());
+
+    // module does not fit into 16-bit block_number after block size change
+    carousel.block_size = 2;
+    carousel.add_file("large.bin", vec![0; 0x1_8000]).unwrap();
+    assert!(carousel.validate().is_ok());
+    carousel.block_size = 1;
+    assert!(carousel.validate().is_err());
+}
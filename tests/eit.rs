@@ -29,7 +29,7 @@ fn test_parse_eit_4e() {
     assert_eq!(item.status, 4);
     assert_eq!(item.ca_mode, 0);
     assert_eq!(item.descriptors.len(), 1);
-    let desc = item.descriptors.iter().next().unwrap().downcast_ref::<Desc4D>();
+    let desc = item.descriptors.iter().next().unwrap().downcast_ref::<Desc4D>().unwrap();
     assert_eq!(&desc.lang.to_string(), EIT_4E_LANG);
     assert_eq!(&desc.name.to_string(), EIT_4E_NAME);
     assert_eq!(&desc.text.to_string(), EIT_4E_TEXT);
@@ -60,7 +60,7 @@ fn test_assemble_eit_4e() {
 
     let mut cc: u8 = 0;
     let mut eit_4e_ts = Vec::<u8>::new();
-    eit.demux(EIT_PID, &mut cc, &mut eit_4e_ts);
+    eit.demux(EIT_PID, &mut cc, &mut eit_4e_ts).unwrap();
 
     assert_eq!(data::EIT_4E, eit_4e_ts.as_slice());
 }
@@ -142,7 +142,193 @@ fn test_assemble_eit_50() {
 
     let mut cc: u8 = 4;
     let mut eit_50_ts = Vec::<u8>::new();
-    eit.demux(EIT_PID, &mut cc, &mut eit_50_ts);
+    eit.demux(EIT_PID, &mut cc, &mut eit_50_ts).unwrap();
 
     assert_eq!(data::EIT_50, &eit_50_ts[..]);
 }
+
+#[test]
+fn test_eit_resolve_reference_event() {
+    let mut reference = Eit {
+        table_id: 0x50,
+        pnr: 256,
+        .. Default::default()
+    };
+    let mut event = EitItem {
+        event_id: 0x1234,
+        .. Default::default()
+    };
+    event.descriptors.push(Desc4D {
+        lang: StringDVB::from_str("eng", ISO6937),
+        name: StringDVB::from_str("Movie", ISO6937),
+        text: StringDVB::default(),
+    });
+    reference.items.push(event);
+
+    let mut shifted = EitItem {
+        event_id: 1,
+        .. Default::default()
+    };
+    shifted.descriptors.push(Desc4F {
+        reference_service_id: 256,
+        reference_event_id: 0x1234,
+    });
+
+    let event = reference.resolve_reference_event(&shifted).unwrap();
+    assert_eq!(event.event_id, 0x1234);
+    let desc = event.descriptors.find::<Desc4D>().unwrap();
+    assert_eq!(desc.name.to_string(), "Movie");
+
+    reference.pnr = 257;
+    assert!(reference.resolve_reference_event(&shifted).is_none());
+    assert!(reference.resolve_reference_event(&EitItem::default()).is_none());
+}
+
+#[test]
+fn test_eit_crid() {
+    let eit = Eit {
+        table_id: 0x4E,
+        pnr: 1,
+        tsid: 2,
+        onid: 3,
+        .. Default::default()
+    };
+
+    let mut item = EitItem::default();
+    item.descriptors.push(Desc76 {
+        items: vec![
+            Desc76i {
+                crid_type: CRID_TYPE_PROGRAMME,
+                crid: Desc76Crid::Descriptor("/P123".to_owned()),
+            },
+            Desc76i {
+                crid_type: CRID_TYPE_SERIES,
+                crid: Desc76Crid::Descriptor("crid://other.tv/S1".to_owned()),
+            },
+            Desc76i {
+                crid_type: CRID_TYPE_RECOMMENDATION,
+                crid: Desc76Crid::Reference(1),
+            },
+        ],
+    });
+
+    let mut nit = Nit {
+        network_id: 3,
+        .. Default::default()
+    };
+    nit.descriptors.push(Desc73 {
+        authority: "network.tv".to_owned(),
+    });
+    nit.items.push(NitItem {
+        tsid: 2,
+        onid: 3,
+        .. Default::default()
+    });
+
+    let mut sdt = Sdt {
+        tsid: 2,
+        onid: 3,
+        .. Default::default()
+    };
+    sdt.items.push(SdtItem {
+        pnr: 1,
+        .. Default::default()
+    });
+
+    // without default authority
+    let resolver = CridResolver::default();
+    let crids = resolver.resolve(&eit, &item);
+    assert_eq!(crids.len(), 1);
+    assert_eq!(crids[0].value, "crid://other.tv/S1");
+
+    // network scope
+    let resolver = CridResolver {
+        sdt: vec![&sdt],
+        nit: vec![&nit],
+    };
+    assert_eq!(
+        resolver.get_crid(&eit, &item, CRID_TYPE_PROGRAMME).unwrap(),
+        "crid://network.tv/P123");
+
+    // service scope overrides network scope
+    sdt.items[0].descriptors.push(Desc73 {
+        authority: "service.tv".to_owned(),
+    });
+    let resolver = CridResolver {
+        sdt: vec![&sdt],
+        nit: vec![&nit],
+    };
+    assert_eq!(
+        resolver.get_crid(&eit, &item, CRID_TYPE_PROGRAMME).unwrap(),
+        "crid://service.tv/P123");
+    assert_eq!(
+        resolver.get_crid(&eit, &item, CRID_TYPE_SERIES).unwrap(),
+        "crid://other.tv/S1");
+    assert!(resolver.get_crid(&eit, &item, CRID_TYPE_RECOMMENDATION).is_none());
+}
+
+#[test]
+fn test_eit_pf_generator() {
+    const START: u64 = 1_500_000_000;
+    const PID: u16 = EIT_PID;
+
+    fn sections(ts: &[u8]) -> Vec<Eit> {
+        let mut list = Vec::new();
+        let mut psi = Psi::default();
+        for packet in ts.chunks(188) {
+            psi.mux(packet);
+            if psi.check() {
+                list.push(Eit::from(&psi));
+            }
+        }
+        list
+    }
+
+    let mut generator = EitPf::new(1, 2, 3);
+    generator.set_schedule(vec![
+        EitItem { event_id: 2, start: START + 3600, duration: 3600, .. Default::default() },
+        EitItem { event_id: 1, start: START, duration: 3600, .. Default::default() },
+        EitItem { event_id: 3, start: START + 7200, duration: 3600, .. Default::default() },
+    ]);
+
+    let mut cc = 0;
+    let mut ts = Vec::new();
+
+    assert!(generator.process(START * 1000, PID, &mut cc, &mut ts).unwrap());
+    let list = sections(&ts);
+    assert_eq!(list.len(), 2);
+    let version = list[0].version;
+    assert_eq!(list[0].table_id, 0x4E);
+    assert_eq!(list[0].pnr, 1);
+    assert_eq!(list[0].items[0].event_id, 1);
+    assert_eq!(list[0].items[0].status, 4);
+    assert_eq!(list[1].items[0].event_id, 2);
+    assert_eq!(list[1].items[0].status, 1);
+
+    // repetition interval
+    ts.clear();
+    assert!(! generator.process(START * 1000 + 1000, PID, &mut cc, &mut ts).unwrap());
+    assert!(ts.is_empty());
+    assert!(generator.process(START * 1000 + EIT_PF_INTERVAL, PID, &mut cc, &mut ts).unwrap());
+    let list = sections(&ts);
+    assert_eq!(list[0].version, version);
+    assert_eq!(list[0].items[0].event_id, 1);
+
+    // event boundary
+    ts.clear();
+    assert!(generator.process((START + 3600) * 1000, PID, &mut cc, &mut ts).unwrap());
+    let list = sections(&ts);
+    assert_eq!(list[0].version, version + 1);
+    assert_eq!(list[0].items[0].event_id, 2);
+    assert_eq!(list[1].items[0].event_id, 3);
+
+    // last event without following
+    ts.clear();
+    assert!(generator.process((START + 7200) * 1000, PID, &mut cc, &mut ts).unwrap());
+    let list = sections(&ts);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].version, version + 2);
+    assert_eq!(list[0].items[0].event_id, 3);
+    assert!(list[1].items.is_empty());
+    assert!(generator.following().is_none());
+}
@@ -0,0 +1,101 @@
+use mpegts::psi::*;
+use mpegts::textcode::*;
+
+
+const START: u64 = 1_500_000_000;
+
+
+fn event(event_id: u16, start: u64, duration: u32, title: &str) -> EitItem {
+    let mut item = EitItem {
+        event_id,
+        start,
+        duration,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc4D {
+        lang: StringDVB::from_str("eng", ISO6937),
+        name: StringDVB::from_str(title, ISO6937),
+        text: StringDVB::default(),
+    });
+    item
+}
+
+
+fn eit(table_id: u8, version: u8, items: Vec<EitItem>) -> Eit {
+    Eit {
+        table_id,
+        version,
+        pnr: 10,
+        tsid: 2,
+        onid: 1,
+        items,
+    }
+}
+
+
+#[test]
+fn test_eit_item_text() {
+    let mut item = event(1, START, 3600, "News");
+    item.descriptors.push(Desc4D {
+        lang: StringDVB::from_str("deu", ISO6937),
+        name: StringDVB::from_str("Nachrichten", ISO6937),
+        text: StringDVB::from_str("Aktuell", ISO6937),
+    });
+
+    let lang = StringDVB::from_str("eng", ISO6937);
+    let text = "Latest news from around the world. ".repeat(12);
+    for desc in Desc4E::build(&lang, &[], &StringDVB::from_str(&text, ISO8859_1)).into_iter().rev() {
+        item.descriptors.push(desc);
+    }
+
+    assert_eq!(item.get_title("ger").unwrap(), "Nachrichten");
+    assert_eq!(item.get_short_text("de").unwrap(), "Aktuell");
+    assert_eq!(item.get_title("fra").unwrap(), "News");
+    assert_eq!(item.get_description("eng").unwrap(), text);
+    assert_eq!(item.get_description("deu").unwrap(), text);
+
+    assert!(EitItem::default().get_title("eng").is_none());
+    assert!(EitItem::default().get_description("eng").is_none());
+}
+
+
+#[test]
+fn test_epg() {
+    let mut epg = Epg::default();
+
+    epg.ingest(&eit(0x4E, 1, vec![event(1, START, 3600, "One")]));
+    epg.ingest(&eit(0x4E, 1, vec![event(2, START + 3600, 1800, "Two")]));
+    epg.ingest(&eit(0x50, 4, vec![
+        event(2, START + 3600, 1800, "Two"),
+        event(3, START + 5400, 3600, "Three"),
+        event(4, START + 9000, 3600, "Four"),
+    ]));
+    // EIT other for the same service
+    epg.ingest(&eit(0x61, 1, vec![event(5, START + 12600, 600, "Five")]));
+
+    assert!(epg.get_service(1, 2, 11).is_none());
+    let service = epg.get_service(1, 2, 10).unwrap();
+    assert_eq!(service.len(), 5);
+    let ids: Vec<u16> = service.iter().map(|x| x.event_id).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+    let now = START + 600;
+    assert_eq!(service.present(now).unwrap().get_title("eng").unwrap(), "One");
+    assert_eq!(service.following(now).unwrap().get_title("eng").unwrap(), "Two");
+    assert!(service.present(START - 1).is_none());
+
+    let ids: Vec<u16> = service.range(START + 4000, START + 9000).map(|x| x.event_id).collect();
+    assert_eq!(ids, vec![2, 3]);
+    assert_eq!(service.get_event(4).unwrap().start, START + 9000);
+
+    // new version of the schedule sub-table replaces its events
+    epg.ingest(&eit(0x50, 5, vec![event(6, START + 5400, 7200, "Six")]));
+    let service = epg.get_service(1, 2, 10).unwrap();
+    let ids: Vec<u16> = service.iter().map(|x| x.event_id).collect();
+    assert_eq!(ids, vec![1, 2, 6, 5]);
+
+    epg.expire(START + 3600);
+    let service = epg.get_service(1, 2, 10).unwrap();
+    let ids: Vec<u16> = service.iter().map(|x| x.event_id).collect();
+    assert_eq!(ids, vec![2, 6, 5]);
+}
@@ -0,0 +1,221 @@
+use mpegts::psi::*;
+use mpegts::textcode::*;
+
+
+fn sdt(table_id: u8, tsid: u16, version: u8, services: &[(u16, &str)]) -> Sdt {
+    let mut sdt = Sdt {
+        table_id,
+        version,
+        tsid,
+        onid: 1,
+        .. Default::default()
+    };
+
+    for (pnr, name) in services {
+        let mut item = SdtItem {
+            pnr: *pnr,
+            running_status: 4,
+            .. Default::default()
+        };
+        item.descriptors.push(Desc48 {
+            service_type: 1,
+            provider: StringDVB::from_str("Provider", ISO6937),
+            name: StringDVB::from_str(name, ISO6937),
+        });
+        sdt.items.push(item);
+    }
+
+    sdt
+}
+
+
+#[test]
+fn test_network_db() {
+    let mut db = NetworkDb::default();
+
+    db.ingest_sdt(&sdt(0x42, 1, 1, &[(101, "One"), (102, "Two")]));
+    db.ingest_sdt(&sdt(0x46, 2, 3, &[(201, "Three")]));
+    // next section of the same table
+    db.ingest_sdt(&sdt(0x46, 2, 3, &[(202, "Four")]));
+    // new version of the SDT other replaces services
+    db.ingest_sdt(&sdt(0x46, 3, 1, &[(301, "Five")]));
+    db.ingest_sdt(&sdt(0x46, 3, 2, &[(302, "Six")]));
+
+    let mut nit = Nit {
+        table_id: 0x40,
+        version: 1,
+        network_id: 1,
+        .. Default::default()
+    };
+    nit.items.push(NitItem { tsid: 1, onid: 1, .. Default::default() });
+    nit.items.push(NitItem { tsid: 2, onid: 1, .. Default::default() });
+    db.ingest_nit(&nit);
+
+    let mut nit = Nit {
+        table_id: 0x41,
+        version: 5,
+        network_id: 2,
+        .. Default::default()
+    };
+    nit.items.push(NitItem { tsid: 3, onid: 1, .. Default::default() });
+    db.ingest_nit(&nit);
+
+    let mut eit = Eit {
+        table_id: 0x4E,
+        version: 1,
+        pnr: 201,
+        tsid: 2,
+        onid: 1,
+        .. Default::default()
+    };
+    // section 0 without present event
+    db.ingest_eit(&eit, 0);
+    eit.items.push(EitItem { event_id: 2, start: 1_500_000_000, .. Default::default() });
+    db.ingest_eit(&eit, 1);
+
+    let ts = db.get_ts(1, 2).unwrap();
+    assert_eq!(ts.network_id, Some(1));
+    assert_eq!(ts.services.len(), 2);
+    assert_eq!(ts.get_service(202).unwrap().get_name("eng").unwrap().to_string(), "Four");
+    assert_eq!(db.get_ts(1, 3).unwrap().services.len(), 1);
+
+    // SDT other
+    let list = db.sdt_other(1, 1);
+    assert_eq!(list.len(), 2);
+    assert!(list.iter().all(|sdt| sdt.table_id == 0x46 && sdt.tsid != 1));
+
+    let mut cc = 0;
+    let mut ts = Vec::new();
+    list[0].demux(SDT_PID, &mut cc, &mut ts).unwrap();
+    let mut psi = Psi::default();
+    psi.mux(&ts);
+    let parsed = Sdt::from(&psi);
+    assert_eq!(parsed.table_id, 0x46);
+    assert_eq!(parsed.tsid, 2);
+    assert_eq!(parsed.version, 3);
+    assert_eq!(parsed.items.len(), 2);
+
+    // NIT other
+    let list = db.nit_other(1);
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].table_id, 0x41);
+    assert_eq!(list[0].network_id, 2);
+    assert_eq!(list[0].version, 5);
+    assert_eq!(list[0].items.len(), 1);
+    assert_eq!(list[0].items[0].tsid, 3);
+
+    // EIT other present/following
+    let list = db.eit_pf_other(1, 1);
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].table_id, 0x4F);
+    assert_eq!(list[0].pnr, 201);
+    assert!(list[0].present().is_none());
+    assert_eq!(list[0].following().unwrap().event_id, 2);
+    assert!(db.eit_pf_other(1, 2).is_empty());
+    assert!(db.eit_other(1, 1).is_empty());
+
+    let mut cc = 0;
+    let mut ts = Vec::new();
+    list[0].demux(EIT_PID, &mut cc, &mut ts).unwrap();
+
+    let mut sections = Vec::new();
+    let mut psi = Psi::default();
+    for packet in ts.chunks(188) {
+        psi.mux(packet);
+        if psi.check() {
+            sections.push((psi.buffer[6], Eit::from(&psi)));
+        }
+    }
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[0].0, 0);
+    assert_eq!(sections[0].1.table_id, 0x4F);
+    assert!(sections[0].1.items.is_empty());
+    assert_eq!(sections[1].0, 1);
+    assert_eq!(sections[1].1.items[0].event_id, 2);
+
+    // next event becomes present
+    eit.version = 2;
+    eit.items[0] = EitItem { event_id: 2, start: 1_500_000_000, .. Default::default() };
+    db.ingest_eit(&eit, 0);
+    eit.items[0] = EitItem { event_id: 3, start: 1_500_003_600, .. Default::default() };
+    db.ingest_eit(&eit, 1);
+
+    let list = db.eit_pf_other(1, 1);
+    assert_eq!(list[0].version, 2);
+    assert_eq!(list[0].present().unwrap().event_id, 2);
+    assert_eq!(list[0].following().unwrap().event_id, 3);
+}
+
+
+#[test]
+fn test_network_db_eit_schedule() {
+    let mut db = NetworkDb::default();
+
+    let mut eit = Eit {
+        table_id: 0x50,
+        version: 1,
+        pnr: 201,
+        tsid: 2,
+        onid: 1,
+        .. Default::default()
+    };
+    eit.items.push(EitItem { event_id: 1, start: 1_500_000_000, .. Default::default() });
+    db.ingest_eit(&eit, 0);
+
+    eit.table_id = 0x51;
+    eit.version = 4;
+    eit.items[0] = EitItem { event_id: 2, start: 1_500_400_000, .. Default::default() };
+    db.ingest_eit(&eit, 0);
+
+    // new version of the one sub-table keeps events of the other sub-table
+    eit.version = 5;
+    eit.items[0] = EitItem { event_id: 3, start: 1_500_500_000, .. Default::default() };
+    db.ingest_eit(&eit, 0);
+
+    let list = db.eit_other(1, 1);
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].table_id, 0x60);
+    assert_eq!(list[0].version, 1);
+    assert_eq!(list[0].items.len(), 1);
+    assert_eq!(list[0].items[0].event_id, 1);
+    assert_eq!(list[1].table_id, 0x61);
+    assert_eq!(list[1].version, 5);
+    assert_eq!(list[1].items.len(), 1);
+    assert_eq!(list[1].items[0].event_id, 3);
+}
+
+
+#[test]
+fn test_network_db_nit_version() {
+    let mut db = NetworkDb::default();
+
+    db.ingest_sdt(&sdt(0x46, 2, 1, &[(201, "One")]));
+
+    let mut nit = Nit {
+        table_id: 0x41,
+        version: 1,
+        network_id: 1,
+        .. Default::default()
+    };
+    for tsid in 1 ..= 3 {
+        nit.items.push(NitItem { tsid, onid: 1, .. Default::default() });
+    }
+    db.ingest_nit(&nit);
+    assert_eq!(db.ts.len(), 3);
+
+    // new version of the NIT replaces transport streams of the network
+    nit.version = 2;
+    nit.items.truncate(1);
+    db.ingest_nit(&nit);
+
+    assert_eq!(db.get_ts(1, 1).unwrap().network_id, Some(1));
+    // transport stream with services is detached from the network
+    assert_eq!(db.get_ts(1, 2).unwrap().network_id, None);
+    assert!(db.get_ts(1, 3).is_none());
+
+    let list = db.nit_other(2);
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].version, 2);
+    assert_eq!(list[0].items.len(), 1);
+    assert_eq!(list[0].items[0].tsid, 1);
+}
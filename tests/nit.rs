@@ -1,4 +1,5 @@
 use mpegts::psi::*;
+use mpegts::textcode::*;
 mod data;
 
 #[test]
@@ -101,7 +102,198 @@ fn test_assemble_nit() {
 
     let mut cc: u8 = 15;
     let mut nit_ts = Vec::<u8>::new();
-    nit.demux(NIT_PID, &mut cc, &mut nit_ts);
+    nit.demux(NIT_PID, &mut cc, &mut nit_ts).unwrap();
 
     assert_eq!(data::NIT_DVBS, nit_ts.as_slice());
 }
+
+#[test]
+fn test_nit_tuning() {
+    let mut psi = Psi::default();
+    psi.mux(data::NIT_DVBS);
+
+    let mut nit = Nit::default();
+    nit.parse(&psi);
+
+    let tuning = nit.tuning();
+    assert_eq!(tuning.len(), 6);
+
+    let item = &tuning[0];
+    assert_eq!(item.tsid, 8400);
+    assert_eq!(item.onid, 318);
+    assert_eq!(item.frequency(), Some(12380000));
+    assert_eq!(item.input_stream_identifier(), None);
+    match &item.delivery {
+        Some(DeliverySystem::Satellite(v)) => assert_eq!(v.symbol_rate, 27500),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_nit_tuning_t2() {
+    let mut item = NitItem {
+        tsid: 1,
+        onid: 8916,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc7F::from(Desc7F04 {
+        plp_id: 0,
+        t2_system_id: 0x2085,
+        params: Some(Desc7F04p {
+            tfs_flag: 0,
+            cells: vec![
+                Desc7F04c {
+                    cell_id: 1,
+                    frequencies: vec![802000000],
+                    subcells: Vec::new(),
+                },
+            ],
+            .. Default::default()
+        }),
+    }));
+    item.descriptors.push(Desc62 {
+        coding_type: CODING_TYPE_TERRESTRIAL,
+        frequencies: vec![810000000],
+    });
+
+    let tuning = item.tuning();
+    assert_eq!(tuning.frequency(), Some(802000000));
+    assert_eq!(tuning.frequencies, vec![810000000]);
+    match &tuning.delivery {
+        Some(DeliverySystem::T2(v)) => assert_eq!(v.t2_system_id, 0x2085),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_nit_get_name() {
+    let mut nit = Nit::default();
+    nit.descriptors.push(Desc40 {
+        name: StringDVB::from_str("Network", ISO6937),
+    });
+    nit.descriptors.push(Desc5B {
+        items: vec![
+            Desc5Bi {
+                lang: StringDVB::from_str("fre", ISO6937),
+                name: StringDVB::from_str("Reseau", ISO6937),
+            },
+        ],
+    });
+
+    assert_eq!(nit.get_name("fra").unwrap().to_string(), "Reseau");
+    assert_eq!(nit.get_name("fr").unwrap().to_string(), "Reseau");
+    assert_eq!(nit.get_name("eng").unwrap().to_string(), "Network");
+}
+
+#[test]
+fn test_nit_lcn() {
+    let mut nit = Nit::default();
+
+    let mut item = NitItem {
+        tsid: 1,
+        onid: 2,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc5F {
+        private_data_specifier: PRIVATE_DATA_SPECIFIER_NORDIG,
+    });
+    item.descriptors.push(Desc83NorDig {
+        items: vec![
+            Desc83i { service_id: 101, visible: 1, lcn: 1 },
+            Desc83i { service_id: 102, visible: 1, lcn: 2 },
+        ],
+    });
+    item.descriptors.push(Desc87NorDig {
+        items: vec![
+            Desc87NorDigList {
+                channel_list_id: 1,
+                name: StringDVB::from_str("Finland", ISO6937),
+                country_code: StringDVB::from_str("FIN", ISO6937),
+                items: vec![
+                    Desc83i { service_id: 101, visible: 1, lcn: 11 },
+                ],
+            },
+            Desc87NorDigList {
+                channel_list_id: 2,
+                name: StringDVB::from_str("Sweden", ISO6937),
+                country_code: StringDVB::from_str("SWE", ISO6937),
+                items: vec![
+                    Desc83i { service_id: 101, visible: 0, lcn: 21 },
+                ],
+            },
+        ],
+    });
+    nit.items.push(item);
+
+    // check parsed descriptors
+    let mut buffer = Vec::new();
+    nit.items[0].descriptors.assemble(&mut buffer);
+    let mut descriptors = Descriptors::default();
+    descriptors.parse(&buffer);
+    assert!(descriptors.find::<Desc83NorDig>().is_some());
+    assert!(descriptors.find::<Desc87NorDig>().is_some());
+
+    let lcn = nit.get_lcn(2, 1, 101, "SE", false).unwrap();
+    assert_eq!(lcn.lcn, 21);
+    assert!(! lcn.visible);
+    assert_eq!(nit.get_lcn(2, 1, 101, "FIN", false).unwrap().lcn, 11);
+    assert_eq!(nit.get_lcn(2, 1, 101, "NOR", false).unwrap().lcn, 11);
+    assert_eq!(nit.get_lcn(2, 1, 102, "SE", false).unwrap().lcn, 2);
+    assert!(nit.get_lcn(2, 2, 101, "SE", false).is_none());
+
+    let mut item = NitItem {
+        tsid: 2,
+        onid: 2,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc83 {
+        items: vec![
+            Desc83i { service_id: 201, visible: 1, lcn: 5 },
+        ],
+    });
+    item.descriptors.push(Desc88 {
+        items: vec![
+            Desc83i { service_id: 201, visible: 1, lcn: 105 },
+        ],
+    });
+    nit.items.push(item);
+
+    assert_eq!(nit.get_lcn(2, 2, 201, "", false).unwrap().lcn, 5);
+    assert_eq!(nit.get_lcn(2, 2, 201, "", true).unwrap().lcn, 105);
+    assert_eq!(nit.lcn("SE", false).len(), 3);
+}
+
+#[test]
+fn test_nit_split_descriptors() {
+    let mut nit = Nit {
+        table_id: 0x40,
+        version: 1,
+        network_id: 1,
+        .. Default::default()
+    };
+    for tag in 0x80 .. 0x88 {
+        nit.descriptors.push(DescRaw {
+            tag,
+            data: vec![0x00; 200],
+        });
+    }
+    nit.items.push(NitItem { tsid: 1, onid: 1, .. Default::default() });
+    assert!(nit.validate().is_ok());
+
+    // network descriptors are placed into several sections
+    let mut psi_list = nit.psi_list_assemble();
+    assert_eq!(psi_list.len(), 2);
+
+    let mut parsed = Nit::default();
+    for psi in &mut psi_list {
+        psi.finalize();
+        assert!(psi.size <= 1024);
+        parsed.parse(psi);
+    }
+    assert_eq!(parsed.descriptors.len(), 8);
+    assert_eq!(parsed.items.len(), 1);
+
+    let mut cc: u8 = 0;
+    let mut nit_ts = Vec::<u8>::new();
+    nit.demux(NIT_PID, &mut cc, &mut nit_ts).unwrap();
+}
@@ -41,7 +41,76 @@ fn test_assemble_pat() {
 
     let mut cc: u8 = 0;
     let mut pat_ts = Vec::<u8>::new();
-    pat.demux(PAT_PID, &mut cc, &mut pat_ts);
+    pat.demux(PAT_PID, &mut cc, &mut pat_ts).unwrap();
 
     assert_eq!(data::PAT, pat_ts.as_slice());
 }
+
+#[test]
+fn test_pat_multi_section() {
+    let mut pat = Pat {
+        version: 2,
+        tsid: 1,
+        .. Default::default()
+    };
+    for pnr in 1 ..= 600 {
+        pat.items.push(PatItem { pnr, pid: 32 + pnr });
+    }
+    assert!(pat.validate().is_ok());
+
+    let psi_list = pat.psi_list_assemble();
+    assert_eq!(psi_list.len(), 3);
+
+    let mut cc: u8 = 0;
+    let mut pat_ts = Vec::<u8>::new();
+    pat.demux(PAT_PID, &mut cc, &mut pat_ts).unwrap();
+
+    let mut parsed = Pat::default();
+    let mut sections = Vec::new();
+    let mut psi = Psi::default();
+    for packet in pat_ts.chunks(188) {
+        psi.mux(packet);
+        if psi.check() {
+            sections.push((psi.buffer[6], psi.buffer[7]));
+            parsed.parse(&psi);
+        }
+    }
+    assert_eq!(sections, vec![(0, 2), (1, 2), (2, 2)]);
+
+    // repeated section does not duplicate programs
+    let mut psi = psi_list.into_iter().next().unwrap();
+    psi.finalize();
+    parsed.parse(&psi);
+
+    assert_eq!(parsed.version, 2);
+    assert_eq!(parsed.tsid, 1);
+    assert_eq!(parsed.items.len(), 600);
+    for (item, pnr) in parsed.items.iter().zip(1 ..) {
+        assert_eq!(item.pnr, pnr);
+        assert_eq!(item.pid, 32 + pnr);
+    }
+
+    // new version replaces programs
+    let pat = Pat {
+        version: 3,
+        tsid: 1,
+        items: vec![PatItem { pnr: 1, pid: 100 }],
+    };
+    let mut psi = pat.psi_list_assemble().into_iter().next().unwrap();
+    psi.finalize();
+    parsed.parse(&psi);
+    assert_eq!(parsed.items.len(), 1);
+    assert_eq!(parsed.items[0].pid, 100);
+}
+
+#[test]
+fn test_pat_validate() {
+    let mut pat = Pat::default();
+    for pnr in 0 ..= 0xFFFF {
+        pat.items.push(PatItem { pnr, pid: 32 });
+    }
+    assert_eq!(pat.validate(), Err(PsiError::SectionOverflow));
+
+    pat.items.truncate(PSI_MAX_SECTIONS * 250);
+    assert!(pat.validate().is_ok());
+}
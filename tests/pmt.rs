@@ -93,7 +93,7 @@ fn test_assemble_pmt() {
     let pid = 278;
     let mut cc: u8 = 0;
     let mut pmt_ts = Vec::<u8>::new();
-    pmt.demux(pid, &mut cc, &mut pmt_ts);
+    pmt.demux(pid, &mut cc, &mut pmt_ts).unwrap();
 
     assert_eq!(data::PMT, pmt_ts.as_slice());
 }
@@ -111,3 +111,148 @@ fn test_pmt_get_stream_type() {
     assert_eq!(StreamType::VIDEO, iter.next().unwrap().get_stream_type());
     assert_eq!(StreamType::AUDIO, iter.next().unwrap().get_stream_type());
 }
+
+#[test]
+fn test_pmt_get_stream_type_registration() {
+    let data: &[(u32, StreamType)] = &[
+        (FORMAT_HEVC, StreamType::VIDEO),
+        (FORMAT_OPUS, StreamType::AUDIO),
+        (FORMAT_BSSD, StreamType::AUDIO),
+        (FORMAT_KLVA, StreamType::DATA),
+    ];
+
+    for (format_identifier, stream_type) in data {
+        let mut item = PmtItem {
+            stream_type: 0x06,
+            pid: 100,
+            .. Default::default()
+        };
+        item.descriptors.push(Desc05 {
+            format_identifier: *format_identifier,
+            info: Vec::new(),
+        });
+        assert_eq!(*stream_type, item.get_stream_type());
+    }
+
+    // user private stream types
+    let data: &[(u8, u32, StreamType)] = &[
+        (0x81, FORMAT_AC3, StreamType::AUDIO),
+        (0x87, FORMAT_EAC3, StreamType::AUDIO),
+        (0xC0, FORMAT_HEVC, StreamType::VIDEO),
+        (0x81, 0x1234_5678, StreamType::DATA),
+    ];
+
+    for (stream_type_id, format_identifier, stream_type) in data {
+        let mut item = PmtItem {
+            stream_type: *stream_type_id,
+            pid: 100,
+            .. Default::default()
+        };
+        item.descriptors.push(Desc05 {
+            format_identifier: *format_identifier,
+            info: Vec::new(),
+        });
+        assert_eq!(*stream_type, item.get_stream_type());
+    }
+}
+
+#[test]
+fn test_pmt_hbbtv() {
+    let mut pmt = Pmt {
+        version: 1,
+        pnr: 1,
+        pcr: 100,
+        .. Default::default()
+    };
+
+    let mut item = PmtItem {
+        stream_type: 0x05,
+        pid: 200,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc6F {
+        items: vec![
+            Desc6Fi {
+                application_type: APPLICATION_TYPE_HBBTV,
+                ait_version: 1,
+            },
+        ],
+    });
+    pmt.items.push(item);
+
+    let mut item = PmtItem {
+        stream_type: 0x0B,
+        pid: 201,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc52 { tag: 0x0B });
+    item.descriptors.push(Desc13 {
+        carousel_id: 1,
+        .. Default::default()
+    });
+    item.descriptors.push(Desc14 {
+        association_tag: 0x000B,
+        tag_use: ASSOCIATION_USE_PROGRAM,
+        .. Default::default()
+    });
+    pmt.items.push(item);
+
+    let mut cc: u8 = 0;
+    let mut pmt_ts = Vec::<u8>::new();
+    pmt.demux(278, &mut cc, &mut pmt_ts).unwrap();
+
+    let mut psi = Psi::default();
+    psi.mux(&pmt_ts);
+
+    let mut parsed = Pmt::default();
+    parsed.parse(&psi);
+    assert_eq!(parsed.items.len(), 2);
+
+    let item = &parsed.items[0];
+    assert_eq!(item.get_stream_type(), StreamType::AIT);
+    let desc = item.descriptors.find::<Desc6F>().unwrap();
+    assert_eq!(desc.items[0].application_type, APPLICATION_TYPE_HBBTV);
+    assert_eq!(desc.items[0].ait_version, 1);
+
+    let item = &parsed.items[1];
+    assert_eq!(item.descriptors.find::<Desc13>().unwrap().carousel_id, 1);
+    let desc = item.descriptors.find::<Desc14>().unwrap();
+    assert_eq!(desc.association_tag, 0x000B);
+    assert_eq!(desc.tag_use, ASSOCIATION_USE_PROGRAM);
+}
+
+#[test]
+fn test_pmt_captions() {
+    let mut item = PmtItem {
+        stream_type: 0x02,
+        pid: 0x31,
+        .. Default::default()
+    };
+    assert_eq!(item.captions().count(), 0);
+
+    item.descriptors.push(Desc86 {
+        items: vec![
+            Desc86i {
+                lang: StringDVB::from_str("eng", ISO6937),
+                digital_cc: false,
+                line21_field: 0,
+                .. Default::default()
+            },
+            Desc86i {
+                lang: StringDVB::from_str("spa", ISO6937),
+                digital_cc: true,
+                caption_service_number: 2,
+                .. Default::default()
+            },
+        ],
+    });
+
+    let mut captions = item.captions();
+    let caption = captions.next().unwrap();
+    assert!(caption.is_608());
+    assert_eq!(caption.lang.to_string(), "eng");
+    let caption = captions.next().unwrap();
+    assert!(! caption.is_608());
+    assert_eq!(caption.caption_service_number, 2);
+    assert_eq!(caption.lang.to_string(), "spa");
+}
@@ -126,3 +126,15 @@ fn test_psi_demux() {
     psi.demux(&mut ts);
     assert_eq!(ts, &data::EIT_50[SKIP_EMPTY ..]);
 }
+
+#[test]
+fn test_finalize_section_length() {
+    let mut psi = Psi::new(0x42, 8, 1);
+    psi.buffer[1] = 0xF0;
+    psi.buffer.resize(5000, 0xFF);
+    psi.finalize();
+
+    // oversized section_length does not overwrite flags
+    assert_eq!(psi.buffer[1], 0xFF);
+    assert_eq!(psi.buffer[2], 0xFF);
+}
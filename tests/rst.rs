@@ -0,0 +1,96 @@
+use mpegts::psi::*;
+mod data;
+
+#[test]
+fn test_parse_rst() {
+    let mut psi = Psi::default();
+    psi.mux(data::RST);
+
+    let rst = Rst::from(&psi);
+
+    assert_eq!(rst.items.len(), 1);
+    let item = &rst.items[0];
+    assert_eq!(item.tsid, 1);
+    assert_eq!(item.onid, 1);
+    assert_eq!(item.pnr, 6);
+    assert_eq!(item.event_id, 1);
+    assert_eq!(item.running_status, 4);
+}
+
+#[test]
+fn test_assemble_rst() {
+    let mut rst = Rst::default();
+    rst.items.push(RstItem {
+        tsid: 1,
+        onid: 1,
+        pnr: 6,
+        event_id: 1,
+        running_status: 4,
+    });
+
+    let mut cc: u8 = 0;
+    let mut rst_ts = Vec::<u8>::new();
+    rst.demux(RST_PID, &mut cc, &mut rst_ts).unwrap();
+
+    assert_eq!(data::RST, rst_ts.as_slice());
+}
+
+#[test]
+fn test_st() {
+    let st = St { size: 200 };
+
+    let mut cc: u8 = 0;
+    let mut st_ts = Vec::<u8>::new();
+    st.demux(RST_PID, &mut cc, &mut st_ts).unwrap();
+    assert_eq!(st_ts.len(), 2 * 188);
+
+    let mut psi = Psi::default();
+    psi.mux(&st_ts[.. 188]);
+    psi.mux(&st_ts[188 ..]);
+    assert_eq!(psi.buffer[0], 0x72);
+
+    let st = St::from(&psi);
+    assert_eq!(st.size, 200);
+
+    let st = St { size: 4094 };
+    let mut st_ts = Vec::<u8>::new();
+    assert!(st.demux(RST_PID, &mut cc, &mut st_ts).is_err());
+    assert!(st_ts.is_empty());
+}
+
+#[test]
+fn test_rst_sections() {
+    let mut rst = Rst::default();
+    for pnr in 0 .. 200 {
+        rst.items.push(RstItem {
+            pnr,
+            running_status: 4,
+            .. Default::default()
+        });
+    }
+    assert!(rst.validate().is_ok());
+
+    // 113 items fit into the single section
+    let psi_list = rst.psi_list_assemble();
+    assert_eq!(psi_list.len(), 2);
+    assert_eq!(psi_list[0].buffer.len(), 3 + 113 * 9);
+}
+
+#[test]
+fn test_rst_running_status() {
+    let mut rst = Rst::default();
+    rst.items.push(RstItem {
+        tsid: 1,
+        onid: 1,
+        pnr: 6,
+        event_id: 1,
+        running_status: 0xFC,
+    });
+
+    let mut cc: u8 = 0;
+    let mut rst_ts = Vec::<u8>::new();
+    rst.demux(RST_PID, &mut cc, &mut rst_ts).unwrap();
+
+    // running_status does not overwrite reserved bits
+    assert_eq!(data::RST, rst_ts.as_slice());
+}
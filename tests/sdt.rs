@@ -41,7 +41,7 @@ fn test_parse_sdt() {
         assert_eq!(item.free_ca_mode, 0);
         assert_eq!(item.descriptors.len(), 1);
 
-        let desc = item.descriptors.iter().next().unwrap().downcast_ref::<Desc48>();
+        let desc = item.descriptors.iter().next().unwrap().downcast_ref::<Desc48>().unwrap();
         assert_eq!(desc.service_type, d.1);
         assert_eq!(desc.provider.to_string(), "Avalpa");
         assert_eq!(desc.name.to_string(), d.2);
@@ -75,7 +75,136 @@ fn test_assemble_sdt() {
 
     let mut cc: u8 = 10;
     let mut sdt_ts = Vec::<u8>::new();
-    sdt.demux(SDT_PID, &mut cc, &mut sdt_ts);
+    sdt.demux(SDT_PID, &mut cc, &mut sdt_ts).unwrap();
 
     assert_eq!(data::SDT, sdt_ts.as_slice());
 }
+
+#[test]
+fn test_sdt_get_name() {
+    let mut item = SdtItem::default();
+    item.descriptors.push(Desc48 {
+        service_type: 1,
+        provider: StringDVB::from_str("Provider", ISO6937),
+        name: StringDVB::from_str("Service", ISO6937),
+    });
+    item.descriptors.push(Desc5D {
+        items: vec![
+            Desc5Di {
+                lang: StringDVB::from_str("deu", ISO6937),
+                provider: StringDVB::from_str("Anbieter", ISO6937),
+                name: StringDVB::from_str("Dienst", ISO6937),
+            },
+        ],
+    });
+
+    assert_eq!(item.get_name("ger").unwrap().to_string(), "Dienst");
+    assert_eq!(item.get_provider("de").unwrap().to_string(), "Anbieter");
+    assert_eq!(item.get_name("eng").unwrap().to_string(), "Service");
+    assert_eq!(item.get_provider("eng").unwrap().to_string(), "Provider");
+
+    assert!(SdtItem::default().get_name("eng").is_none());
+}
+
+#[test]
+fn test_sdt_nvod() {
+    let mut sdt = Sdt::default();
+
+    let mut item = SdtItem {
+        pnr: 256,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc48 {
+        service_type: SERVICE_TYPE_NVOD_REFERENCE,
+        provider: StringDVB::from_str("Provider", ISO6937),
+        name: StringDVB::from_str("NVOD", ISO6937),
+    });
+    item.descriptors.push(Desc4B {
+        items: vec![
+            Desc4Bi { tsid: 1, onid: 1, service_id: 257 },
+            Desc4Bi { tsid: 1, onid: 1, service_id: 258 },
+        ],
+    });
+    sdt.items.push(item);
+
+    for pnr in 257 ..= 258 {
+        let mut item = SdtItem {
+            pnr,
+            .. Default::default()
+        };
+        item.descriptors.push(Desc4C {
+            reference_service_id: 256,
+        });
+        sdt.items.push(item);
+    }
+
+    let reference = &sdt.items[0];
+    assert!(reference.descriptors.find::<Desc48>().unwrap().is_nvod_reference());
+    assert_eq!(
+        reference.nvod_services().map(|x| x.service_id).collect::<Vec<u16>>(),
+        vec![257, 258]);
+
+    let shifted = sdt.get_service(258).unwrap();
+    assert_eq!(shifted.reference_service_id(), Some(256));
+    assert_eq!(sdt.resolve_reference_service(shifted).unwrap().pnr, 256);
+    assert!(sdt.resolve_reference_service(reference).is_none());
+}
+
+#[test]
+fn test_sdt_is_available() {
+    let mut item = SdtItem::default();
+    assert!(item.is_available("FIN"));
+
+    item.descriptors.push(Desc49 {
+        country_availability_flag: false,
+        countries: vec![StringDVB::from_str("NOR", ISO6937)],
+    });
+    assert!(item.is_available("FIN"));
+    assert!(! item.is_available("no"));
+
+    item.descriptors.push(Desc49 {
+        country_availability_flag: true,
+        countries: vec![StringDVB::from_str("SWE", ISO6937)],
+    });
+    assert!(item.is_available("SE"));
+    assert!(! item.is_available("FIN"));
+}
+
+#[test]
+fn test_sdt_validate() {
+    let mut sdt = Sdt {
+        table_id: 0x42,
+        version: 1,
+        tsid: 1,
+        onid: 1,
+        .. Default::default()
+    };
+
+    let mut item = SdtItem {
+        pnr: 1,
+        .. Default::default()
+    };
+    item.descriptors.push(Desc48 {
+        service_type: 1,
+        provider: StringDVB::from_str("Provider", ISO6937),
+        name: StringDVB::from_str(&"Service ".repeat(40), ISO6937),
+    });
+    sdt.items.push(item);
+    assert!(sdt.validate().is_err());
+
+    let mut cc: u8 = 0;
+    let mut sdt_ts = Vec::<u8>::new();
+    assert!(sdt.demux(SDT_PID, &mut cc, &mut sdt_ts).is_err());
+    assert!(sdt_ts.is_empty());
+
+    sdt.items[0].descriptors.find_mut::<Desc48>().unwrap().name =
+        StringDVB::from_str("Service", ISO6937);
+    assert!(sdt.validate().is_ok());
+    sdt.demux(SDT_PID, &mut cc, &mut sdt_ts).unwrap();
+
+    let mut psi = Psi::default();
+    psi.mux(&sdt_ts);
+    let parsed = Sdt::from(&psi);
+    assert_eq!(parsed.items.len(), 1);
+    assert_eq!(parsed.items[0].get_name("eng").unwrap().to_string(), "Service");
+}
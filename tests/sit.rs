@@ -0,0 +1,128 @@
+use mpegts::psi::*;
+use mpegts::textcode::*;
+
+fn mux_psi(ts: &[u8]) -> Psi {
+    let mut psi = Psi::default();
+    let mut skip = 0;
+    while skip < ts.len() {
+        psi.mux(&ts[skip ..]);
+        skip += 188;
+    }
+    psi
+}
+
+#[test]
+fn test_sit_from_source() {
+    let mut pat = Pat::default();
+    pat.items.push(PatItem { pnr: 0, pid: 16 });
+    pat.items.push(PatItem { pnr: 1, pid: 1031 });
+    pat.items.push(PatItem { pnr: 2, pid: 1032 });
+
+    let mut sdt = Sdt::default();
+    for pnr in 1 ..= 2 {
+        let mut item = SdtItem {
+            pnr,
+            running_status: 4,
+            .. Default::default()
+        };
+        item.descriptors.push(Desc48 {
+            service_type: 1,
+            provider: StringDVB::from_str("Cesbo", ISO6937),
+            name: StringDVB::from_str(&format!("Service {}", pnr), ISO6937),
+        });
+        sdt.items.push(item);
+    }
+
+    let mut sit = Sit::from_source(&pat, &sdt, &[2, 3], 10000);
+    sit.version = 2;
+
+    let mut cc: u8 = 0;
+    let mut sit_ts = Vec::<u8>::new();
+    sit.demux(SIT_PID, &mut cc, &mut sit_ts).unwrap();
+
+    let psi = mux_psi(&sit_ts);
+    assert!(psi.check());
+
+    let sit = Sit::from(&psi);
+    assert_eq!(sit.version, 2);
+    assert_eq!(sit.descriptors.len(), 1);
+    let desc = sit.descriptors.iter().next().unwrap().downcast_ref::<Desc63>().unwrap();
+    assert_eq!(desc.peak_rate, 10000);
+
+    assert_eq!(sit.items.len(), 1);
+    let item = &sit.items[0];
+    assert_eq!(item.pnr, 2);
+    assert_eq!(item.running_status, 4);
+    let desc = item.descriptors.iter().next().unwrap().downcast_ref::<Desc48>().unwrap();
+    assert_eq!(desc.name.to_string(), "Service 2");
+}
+
+#[test]
+fn test_sit_malformed() {
+    let sit = Sit {
+        version: 1,
+        .. Default::default()
+    };
+    let mut psi = sit.psi_list_assemble().into_iter().next().unwrap();
+    psi.finalize();
+    assert!(psi.check());
+
+    // descriptors_loop_length out of the section
+    psi.buffer[8] = 0xFF;
+    psi.buffer[9] = 0xFF;
+    psi.finalize();
+    assert!(psi.check());
+
+    let sit = Sit::from(&psi);
+    assert!(sit.descriptors.is_empty());
+    assert!(sit.items.is_empty());
+}
+
+#[test]
+fn test_sit_validate() {
+    let mut sit = Sit {
+        version: 1,
+        .. Default::default()
+    };
+
+    for pnr in 1 ..= 4 {
+        let mut item = SitItem {
+            pnr,
+            .. Default::default()
+        };
+        item.descriptors.push(Desc48 {
+            service_type: 1,
+            provider: StringDVB::from_str("Provider", ISO6937),
+            name: StringDVB::from_str(&"Service ".repeat(28), ISO6937),
+        });
+        sit.items.push(item);
+    }
+    assert!(sit.validate().is_ok());
+
+    // SIT is limited to the single section
+    sit.items.push(SitItem {
+        pnr: 5,
+        descriptors: sit.items[0].descriptors.clone(),
+        .. Default::default()
+    });
+    assert_eq!(sit.validate(), Err(PsiError::SectionOverflow));
+
+    let mut cc: u8 = 0;
+    let mut sit_ts = Vec::<u8>::new();
+    assert!(sit.demux(SIT_PID, &mut cc, &mut sit_ts).is_err());
+    assert!(sit_ts.is_empty());
+}
+
+#[test]
+fn test_dit() {
+    let dit = Dit { transition_flag: 1 };
+
+    let mut cc: u8 = 0;
+    let mut dit_ts = Vec::<u8>::new();
+    dit.demux(DIT_PID, &mut cc, &mut dit_ts).unwrap();
+    assert_eq!(&dit_ts[.. 9], &[0x47, 0x40, 0x1e, 0x10, 0x00, 0x7e, 0x70, 0x01, 0xff]);
+
+    let psi = mux_psi(&dit_ts);
+    let dit = Dit::from(&psi);
+    assert_eq!(dit.transition_flag, 1);
+}
@@ -19,7 +19,7 @@ fn test_assemble_tdt() {
 
     let mut cc: u8 = 3;
     let mut tdt_ts = Vec::<u8>::new();
-    tdt.demux(TDT_PID, &mut cc, &mut tdt_ts);
+    tdt.demux(TDT_PID, &mut cc, &mut tdt_ts).unwrap();
 
     assert_eq!(data::TDT, tdt_ts.as_slice());
 }
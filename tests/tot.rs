@@ -23,7 +23,30 @@ fn test_assemble_tot() {
 
     let mut cc: u8 = 4;
     let mut tot_ts = Vec::<u8>::new();
-    tot.demux(TOT_PID, &mut cc, &mut tot_ts);
+    tot.demux(TOT_PID, &mut cc, &mut tot_ts).unwrap();
 
     assert_eq!(data::TOT, tot_ts.as_slice());
 }
+
+#[test]
+fn test_tot_validate() {
+    let mut tot = Tot::default();
+    for _ in 0 .. 4 {
+        tot.descriptors.push(DescRaw {
+            tag: 0x9a,
+            data: vec![0x00; 250],
+        });
+    }
+    assert!(tot.validate().is_ok());
+
+    tot.descriptors.push(DescRaw {
+        tag: 0x9a,
+        data: vec![0x00; 250],
+    });
+    assert_eq!(tot.validate(), Err(PsiError::ItemOverflow { size: 10 + 5 * 252, limit: 1020 }));
+
+    let mut cc: u8 = 0;
+    let mut tot_ts = Vec::<u8>::new();
+    assert!(tot.demux(TOT_PID, &mut cc, &mut tot_ts).is_err());
+    assert!(tot_ts.is_empty());
+}